@@ -44,6 +44,7 @@ pub fn execute_add(args: &AttachAddArgs) -> Result<()> {
     }
 
     // Process attachments
+    item.touch();
     let added_count = ui::process_and_save_attachments(&mut item, &path, &args.sources)?;
 
     if added_count == 0 {
@@ -122,6 +123,7 @@ pub fn execute_remove(args: &AttachRemoveArgs) -> Result<()> {
     }
 
     // Save updated item
+    item.touch();
     item.save(&path)?;
 
     println!(
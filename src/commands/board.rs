@@ -0,0 +1,348 @@
+//! # Board Command
+//!
+//! Shows items as a kanban board with one column per workflow state, label,
+//! or category.
+//!
+//! Moving a card to another column changes the item to match: a state change
+//! (closing or reopening it as needed), a label swap, or a category move.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    commands::close::{apply_state, find_dependents},
+    config::Config,
+    item::{normalize_identifier, Item, Status},
+    output::{self, ColumnRecord, ItemRecord, OutputFormat},
+    storage::{self, ParseFailure},
+    tui::screens::{show_board, BoardCard, BoardColumn, MovedCard},
+    ui::{self, InteractiveArgs},
+};
+
+/// Column for items without any of the board's labels.
+const UNLABELED: &str = "unlabeled";
+
+/// Column for items without a category.
+const UNCATEGORIZED: &str = "uncategorized";
+
+/// What the board's columns are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum GroupBy {
+    /// One column per workflow state (including archived states)
+    #[default]
+    Status,
+    /// One column per label, plus one for items without them
+    Label,
+    /// One column per category, plus one for uncategorized items
+    Category,
+}
+
+/// Arguments for the board command
+#[derive(Default)]
+pub struct BoardArgs {
+    pub by: GroupBy,
+    /// Labels to use as columns (default: all labels of open items)
+    pub labels: Vec<String>,
+    pub interactive: InteractiveArgs,
+    pub format: OutputFormat,
+    /// Fail instead of skipping item files that cannot be parsed
+    pub strict: bool,
+}
+
+/// Board columns and how items map to them.
+pub struct Board {
+    by: GroupBy,
+    columns: Vec<String>,
+}
+
+impl Board {
+    /// Builds the columns for a grouping.
+    ///
+    /// `labels` picks the label columns; without it every label of an open
+    /// item gets a column.
+    pub fn new(config: &Config, by: GroupBy, labels: &[String]) -> Result<Self> {
+        let columns = match by {
+            GroupBy::Status => config.workflow().states,
+            GroupBy::Label => {
+                let mut columns = vec![UNLABELED.to_string()];
+                if labels.is_empty() {
+                    let used: BTreeSet<String> = open_items(config)
+                        .0
+                        .iter()
+                        .flat_map(|item| item.labels().iter().cloned())
+                        .collect();
+                    columns.extend(used);
+                } else {
+                    for label in labels.iter().map(|l| normalize_identifier(l)) {
+                        if label.is_empty() {
+                            bail!("Board labels cannot be empty");
+                        }
+                        if !columns.contains(&label) {
+                            columns.push(label);
+                        }
+                    }
+                }
+                columns
+            }
+            GroupBy::Category => {
+                let used: BTreeSet<String> = open_items(config)
+                    .0
+                    .iter()
+                    .filter_map(|item| storage::derive_category(config, item.path.as_ref()?))
+                    .collect();
+                std::iter::once(UNCATEGORIZED.to_string())
+                    .chain(used)
+                    .collect()
+            }
+        };
+        Ok(Self { by, columns })
+    }
+
+    /// Returns the column names, in board order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the index of the column an item belongs in.
+    ///
+    /// Items with several of the board's labels go into the first matching
+    /// column.
+    pub fn column_of(&self, config: &Config, item: &Item) -> Option<usize> {
+        let position = |name: &str| self.columns.iter().position(|c| c == name);
+        match self.by {
+            GroupBy::Status => position(&config.workflow().current_state(item)),
+            GroupBy::Label => Some(
+                self.columns[1..]
+                    .iter()
+                    .position(|label| item.labels().contains(label))
+                    .map_or(0, |i| i + 1),
+            ),
+            GroupBy::Category => {
+                let category = item
+                    .path
+                    .as_ref()
+                    .and_then(|path| storage::derive_category(config, path));
+                category.map_or(Some(0), |category| position(&category))
+            }
+        }
+    }
+
+    /// Loads the items on the board, grouped by column and ordered by ID,
+    /// and the files that failed to parse.
+    pub fn load(&self, config: &Config) -> (Vec<(String, Vec<Item>)>, Vec<ParseFailure>) {
+        let (mut items, failures) = match self.by {
+            GroupBy::Status => storage::load_items(config, storage::walk_all(config)),
+            GroupBy::Label | GroupBy::Category => open_items(config),
+        };
+        items.sort_by(|a, b| a.id().cmp(b.id()));
+
+        let mut columns: Vec<_> = self
+            .columns
+            .iter()
+            .map(|name| (name.clone(), Vec::new()))
+            .collect();
+        for item in items {
+            if let Some(index) = self.column_of(config, &item) {
+                columns[index].1.push(item);
+            }
+        }
+        (columns, failures)
+    }
+
+    /// Moves an item into a column and returns its new path.
+    ///
+    /// - Status: moves to the state (respecting workflow transitions), closing
+    ///   or reopening the item as needed; closing reports unblocked and still
+    ///   blocked dependents like `qs close`
+    /// - Label: removes the board's labels and adds the column's label
+    /// - Category: moves the item to the column's category
+    ///
+    /// Nothing is printed, since the board's screen is active; notes and
+    /// warnings are returned for its title bar.
+    pub fn move_item(&self, config: &Config, path: &Path, column: usize) -> Result<MovedCard> {
+        let Some(target) = self.columns.get(column) else {
+            bail!("Unknown board column {column}");
+        };
+        let storage::LoadedItem { path, mut item } = storage::load_from_file(path)?;
+        if item.status() == Status::Template {
+            bail!("Templates cannot be moved on the board");
+        }
+
+        match self.by {
+            GroupBy::Status => {
+                let workflow = config.workflow();
+                let current = workflow.current_state(&item);
+                if &current == target {
+                    return Ok(moved_to(path));
+                }
+                if !workflow.can_transition(&current, target) {
+                    bail!("Cannot move '{}' from '{current}' to '{target}'", item.id());
+                }
+                let was_open = item.status() == Status::Open;
+                let (path, mut warnings) = apply_state(config, &path, &mut item, target)?;
+                let mut notes = Vec::new();
+                if was_open && item.status() == Status::Closed {
+                    let dependents = find_dependents(config, &item);
+                    notes.extend(
+                        dependents
+                            .unblocked
+                            .iter()
+                            .map(|dependent| format!("Unblocked: {}", dependent.id())),
+                    );
+                    warnings.extend(dependents.warnings(&item));
+                }
+                Ok(MovedCard {
+                    path,
+                    notes,
+                    warnings,
+                })
+            }
+            GroupBy::Label => {
+                let mut changed = false;
+                for label in &self.columns[1..] {
+                    if label != target && item.labels().contains(label) {
+                        item.remove_label(label);
+                        changed = true;
+                    }
+                }
+                if column > 0 && !item.labels().contains(target) {
+                    item.add_label(target);
+                    changed = true;
+                }
+                if changed {
+                    item.touch();
+                    item.save(&path)?;
+                }
+                Ok(moved_to(path))
+            }
+            GroupBy::Category => {
+                let category = (column > 0).then_some(target.as_str());
+                if storage::derive_category(config, &path).as_deref() == category {
+                    return Ok(moved_to(path));
+                }
+                item.touch();
+                item.save(&path)?;
+                let (path, warnings) = storage::move_to_category(config, &path, category)?;
+                Ok(MovedCard {
+                    path,
+                    warnings,
+                    ..MovedCard::default()
+                })
+            }
+        }
+    }
+}
+
+/// A move to `path` without notes or warnings.
+fn moved_to(path: PathBuf) -> MovedCard {
+    MovedCard {
+        path,
+        ..MovedCard::default()
+    }
+}
+
+/// Loads all open (non-archived) items, and the files that failed to parse.
+fn open_items(config: &Config) -> (Vec<Item>, Vec<ParseFailure>) {
+    let (items, failures) = storage::load_items(config, storage::walk_items(config));
+    let items = items
+        .into_iter()
+        .filter(|item| item.status() == Status::Open)
+        .collect();
+    (items, failures)
+}
+
+/// Executes the board command.
+pub fn execute(args: &BoardArgs) -> Result<()> {
+    let config = Config::load()?;
+    show(args, &config)
+}
+
+/// Builds the board and prints or shows it.
+fn show(args: &BoardArgs, config: &Config) -> Result<()> {
+    let board = Board::new(config, args.by, &args.labels)?;
+    let (columns, failures) = board.load(config);
+    storage::check_parse_failures(config, args.strict, &failures)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+
+    if args.format.is_machine() {
+        let records: Vec<ColumnRecord> = columns
+            .into_iter()
+            .map(|(name, items)| ColumnRecord {
+                name,
+                items: items
+                    .iter()
+                    .map(|item| ItemRecord::new(item, config, false))
+                    .collect(),
+            })
+            .collect();
+        return output::print_records(args.format, &records);
+    }
+
+    if !args.interactive.should_run(config) {
+        print_board(&columns);
+        return Ok(());
+    }
+
+    // Reload after each opened item, since it may have been edited
+    let title = match args.by {
+        GroupBy::Status => "Board by status",
+        GroupBy::Label => "Board by label",
+        GroupBy::Category => "Board by category",
+    };
+    loop {
+        // Unparseable files are reported once, after the board closes
+        let columns = board
+            .load(config)
+            .0
+            .into_iter()
+            .map(|(name, items)| BoardColumn {
+                name,
+                cards: items.into_iter().filter_map(card).collect(),
+            })
+            .collect();
+        let mover = |path: &Path, column: usize| board.move_item(config, path, column);
+        let Some(path) = show_board(title, columns, mover)? else {
+            return Ok(());
+        };
+        ui::open_item_in_editor(&Item::load(&path)?, config)?;
+    }
+}
+
+/// Builds a board card from an item.
+fn card(item: Item) -> Option<BoardCard> {
+    Some(BoardCard {
+        id: item.id().to_string(),
+        title: item.title().to_string(),
+        path: item.path?,
+    })
+}
+
+/// Prints the board as text, one column after another.
+fn print_board(columns: &[(String, Vec<Item>)]) {
+    let color = ui::use_color();
+    for (i, (name, items)) in columns.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        if color {
+            println!("{} ({})", name.bold(), items.len());
+        } else {
+            println!("{name} ({})", items.len());
+        }
+        for item in items {
+            if color {
+                println!("  {}  {}", item.id().dimmed(), item.title());
+            } else {
+                println!("  {}  {}", item.id(), item.title());
+            }
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! # Bulk Operations
+//!
+//! Applies one change to many items at once and reports the result for each
+//! item.
+//!
+//! Every item is handled on its own: a failing item is reported and the rest
+//! still get the change.
+//!
+//! Items come from the TUI's marked items, or on the command line from a
+//! query (`--query`) or IDs and paths on stdin (`--stdin`). `--dry-run`
+//! reports what would change without writing anything.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    commands::close::{apply_state, find_dependents, print_dependents, Dependents},
+    config::Config,
+    item::{normalize_identifier, Item, Query, Status},
+    storage, ui,
+};
+
+/// A change applied to every item of a bulk operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkOp {
+    /// Close open items
+    Close,
+    /// Reopen closed items
+    Reopen,
+    /// Add a label
+    AddLabel(String),
+    /// Remove a label
+    RemoveLabel(String),
+    /// Move to a category (`None` = uncategorized)
+    MoveCategory(Option<String>),
+    /// Move to trash
+    Delete,
+}
+
+impl BulkOp {
+    /// Describes the operation for a number of items, e.g. `Close 3 items`.
+    pub fn describe(&self, count: usize) -> String {
+        let items = if count == 1 {
+            "1 item".to_string()
+        } else {
+            format!("{count} items")
+        };
+        match self {
+            Self::Close => format!("Close {items}"),
+            Self::Reopen => format!("Reopen {items}"),
+            Self::AddLabel(label) => format!("Add label '{label}' to {items}"),
+            Self::RemoveLabel(label) => format!("Remove label '{label}' from {items}"),
+            Self::MoveCategory(Some(category)) => format!("Move {items} to '{category}'"),
+            Self::MoveCategory(None) => format!("Move {items} out of their category"),
+            Self::Delete => format!("Delete {items}"),
+        }
+    }
+
+    /// Past-tense verb for success messages.
+    pub const fn verb(&self) -> &'static str {
+        match self {
+            Self::Close => "Closed",
+            Self::Reopen => "Reopened",
+            Self::AddLabel(_) | Self::RemoveLabel(_) => "Updated",
+            Self::MoveCategory(_) => "Moved",
+            Self::Delete => "Deleted",
+        }
+    }
+}
+
+/// How a command line command picks several items.
+#[derive(Debug, Default, Clone)]
+pub struct BulkTargets {
+    /// Query selecting the items (`--query`)
+    pub query: Option<String>,
+    /// Read item IDs or paths from stdin, one per line (`--stdin`)
+    pub stdin: bool,
+    /// Report what would change without writing (`--dry-run`)
+    pub dry_run: bool,
+}
+
+impl BulkTargets {
+    /// Returns true if the command should run as a bulk operation.
+    ///
+    /// `--dry-run` alone makes a single `--id`/`--file` item a bulk operation
+    /// of one, so it gets the same report.
+    pub const fn is_bulk(&self) -> bool {
+        self.query.is_some() || self.stdin || self.dry_run
+    }
+}
+
+/// What happened to one item.
+pub struct BulkOutcome {
+    /// Item path before the operation
+    pub path: PathBuf,
+    /// New path if the item changed, `None` if it already matched
+    pub result: Result<Option<PathBuf>>,
+    /// The item and its dependents, if the operation closed it
+    pub dependents: Option<(Item, Dependents)>,
+}
+
+/// Executes a bulk operation from the command line (`qs close` and
+/// `qs reopen` with `--query`, `--stdin`, or `--dry-run`).
+pub fn execute(
+    op: &BulkOp,
+    id: Option<String>,
+    file: Option<PathBuf>,
+    targets: &BulkTargets,
+) -> Result<()> {
+    let config = Config::load()?;
+    // Reopening looks for closed items, everything else for open ones
+    let archived = *op == BulkOp::Reopen;
+    let (paths, mut outcomes) = select(&config, id, file, targets, archived)?;
+    if paths.is_empty() && outcomes.is_empty() {
+        println!("No items found.");
+        return Ok(());
+    }
+
+    outcomes.extend(apply(&config, op, &paths, targets.dry_run)?);
+    print_summary(&config, op.verb(), &outcomes, targets.dry_run)
+}
+
+/// Selects the items of a command line bulk operation.
+///
+/// `--query` matches open items, or archived ones with `archived`, with plain
+/// words matching title and ID only (as in `qs list --query`). Stdin
+/// lines are item paths or full IDs; only the first word of a line is used
+/// and indented lines (like the body snippets of `qs search --full-text`) are
+/// skipped, so the output of `qs list` and `qs search` can be piped in. Lines
+/// that don't resolve are returned as failed outcomes, so they show up in
+/// the report without stopping the rest.
+pub fn select(
+    config: &Config,
+    id: Option<String>,
+    file: Option<PathBuf>,
+    targets: &BulkTargets,
+    archived: bool,
+) -> Result<(Vec<PathBuf>, Vec<BulkOutcome>)> {
+    let mut paths = Vec::new();
+    let mut unresolved = Vec::new();
+
+    if let Some(query) = &targets.query {
+        let query = Query::parse(query)?;
+        let walk: Box<dyn Iterator<Item = PathBuf>> = if archived {
+            Box::new(storage::walk_archived(config))
+        } else {
+            Box::new(storage::walk_items(config))
+        };
+        let (items, failures) = storage::load_items(config, walk);
+        ui::print_parse_failures(config, &failures);
+        let mut items: Vec<Item> = items
+            .into_iter()
+            .filter(|item| {
+                let category = item
+                    .path
+                    .as_ref()
+                    .and_then(|path| storage::derive_category(config, path));
+                query.matches(item, category.as_deref(), false)
+            })
+            .collect();
+        items.sort_by(|a, b| a.id().cmp(b.id()));
+        paths.extend(items.into_iter().filter_map(|item| item.path));
+    } else if targets.stdin {
+        for line in ui::read_stdin("item IDs or paths")?.lines() {
+            if line.starts_with(char::is_whitespace) {
+                continue;
+            }
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match resolve_reference(config, line) {
+                Ok(path) => paths.push(path),
+                Err(err) => unresolved.push(BulkOutcome {
+                    path: PathBuf::from(line),
+                    result: Err(err),
+                    dependents: None,
+                }),
+            }
+        }
+    } else {
+        let item_ref = storage::ItemRef::from_options(id, file)?;
+        paths.push(item_ref.resolve(config)?.path);
+    }
+
+    // The same item listed twice is only changed once
+    let mut seen = HashSet::new();
+    paths.retain(|path| seen.insert(path.clone()));
+    Ok((paths, unresolved))
+}
+
+/// Resolves a stdin line to an item path: a file path (relative to the
+/// current directory or the project root) or a full ID.
+///
+/// Partial IDs are not accepted, since a stray word could match an
+/// unrelated item.
+fn resolve_reference(config: &Config, line: &str) -> Result<PathBuf> {
+    let candidates = [line, line.split_whitespace().next().unwrap_or(line)];
+    for candidate in candidates {
+        let path = Path::new(candidate);
+        for full in [path.to_path_buf(), config.project_root().join(path)] {
+            if full.is_file() {
+                return Ok(full);
+            }
+        }
+    }
+    storage::find_by_full_id(config, candidates[1])
+}
+
+/// Applies an operation to each item.
+///
+/// Labels and categories are normalized once up front; an empty label fails
+/// the whole operation, an empty category means uncategorized. With
+/// `dry_run` nothing is written and changed items keep their path. Closed
+/// items get their dependents, as reported by `qs close`.
+pub fn apply(
+    config: &Config,
+    op: &BulkOp,
+    paths: &[PathBuf],
+    dry_run: bool,
+) -> Result<Vec<BulkOutcome>> {
+    let op = match op {
+        BulkOp::AddLabel(label) | BulkOp::RemoveLabel(label) => {
+            if label.trim().is_empty() {
+                bail!("Label cannot be empty");
+            }
+            let label = normalize_identifier(label);
+            if matches!(op, BulkOp::AddLabel(_)) {
+                BulkOp::AddLabel(label)
+            } else {
+                BulkOp::RemoveLabel(label)
+            }
+        }
+        BulkOp::MoveCategory(category) => BulkOp::MoveCategory(
+            category
+                .as_deref()
+                .filter(|c| !c.trim().is_empty())
+                .map(normalize_identifier),
+        ),
+        op => op.clone(),
+    };
+
+    Ok(paths
+        .iter()
+        .map(|path| {
+            let result = apply_one(config, &op, path, dry_run);
+            // Found right after each close, so later items see earlier ones closed
+            let dependents = match &result {
+                Ok(Some(new_path)) if op == BulkOp::Close && !dry_run => {
+                    Item::load(new_path).ok().map(|closed| {
+                        let dependents = find_dependents(config, &closed);
+                        (closed, dependents)
+                    })
+                }
+                _ => None,
+            };
+            BulkOutcome {
+                path: path.clone(),
+                result,
+                dependents,
+            }
+        })
+        .collect())
+}
+
+/// Applies an operation to one item.
+fn apply_one(config: &Config, op: &BulkOp, path: &Path, dry_run: bool) -> Result<Option<PathBuf>> {
+    let storage::LoadedItem { path, mut item } = storage::load_from_file(path)?;
+    if item.status() == Status::Template {
+        bail!("Templates cannot be changed in bulk");
+    }
+
+    match op {
+        BulkOp::Close | BulkOp::Reopen => {
+            let workflow = config.workflow();
+            let (target_status, state) = if *op == BulkOp::Close {
+                (Status::Closed, workflow.closed_state())
+            } else {
+                (Status::Open, workflow.initial())
+            };
+            if item.status() == target_status {
+                return Ok(None);
+            }
+            if dry_run {
+                return Ok(Some(path));
+            }
+            let (new_path, warnings) = apply_state(config, &path, &mut item, state)?;
+            ui::print_warnings(&warnings);
+            Ok(Some(new_path))
+        }
+        BulkOp::AddLabel(label) => {
+            if item.labels().contains(label) {
+                return Ok(None);
+            }
+            if dry_run {
+                return Ok(Some(path));
+            }
+            item.add_label(label);
+            save(&path, &mut item).map(Some)
+        }
+        BulkOp::RemoveLabel(label) => {
+            if !item.labels().contains(label) {
+                return Ok(None);
+            }
+            if dry_run {
+                return Ok(Some(path));
+            }
+            item.remove_label(label);
+            save(&path, &mut item).map(Some)
+        }
+        BulkOp::MoveCategory(category) => {
+            if storage::derive_category(config, &path) == *category {
+                return Ok(None);
+            }
+            if dry_run {
+                return Ok(Some(path));
+            }
+            save(&path, &mut item)?;
+            let (new_path, warnings) =
+                storage::move_to_category(config, &path, category.as_deref())?;
+            ui::print_warnings(&warnings);
+            Ok(Some(new_path))
+        }
+        BulkOp::Delete => {
+            if dry_run {
+                return Ok(Some(path));
+            }
+            storage::trash_item(&path)?;
+            Ok(Some(path))
+        }
+    }
+}
+
+/// Touches and saves a changed item.
+fn save(path: &Path, item: &mut Item) -> Result<PathBuf> {
+    item.touch();
+    item.save(path)?;
+    Ok(path.to_path_buf())
+}
+
+/// Prints one line per item and a closing tally.
+///
+/// `verb` is the past tense for changed items (e.g. `Closed`). Fails when any
+/// item failed, so the command exits with an error.
+pub fn print_summary(
+    config: &Config,
+    verb: &str,
+    outcomes: &[BulkOutcome],
+    dry_run: bool,
+) -> Result<()> {
+    let (mut changed, mut unchanged, mut failed) = (0, 0, 0);
+    for outcome in outcomes {
+        let relative = config.relative_path(&outcome.path);
+        match &outcome.result {
+            Ok(Some(_)) if dry_run => {
+                changed += 1;
+                println!("{} Would change: {}", "→".cyan(), relative.display());
+            }
+            Ok(Some(new_path)) => {
+                changed += 1;
+                ui::print_success(verb, config, new_path);
+                if let Some((closed, dependents)) = &outcome.dependents {
+                    print_dependents(closed, dependents);
+                }
+            }
+            Ok(None) => {
+                unchanged += 1;
+                println!("{} Unchanged: {}", "·".dimmed(), relative.display());
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("{} {}: {err:#}", "✗".red(), relative.display());
+            }
+        }
+    }
+
+    let changed = if dry_run {
+        format!("{changed} would change")
+    } else {
+        format!("{changed} changed")
+    };
+    println!(
+        "{}",
+        format!("{changed}, {unchanged} unchanged, {failed} failed").dimmed()
+    );
+    if failed > 0 {
+        bail!("{failed} of {} items failed", outcomes.len());
+    }
+    Ok(())
+}
@@ -2,14 +2,23 @@
 //!
 //! Closes or reopens queuestack items, moving them to/from the archive.
 //!
+//! With a project workflow, closing sets the first archived state and
+//! reopening sets the initial state. Like `qs status --force`, both ignore the
+//! workflow's transition rules, so an item can always be closed or reopened.
+//!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use owo_colors::OwoColorize;
 
-use crate::{config::Config, item::Status, storage, ui};
+use crate::{
+    config::Config,
+    item::{links, Item, Status},
+    storage, ui,
+};
 
 /// Executes the close command.
 pub fn execute_close(id: Option<String>, file: Option<PathBuf>) -> Result<()> {
@@ -40,10 +49,45 @@ fn execute_status_change(
     let item_ref = storage::ItemRef::from_options(id, file)?;
     let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
 
-    // Determine operation parameters
-    let (target_status, verb, state_name) = match operation {
-        StatusChange::Close => (Status::Closed, "Closed", "closed"),
-        StatusChange::Reopen => (Status::Open, "Reopened", "open"),
+    let verb = match operation {
+        StatusChange::Close => "Closed",
+        StatusChange::Reopen => "Reopened",
+    };
+    let (new_path, warnings) = change_status(&config, &path, &mut item, operation)?;
+
+    // Print success message
+    ui::print_success(verb, &config, &new_path);
+    ui::print_warnings(&warnings);
+
+    // Report effects on linked items
+    if matches!(operation, StatusChange::Close) {
+        report_dependents(&config, &item);
+    }
+
+    Ok(())
+}
+
+/// Closes an item and moves it to the archive, returning its new path.
+pub fn close(config: &Config, path: &Path, item: &mut Item) -> Result<PathBuf> {
+    let (new_path, warnings) = change_status(config, path, item, StatusChange::Close)?;
+    ui::print_warnings(&warnings);
+    Ok(new_path)
+}
+
+/// Closes or reopens an item without printing, returning its new path and
+/// any attachment move warnings.
+///
+/// Transition rules are deliberately not checked (see the module docs).
+fn change_status(
+    config: &Config,
+    path: &Path,
+    item: &mut Item,
+    operation: StatusChange,
+) -> Result<(PathBuf, Vec<String>)> {
+    let workflow = config.workflow();
+    let (target_status, target_state, state_name) = match operation {
+        StatusChange::Close => (Status::Closed, workflow.closed_state(), "closed"),
+        StatusChange::Reopen => (Status::Open, workflow.initial(), "open"),
     };
 
     // Check if already in target state
@@ -51,21 +95,145 @@ fn execute_status_change(
         anyhow::bail!("Item '{}' is already {}", item.id(), state_name);
     }
 
-    // Update status and save
-    item.set_status(target_status);
-    item.save(&path)?;
+    // Update status and move to/from archive
+    apply_state(config, path, item, target_state)
+}
 
-    // Move to/from archive
-    let (new_path, warnings) = match operation {
-        StatusChange::Close => storage::archive_item(&config, &path)?,
-        StatusChange::Reopen => storage::unarchive_item(&config, &path)?,
+/// Moves an item into a workflow state, archiving or unarchiving it as needed.
+///
+/// Archived states close the item, all others open it. The `state` key is only
+/// written when the project defines a workflow. Closing records `closed_at`,
+/// reopening clears it. Returns the item's new path and any attachment move
+/// warnings.
+pub(crate) fn apply_state(
+    config: &Config,
+    path: &Path,
+    item: &mut Item,
+    state: &str,
+) -> Result<(PathBuf, Vec<String>)> {
+    let archived = config.workflow().is_archived(state);
+    let target_status = if archived {
+        Status::Closed
+    } else {
+        Status::Open
     };
+    let status_changed = item.status() != target_status;
 
-    // Print any attachment move warnings
-    ui::print_warnings(&warnings);
+    if config.has_workflow() {
+        item.set_state(Some(state.to_string()));
+    }
+    item.set_status(target_status);
+    item.touch();
+    if status_changed {
+        item.set_closed_at(archived.then(|| item.last_updated()));
+    }
+    item.save(path)?;
 
-    // Print success message
-    ui::print_success(verb, &config, &new_path);
+    if !status_changed {
+        return Ok((path.to_path_buf(), Vec::new()));
+    }
 
-    Ok(())
+    if archived {
+        storage::archive_item(config, path)
+    } else {
+        storage::unarchive_item(config, path)
+    }
+}
+
+/// How closing an item affects linked items.
+#[derive(Debug, Default)]
+pub struct Dependents {
+    /// Open items the closed item was still blocked by
+    pub open_blockers: Vec<String>,
+    /// Open dependents without any open blockers left
+    pub unblocked: Vec<Item>,
+    /// Open dependents with the open items they are still blocked by
+    pub still_blocked: Vec<(Item, Vec<String>)>,
+}
+
+impl Dependents {
+    /// Returns warnings about open blockers of `closed` and dependents that
+    /// are still blocked.
+    pub fn warnings(&self, closed: &Item) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.open_blockers.is_empty() {
+            warnings.push(format!(
+                "'{}' was still blocked by open item(s): {}",
+                closed.id(),
+                self.open_blockers.join(", ")
+            ));
+        }
+        for (dependent, remaining) in &self.still_blocked {
+            warnings.push(format!(
+                "'{}' is still blocked by open item(s): {}",
+                dependent.id(),
+                remaining.join(", ")
+            ));
+        }
+        warnings
+    }
+}
+
+/// Finds the linked items affected by closing `closed`.
+///
+/// Dependents are open items blocked by `closed`; they are unblocked once
+/// none of their other blockers is open.
+pub fn find_dependents(config: &Config, closed: &Item) -> Dependents {
+    // `closed` is already archived, so it is not part of the open set
+    let open_ids = storage::collect_open_ids(config);
+
+    let open_blockers = links::open_blockers(closed, &open_ids)
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut unblocked = Vec::new();
+    let mut still_blocked = Vec::new();
+    // Unparseable files are reported by the listing commands
+    let (items, _) = storage::load_items(config, storage::walk_items(config));
+    let dependents = items
+        .into_iter()
+        .filter(|item| item.status() == Status::Open)
+        .filter(|item| {
+            item.blocked_by().iter().any(|id| id == closed.id())
+                || closed.blocks().iter().any(|id| id == item.id())
+        });
+    for dependent in dependents {
+        let remaining: Vec<String> = links::open_blockers(&dependent, &open_ids)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        if remaining.is_empty() {
+            unblocked.push(dependent);
+        } else {
+            still_blocked.push((dependent, remaining));
+        }
+    }
+
+    Dependents {
+        open_blockers,
+        unblocked,
+        still_blocked,
+    }
+}
+
+/// Reports how closing `closed` affects linked items.
+///
+/// Prints open dependents that are now unblocked, and warns about dependents
+/// still waiting on other open items and about open items `closed` was blocked by.
+pub(crate) fn report_dependents(config: &Config, closed: &Item) {
+    print_dependents(closed, &find_dependents(config, closed));
+}
+
+/// Prints dependents found by [`find_dependents`] (see [`report_dependents`]).
+pub(crate) fn print_dependents(closed: &Item, dependents: &Dependents) {
+    for dependent in &dependents.unblocked {
+        println!(
+            "  {} Unblocked: {} {}",
+            "→".cyan(),
+            dependent.id(),
+            dependent.title()
+        );
+    }
+    ui::print_warnings(&dependents.warnings(closed));
 }
@@ -0,0 +1,55 @@
+//! # Comment Command
+//!
+//! Appends a timestamped, author-attributed comment to an item.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use chrono::Utc;
+
+use crate::{config::Config, item::Comment, storage, ui};
+
+/// Arguments for the comment command
+#[derive(Default)]
+pub struct CommentArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Comment text (`None` or `-` reads from stdin)
+    pub text: Option<String>,
+}
+
+/// Executes the comment command.
+pub fn execute(args: &CommentArgs) -> Result<()> {
+    let mut config = Config::load()?;
+
+    let text = match args.text.as_deref() {
+        Some(text) => ui::read_text_arg(text, "comment text")?,
+        None => ui::read_stdin("comment text")?,
+    };
+
+    // Resolve item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let loaded = item_ref.resolve(&config)?;
+
+    let author = config.user_name_or_prompt()?;
+    let path = add_comment(loaded, author, &text)?;
+
+    ui::print_success("Commented on", &config, &path);
+    Ok(())
+}
+
+/// Appends a comment to a loaded item and saves it, returning the item path.
+pub fn add_comment(loaded: storage::LoadedItem, author: String, text: &str) -> Result<PathBuf> {
+    if text.trim().is_empty() {
+        bail!("Comment cannot be empty");
+    }
+
+    let storage::LoadedItem { path, mut item } = loaded;
+    item.add_comment(&Comment::new(author, Utc::now(), text));
+    item.touch();
+    item.save(&path)?;
+    Ok(path)
+}
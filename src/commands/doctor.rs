@@ -0,0 +1,433 @@
+//! # Doctor Command
+//!
+//! Checks the queuestack directory for problems that the other commands
+//! silently work around: files that fail to parse, duplicate IDs, filenames
+//! that no longer match the title, items whose status disagrees with their
+//! location, and attachments that are missing or have lost their item.
+//!
+//! `--fix` repairs what can be repaired without losing data: renaming and
+//! moving files and listing attachments in their item's frontmatter. Parse
+//! errors, duplicate IDs, and missing attachments need a human.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    constants::ITEM_FILE_EXTENSION,
+    item::{is_url, Item, Status},
+    storage::{self, AttachmentFileName, ParseFailure},
+    ui,
+};
+
+/// Upper bound on repair rounds; a repair can reveal another one on the
+/// same file (e.g. a rename followed by a move to the archive).
+const MAX_FIX_PASSES: usize = 3;
+
+/// Arguments for the doctor command
+#[derive(Default)]
+pub struct DoctorArgs {
+    /// Apply the safe repairs
+    pub fix: bool,
+}
+
+/// The kind of problem found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// Item file that fails to parse
+    ParseError,
+    /// Item sharing its ID with an earlier file
+    DuplicateId,
+    /// Filename that doesn't match the item's ID and title
+    FilenameMismatch,
+    /// Item whose status doesn't match its directory
+    WrongLocation,
+    /// Attachment listed in the frontmatter but missing on disk
+    MissingAttachment,
+    /// Attachment file that isn't listed by an item in its directory
+    OrphanedAttachment,
+}
+
+/// A safe repair for a problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repair {
+    /// Rename the item file (in place)
+    Rename(String),
+    /// Move the item to the archive
+    Archive,
+    /// Move the item out of the archive
+    Unarchive,
+    /// Move the attachment into its item's directory
+    MoveAttachment(PathBuf),
+    /// List the attachment in the frontmatter of the item at the path
+    LinkAttachment(PathBuf),
+}
+
+/// A problem with one file.
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub kind: ProblemKind,
+    /// File with the problem
+    pub path: PathBuf,
+    pub message: String,
+    /// Repair applied by `--fix`, if the problem has a safe one
+    pub repair: Option<Repair>,
+}
+
+/// Executes the doctor command.
+///
+/// Fails when problems remain, so scripts and CI can rely on the exit code.
+pub fn execute(args: &DoctorArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    if args.fix {
+        let fixed = fix(&config);
+        if fixed > 0 {
+            println!();
+        }
+    }
+
+    let problems = diagnose(&config);
+    if problems.is_empty() {
+        println!("{} No problems found.", "✓".green());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        print_problem(&config, problem);
+    }
+
+    let fixable = problems.iter().filter(|p| p.repair.is_some()).count();
+    let total = plural(problems.len(), "problem");
+    if fixable > 0 && !args.fix {
+        println!(
+            "{}",
+            format!("{total} found, {fixable} can be fixed with --fix").dimmed()
+        );
+    } else {
+        println!("{}", format!("{total} found").dimmed());
+    }
+    bail!("Found {total}");
+}
+
+/// Checks every file in the queuestack directory.
+///
+/// Problems are ordered by path.
+pub fn diagnose(config: &Config) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut items = Vec::new();
+    let mut attachments = Vec::new();
+
+    for path in storage::walk_stack_files(config) {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if let Some(attachment) = AttachmentFileName::parse(name) {
+            attachments.push((path, attachment));
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == ITEM_FILE_EXTENSION)
+        {
+            match Item::load(&path) {
+                Ok(item) => items.push((path, item)),
+                Err(err) => {
+                    let failure = ParseFailure::new(&path, &err);
+                    let message = match failure.location {
+                        Some((line, column)) => format!(
+                            "Failed to parse (line {line}, column {column}): {}",
+                            failure.message
+                        ),
+                        None => format!("Failed to parse: {}", failure.message),
+                    };
+                    problems.push(Problem {
+                        kind: ProblemKind::ParseError,
+                        path,
+                        message,
+                        repair: None,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut first_by_id: HashMap<&str, &Path> = HashMap::new();
+    for (path, item) in &items {
+        if let Some(first) = first_by_id.get(item.id()) {
+            problems.push(Problem {
+                kind: ProblemKind::DuplicateId,
+                path: path.clone(),
+                message: format!(
+                    "Duplicate ID {} (also used by {})",
+                    item.id(),
+                    config.relative_path(first).display()
+                ),
+                repair: None,
+            });
+        } else {
+            first_by_id.insert(item.id(), path);
+        }
+        check_item(config, path, item, &mut problems);
+    }
+
+    for (path, attachment) in &attachments {
+        check_attachment(config, path, attachment, &items, &mut problems);
+    }
+
+    problems.sort_by(|a, b| a.path.cmp(&b.path));
+    problems
+}
+
+/// Checks an item's filename, location, and attachment list.
+fn check_item(config: &Config, path: &Path, item: &Item, problems: &mut Vec<Problem>) {
+    let dir = path.parent().unwrap_or(path);
+    let mut report = |kind, message: String, repair| {
+        problems.push(Problem {
+            kind,
+            path: path.to_path_buf(),
+            message,
+            repair,
+        });
+    };
+
+    let expected = item.filename();
+    if path.file_name().and_then(|n| n.to_str()) != Some(expected.as_str()) {
+        let repair = (!dir.join(&expected).exists()).then(|| Repair::Rename(expected.clone()));
+        report(
+            ProblemKind::FilenameMismatch,
+            format!("Filename doesn't match the ID and title (expected {expected})"),
+            repair,
+        );
+    }
+
+    let in_archive = path.starts_with(config.archive_path());
+    let in_templates = path.starts_with(config.template_path());
+    match item.status() {
+        Status::Template if !in_templates => report(
+            ProblemKind::WrongLocation,
+            "Template outside the template directory".to_string(),
+            None,
+        ),
+        status if status != Status::Template && in_templates => report(
+            ProblemKind::WrongLocation,
+            format!("Item with status '{status}' in the template directory"),
+            None,
+        ),
+        Status::Closed if !in_archive => report(
+            ProblemKind::WrongLocation,
+            "Closed item outside the archive".to_string(),
+            Some(Repair::Archive),
+        ),
+        Status::Open if in_archive => report(
+            ProblemKind::WrongLocation,
+            "Open item in the archive".to_string(),
+            Some(Repair::Unarchive),
+        ),
+        _ => {}
+    }
+
+    for attachment in item.attachments() {
+        if !is_url(attachment) && !dir.join(attachment).is_file() {
+            report(
+                ProblemKind::MissingAttachment,
+                format!("Attachment {attachment} is missing"),
+                None,
+            );
+        }
+    }
+}
+
+/// Checks that an attachment file belongs to an item in its directory.
+fn check_attachment(
+    config: &Config,
+    path: &Path,
+    attachment: &AttachmentFileName,
+    items: &[(PathBuf, Item)],
+    problems: &mut Vec<Problem>,
+) {
+    let Some(filename) = path.file_name().and_then(|n| n.to_str()) else {
+        return;
+    };
+    let dir = path.parent().unwrap_or(path);
+    let owners: Vec<&PathBuf> = items
+        .iter()
+        .filter(|(_, item)| item.id() == attachment.item_id)
+        .map(|(item_path, _)| item_path)
+        .collect();
+
+    let (message, repair) = if let Some(owner) = owners.iter().find(|p| p.parent() == Some(dir)) {
+        let listed = items
+            .iter()
+            .any(|(p, item)| p == *owner && item.attachments().iter().any(|a| a == filename));
+        if listed {
+            return;
+        }
+        (
+            "Attachment is not listed by its item".to_string(),
+            Some(Repair::LinkAttachment((*owner).clone())),
+        )
+    } else if let [owner] = owners.as_slice() {
+        let owner_dir = owner.parent().unwrap_or(owner);
+        let repair = (!owner_dir.join(filename).exists())
+            .then(|| Repair::MoveAttachment(owner_dir.to_path_buf()));
+        (
+            format!(
+                "Attachment is not next to its item ({})",
+                config.relative_path(owner).display()
+            ),
+            repair,
+        )
+    } else {
+        (
+            format!(
+                "Attachment has no item (no item with ID {})",
+                attachment.item_id
+            ),
+            None,
+        )
+    };
+
+    problems.push(Problem {
+        kind: ProblemKind::OrphanedAttachment,
+        path: path.to_path_buf(),
+        message,
+        repair,
+    });
+}
+
+/// Applies all safe repairs and returns how many succeeded.
+///
+/// Each file is repaired at most once per pass, then the directory is
+/// checked again, since a repair moves files that other repairs refer to.
+/// Failed repairs are reported and not retried.
+pub fn fix(config: &Config) -> usize {
+    let mut fixed = 0;
+    let mut failed = HashSet::new();
+
+    for _ in 0..MAX_FIX_PASSES {
+        let mut touched = HashSet::new();
+        let mut progress = false;
+
+        for problem in diagnose(config) {
+            let Some(repair) = &problem.repair else {
+                continue;
+            };
+            if failed.contains(&problem.path) {
+                continue;
+            }
+            let item_path = match repair {
+                Repair::LinkAttachment(item_path) => Some(item_path.clone()),
+                _ => None,
+            };
+            if touched.contains(&problem.path)
+                || item_path.as_ref().is_some_and(|p| touched.contains(p))
+            {
+                continue;
+            }
+
+            match apply_repair(config, &problem.path, repair) {
+                Ok(new_path) => {
+                    println!(
+                        "{} Fixed {}: {}",
+                        "✓".green(),
+                        config.relative_path(&problem.path).display(),
+                        describe(config, repair, &new_path)
+                    );
+                    fixed += 1;
+                    progress = true;
+                    touched.insert(new_path);
+                    touched.extend(item_path);
+                }
+                Err(err) => {
+                    eprintln!(
+                        "{} {}: {err:#}",
+                        "✗".red(),
+                        config.relative_path(&problem.path).display()
+                    );
+                    failed.insert(problem.path.clone());
+                }
+            }
+            touched.insert(problem.path);
+        }
+
+        if !progress {
+            break;
+        }
+    }
+    fixed
+}
+
+/// Applies one repair and returns the file's new path.
+pub fn apply_repair(config: &Config, path: &Path, repair: &Repair) -> Result<PathBuf> {
+    match repair {
+        Repair::Rename(filename) => storage::rename_item(path, filename),
+        Repair::Archive | Repair::Unarchive => {
+            let (new_path, warnings) = if *repair == Repair::Archive {
+                storage::archive_item(config, path)?
+            } else {
+                storage::unarchive_item(config, path)?
+            };
+            ui::print_warnings(&warnings);
+            Ok(new_path)
+        }
+        Repair::MoveAttachment(dir) => {
+            let Some(filename) = path.file_name() else {
+                bail!("Invalid attachment path");
+            };
+            let new_path = dir.join(filename);
+            storage::git::move_file(path, &new_path)?;
+            Ok(new_path)
+        }
+        Repair::LinkAttachment(item_path) => {
+            let Some(filename) = path.file_name().and_then(|n| n.to_str()) else {
+                bail!("Invalid attachment path");
+            };
+            let mut item = Item::load(item_path)?;
+            item.touch();
+            item.add_attachment(filename.to_string());
+            item.save(item_path)?;
+            Ok(path.to_path_buf())
+        }
+    }
+}
+
+/// Describes an applied repair, e.g. `renamed to ...`.
+fn describe(config: &Config, repair: &Repair, new_path: &Path) -> String {
+    let new_path = config.relative_path(new_path);
+    match repair {
+        Repair::Rename(filename) => format!("renamed to {filename}"),
+        Repair::Archive => format!("archived to {}", new_path.display()),
+        Repair::Unarchive => format!("restored to {}", new_path.display()),
+        Repair::MoveAttachment(_) => format!("moved to {}", new_path.display()),
+        Repair::LinkAttachment(item_path) => {
+            format!("listed in {}", config.relative_path(item_path).display())
+        }
+    }
+}
+
+/// Prints one problem.
+fn print_problem(config: &Config, problem: &Problem) {
+    let relative = config.relative_path(&problem.path);
+    let marker = if problem.repair.is_some() {
+        "!".yellow().to_string()
+    } else {
+        "✗".red().to_string()
+    };
+    println!("{marker} {}: {}", relative.display(), problem.message);
+}
+
+/// Formats a count with a noun, e.g. `1 problem` or `3 problems`.
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
@@ -0,0 +1,459 @@
+//! # Export Command
+//!
+//! Writes the items matching `qs list` filters as CSV, JSON, JSON Lines, or a
+//! self-contained HTML report.
+//!
+//! CSV has one column per frontmatter field (custom fields after the built-in
+//! ones), JSON uses the `--format json` record schema with bodies included,
+//! and HTML groups items into one section per category with rendered bodies
+//! and links to local attachments.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    env,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use owo_colors::OwoColorize;
+
+use crate::{
+    commands::list::{self, ListOptions},
+    config::Config,
+    csv,
+    item::{fields, is_url, Item},
+    markdown::{self, escape_html},
+    output::{self, ItemRecord, OutputFormat},
+    storage, ui,
+};
+
+/// Built-in CSV columns, in order. Custom fields follow.
+const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "state",
+    "category",
+    "labels",
+    "assignees",
+    "priority",
+    "due",
+    "author",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "blocks",
+    "blocked_by",
+    "related",
+    "attachments",
+    "path",
+];
+
+/// Arguments for the export command.
+pub struct ExportArgs {
+    /// Item filters, output format, and strict mode (as for `qs list`)
+    pub filter: ListOptions,
+    /// File to write (stdout if `None` or `-`)
+    pub output: Option<PathBuf>,
+}
+
+/// Executes the export command.
+pub fn execute(args: &ExportArgs) -> Result<()> {
+    let config = Config::load()?;
+    export(args, &config)
+}
+
+/// Exports the matching items to the output file or stdout.
+fn export(args: &ExportArgs, config: &Config) -> Result<()> {
+    let format = args.filter.format;
+    if format == OutputFormat::Text {
+        bail!("Choose an export format with --format csv, json, jsonl, or html");
+    }
+
+    let (items, failures) = list::filter_items(&args.filter, args.filter.view.as_deref(), config)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+    let output = args
+        .output
+        .as_deref()
+        .filter(|path| *path != Path::new("-"));
+    let content = render(&items, config, format, output)?;
+
+    let Some(path) = output else {
+        let mut out = io::stdout().lock();
+        return out
+            .write_all(content.as_bytes())
+            .context("Failed to write output");
+    };
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    let noun = if items.len() == 1 { "item" } else { "items" };
+    println!(
+        "{} Exported {} {noun} to {}",
+        "✓".green(),
+        items.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Renders items in an export format.
+///
+/// `output` is the file being written; HTML links attachments relative to its
+/// directory (or the current directory when writing to stdout).
+pub fn render(
+    items: &[Item],
+    config: &Config,
+    format: OutputFormat,
+    output: Option<&Path>,
+) -> Result<String> {
+    match format {
+        OutputFormat::Csv => Ok(to_csv(items, config)),
+        OutputFormat::Html => Ok(to_html(items, config, &link_base(output)?)),
+        OutputFormat::Json | OutputFormat::Jsonl => {
+            let records: Vec<ItemRecord> = items
+                .iter()
+                .map(|item| ItemRecord::new(item, config, true))
+                .collect();
+            let mut buf = Vec::new();
+            output::write_records(&mut buf, format, &records)?;
+            Ok(String::from_utf8(buf)?)
+        }
+        OutputFormat::Text => bail!("Text is not an export format"),
+    }
+}
+
+// =============================================================================
+// CSV
+// =============================================================================
+
+/// Writes items as CSV with a header row.
+fn to_csv(items: &[Item], config: &Config) -> String {
+    // Custom fields in the order they first appear
+    let mut custom: Vec<String> = Vec::new();
+    for item in items {
+        for key in item.frontmatter.extra.keys() {
+            if !custom.contains(key) {
+                custom.push(key.clone());
+            }
+        }
+    }
+
+    let mut out = String::new();
+    let header = CSV_COLUMNS
+        .iter()
+        .map(|column| (*column).to_string())
+        .chain(custom.iter().cloned());
+    csv::write_row(&mut out, header);
+
+    for item in items {
+        let record = ItemRecord::new(item, config, false);
+        let builtin = [
+            record.id,
+            record.title,
+            record.status.to_string(),
+            record.state.unwrap_or_default(),
+            record.category.unwrap_or_default(),
+            record.labels.join(", "),
+            record.assignees.join(", "),
+            record.priority.unwrap_or_default(),
+            record.due.map(|due| due.to_string()).unwrap_or_default(),
+            record.author,
+            timestamp(record.created_at),
+            record.updated_at.map(timestamp).unwrap_or_default(),
+            record.closed_at.map(timestamp).unwrap_or_default(),
+            record.blocks.join(", "),
+            record.blocked_by.join(", "),
+            record.related.join(", "),
+            record.attachments.join(", "),
+            record.path,
+        ];
+        let extra = custom.iter().map(|key| {
+            record
+                .fields
+                .get(key.as_str())
+                .map(fields::display_value)
+                .unwrap_or_default()
+        });
+        csv::write_row(&mut out, builtin.into_iter().chain(extra));
+    }
+    out
+}
+
+/// Formats a timestamp as RFC 3339 with whole seconds.
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+// =============================================================================
+// HTML
+// =============================================================================
+
+/// Stylesheet embedded in the report.
+const STYLE: &str = "\
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; \
+max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #222; line-height: 1.5; }
+header { border-bottom: 2px solid #ddd; margin-bottom: 1.5rem; }
+nav ul { list-style: none; padding: 0; display: flex; flex-wrap: wrap; gap: 0.5rem 1.5rem; }
+section > h2 { border-bottom: 1px solid #ddd; padding-bottom: 0.25rem; }
+article { border: 1px solid #e3e3e3; border-radius: 6px; padding: 0.5rem 1rem; margin: 1rem 0; }
+article h3 { margin: 0.5rem 0; }
+.id { color: #777; font-family: monospace; font-weight: normal; margin-right: 0.5rem; }
+.count, .meta { color: #777; font-weight: normal; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 0.15rem 1rem; margin: 0.5rem 0; }
+dt { color: #555; font-weight: 600; }
+dd { margin: 0; }
+.state { background: #eef; border-radius: 4px; padding: 0 0.4rem; }
+.missing { color: #b00; }
+.body { border-top: 1px solid #eee; margin-top: 0.5rem; }
+.comment { border-left: 3px solid #ddd; padding-left: 0.75rem; margin: 0.5rem 0; }
+pre { background: #f6f6f6; padding: 0.5rem; overflow-x: auto; }
+code { background: #f6f6f6; padding: 0 0.2rem; }
+table { border-collapse: collapse; }
+td, th { border: 1px solid #ddd; padding: 0.2rem 0.5rem; }
+";
+
+/// Writes items as a standalone HTML page with one section per category.
+fn to_html(items: &[Item], config: &Config, base: &Path) -> String {
+    let project = config.project_root().file_name().map_or_else(
+        || "queuestack".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let exported: HashSet<&str> = items.iter().map(Item::id).collect();
+
+    // Uncategorized items first, then categories alphabetically
+    let mut sections: BTreeMap<Option<String>, Vec<&Item>> = BTreeMap::new();
+    for item in items {
+        let category = item
+            .path
+            .as_ref()
+            .and_then(|path| storage::derive_category(config, path));
+        sections.entry(category).or_default().push(item);
+    }
+
+    let mut out = String::new();
+    let title = escape_html(&project);
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title} — queuestack</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n"
+    );
+    let noun = if items.len() == 1 { "item" } else { "items" };
+    let _ = writeln!(
+        out,
+        "<header>\n<h1>{title}</h1>\n<p class=\"meta\">{} {noun}, exported {}</p>",
+        items.len(),
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    );
+    out.push_str("<nav><ul>\n");
+    for (category, items) in &sections {
+        let _ = writeln!(
+            out,
+            "<li><a href=\"#{}\">{}</a> <span class=\"count\">{}</span></li>",
+            section_anchor(category.as_deref()),
+            escape_html(section_title(category.as_deref())),
+            items.len()
+        );
+    }
+    out.push_str("</ul></nav>\n</header>\n");
+
+    for (category, items) in &sections {
+        let _ = writeln!(
+            out,
+            "<section id=\"{}\">\n<h2>{} <span class=\"count\">{}</span></h2>",
+            section_anchor(category.as_deref()),
+            escape_html(section_title(category.as_deref())),
+            items.len()
+        );
+        for item in items {
+            write_html_item(&mut out, item, config, base, &exported);
+        }
+        out.push_str("</section>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Writes a single item as an `<article>`.
+fn write_html_item(
+    out: &mut String,
+    item: &Item,
+    config: &Config,
+    base: &Path,
+    exported: &HashSet<&str>,
+) {
+    let fm = &item.frontmatter;
+    let id = escape_html(item.id());
+    let _ = writeln!(
+        out,
+        "<article id=\"item-{id}\">\n<h3><span class=\"id\">{id}</span>{}</h3>\n<dl>",
+        escape_html(item.title())
+    );
+
+    let mut row = |name: &str, value: String| {
+        if !value.is_empty() {
+            let _ = writeln!(out, "<dt>{name}</dt><dd>{value}</dd>");
+        }
+    };
+    let text = |values: &[String]| escape_html(&values.join(", "));
+    let links = |ids: &[String]| {
+        ids.iter()
+            .map(|id| {
+                if exported.contains(id.as_str()) {
+                    format!("<a href=\"#item-{0}\">{0}</a>", escape_html(id))
+                } else {
+                    escape_html(id)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    row(
+        "State",
+        format!(
+            "<span class=\"state\">{}</span>",
+            escape_html(&config.display_state(item))
+        ),
+    );
+    row("Priority", escape_html(item.priority().unwrap_or_default()));
+    row("Due", fm.due.map(|due| due.to_string()).unwrap_or_default());
+    row("Labels", text(item.labels()));
+    row("Assignees", text(item.assignees()));
+    row("Author", escape_html(item.author()));
+    row("Created", timestamp(fm.created_at));
+    row("Updated", fm.updated_at.map(timestamp).unwrap_or_default());
+    row("Closed", fm.closed_at.map(timestamp).unwrap_or_default());
+    row("Blocks", links(item.blocks()));
+    row("Blocked by", links(item.blocked_by()));
+    row("Related", links(item.related()));
+    for (key, value) in &fm.extra {
+        row(
+            &escape_html(key),
+            escape_html(&fields::display_value(value)),
+        );
+    }
+    out.push_str("</dl>\n");
+
+    let description = item.description().trim();
+    if !description.is_empty() {
+        let _ = write!(
+            out,
+            "<div class=\"body\">\n{}</div>\n",
+            markdown::to_html(description)
+        );
+    }
+
+    let comments = item.comments();
+    if !comments.is_empty() {
+        out.push_str("<h4>Comments</h4>\n");
+        for comment in comments {
+            let _ = write!(
+                out,
+                "<div class=\"comment\">\n<p class=\"meta\">{} — {}</p>\n{}</div>\n",
+                escape_html(&comment.author),
+                timestamp(comment.created_at),
+                markdown::to_html(&comment.text)
+            );
+        }
+    }
+
+    if !item.attachments().is_empty() {
+        out.push_str("<h4>Attachments</h4>\n<ul>\n");
+        for attachment in item.attachments() {
+            let _ = writeln!(out, "<li>{}</li>", attachment_link(item, attachment, base));
+        }
+        out.push_str("</ul>\n");
+    }
+    out.push_str("</article>\n");
+}
+
+/// Links an attachment: URLs directly, files relative to the report.
+fn attachment_link(item: &Item, attachment: &str, base: &Path) -> String {
+    let name = escape_html(attachment);
+    if is_url(attachment) {
+        return format!("<a href=\"{name}\">{name}</a>");
+    }
+    let path = item
+        .path
+        .as_ref()
+        .and_then(|path| path.parent())
+        .map(|dir| dir.join(attachment))
+        .and_then(|path| path.canonicalize().ok());
+    path.map_or_else(
+        || format!("<span class=\"missing\">{name} (missing)</span>"),
+        |path| format!("<a href=\"{}\">{name}</a>", relative_href(&path, base)),
+    )
+}
+
+/// Returns the section title for a category.
+fn section_title(category: Option<&str>) -> &str {
+    category.unwrap_or("Uncategorized")
+}
+
+/// Returns the anchor of a category section.
+fn section_anchor(category: Option<&str>) -> String {
+    category.map_or_else(
+        || "uncategorized".to_string(),
+        |category| format!("category-{}", percent_encode(category)),
+    )
+}
+
+/// Returns the directory attachment links are relative to.
+fn link_base(output: Option<&Path>) -> Result<PathBuf> {
+    let cwd = env::current_dir().context("Failed to get current directory")?;
+    let dir = output
+        .and_then(Path::parent)
+        .map_or_else(|| cwd.clone(), |parent| cwd.join(parent));
+    Ok(dir.canonicalize().unwrap_or(dir))
+}
+
+/// Builds a relative URL from `base` (a directory) to `target`.
+fn relative_href(target: &Path, base: &Path) -> String {
+    let target: Vec<_> = target.components().collect();
+    let base: Vec<_> = base.components().collect();
+    let common = target.iter().zip(&base).take_while(|(a, b)| a == b).count();
+
+    let up = std::iter::repeat("..".to_string()).take(base.len() - common);
+    let down = target[common..]
+        .iter()
+        .map(|part| percent_encode(&part.as_os_str().to_string_lossy()));
+    up.chain(down).collect::<Vec<_>>().join("/")
+}
+
+/// Percent-encodes everything except unreserved URL characters.
+fn percent_encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_href() {
+        let target = Path::new("/project/queuestack/bugs/260101-AAA-Attachment-1-my log.txt");
+        assert_eq!(
+            relative_href(target, Path::new("/project/reports")),
+            "../queuestack/bugs/260101-AAA-Attachment-1-my%20log.txt"
+        );
+        assert_eq!(
+            relative_href(target, Path::new("/project")),
+            "queuestack/bugs/260101-AAA-Attachment-1-my%20log.txt"
+        );
+    }
+}
@@ -0,0 +1,611 @@
+//! # Import Command
+//!
+//! Creates items from another tracker's issue export: GitHub JSON (`gh issue
+//! list --json` or the REST API), GitLab JSON (REST API) or CSV ("Export as
+//! CSV"), and Jira JSON (REST search API) or CSV ("Export CSV").
+//!
+//! Titles, bodies, labels, authors, assignees, timestamps, and open/closed
+//! state are mapped onto the frontmatter. IDs are generated for the original
+//! creation time, closed issues go straight to the archive, and the issue's
+//! URL is recorded as an attachment. Issues whose URL is already attached to
+//! an item are skipped, so importing the same file twice is safe.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::HashSet, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use owo_colors::OwoColorize;
+use serde_json::Value;
+
+use crate::{
+    config::{schema, Config},
+    csv, id,
+    item::{is_url, normalize_identifier, Frontmatter, Item, Status},
+    storage, ui,
+};
+
+/// Attempts at a unique ID before giving up (the pattern may lack random tokens).
+const MAX_ID_ATTEMPTS: u32 = 10_000;
+
+/// Issue export formats `qs import` understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Source {
+    /// `gh issue list --json ...` output or the REST issues API
+    GithubJson,
+    /// The REST issues API
+    GitlabJson,
+    /// The issue list's "Export as CSV"
+    GitlabCsv,
+    /// The REST search API (`{"issues": [...]}`)
+    JiraJson,
+    /// "Export CSV (all fields)" from the issue navigator
+    JiraCsv,
+}
+
+/// Arguments for the import command
+pub struct ImportArgs {
+    pub source: Source,
+    /// Export file (`-` reads from stdin)
+    pub file: PathBuf,
+    /// Category for all imported items
+    pub category: Option<String>,
+    /// Labels added to all imported items
+    pub labels: Vec<String>,
+    /// Custom fields as `(key, value)` pairs, set on all imported items
+    pub fields: Vec<(String, String)>,
+    /// Print what would be imported without creating anything
+    pub dry_run: bool,
+}
+
+/// An issue read from another tracker.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Issue {
+    pub title: String,
+    /// Description (Markdown or plain text)
+    pub body: String,
+    pub labels: Vec<String>,
+    pub author: Option<String>,
+    pub assignees: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub closed: bool,
+    pub due: Option<NaiveDate>,
+    /// Link to the issue in the original tracker
+    pub url: Option<String>,
+}
+
+/// Executes the import command.
+pub fn execute(args: &ImportArgs) -> Result<()> {
+    let config = Config::load()?;
+    import(args, &config)
+}
+
+/// Imports all issues from the export file.
+fn import(args: &ImportArgs, config: &Config) -> Result<()> {
+    let text = if args.file.as_os_str() == "-" {
+        ui::read_stdin("issues")?
+    } else {
+        fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}", args.file.display()))?
+    };
+    let issues = parse(args.source, &text)?;
+
+    // Existing IDs must not be reused; attached URLs mark imported issues
+    let (existing, failures) = storage::load_all_items(config);
+    ui::print_parse_failures(config, &failures);
+    let mut ids: HashSet<String> = existing.iter().map(|item| item.id().to_string()).collect();
+    let imported_urls: HashSet<&str> = existing
+        .iter()
+        .flat_map(Item::attachments)
+        .filter(|attachment| is_url(attachment))
+        .map(String::as_str)
+        .collect();
+    let category = args.category.as_deref().map(normalize_identifier);
+
+    let (mut created, mut skipped) = (0, 0);
+    for issue in &issues {
+        if issue
+            .url
+            .as_deref()
+            .is_some_and(|url| imported_urls.contains(url))
+        {
+            println!("{} Already imported: {}", "·".dimmed(), issue.title);
+            skipped += 1;
+            continue;
+        }
+
+        let item = to_item(config, issue, args, &mut ids)?;
+        if args.dry_run {
+            println!("{} Would import: {}", "→".cyan(), issue.title);
+            created += 1;
+            continue;
+        }
+
+        let mut path = storage::create_item(config, &item, category.as_deref())?;
+        if issue.closed {
+            let (archived, warnings) = storage::archive_item(config, &path)?;
+            ui::print_warnings(&warnings);
+            path = archived;
+        }
+        ui::print_success("Imported", config, &path);
+        created += 1;
+    }
+
+    let created = if args.dry_run {
+        format!("{created} would be imported")
+    } else {
+        format!("{created} imported")
+    };
+    println!(
+        "{}",
+        format!("{created}, {skipped} already imported").dimmed()
+    );
+    Ok(())
+}
+
+/// Builds the item for an issue, reserving a fresh ID in `ids`.
+fn to_item(
+    config: &Config,
+    issue: &Issue,
+    args: &ImportArgs,
+    ids: &mut HashSet<String>,
+) -> Result<Item> {
+    let created_at = issue.created_at.unwrap_or_else(Utc::now);
+    let mut labels: Vec<String> = Vec::new();
+    for label in issue.labels.iter().chain(&args.labels) {
+        let label = normalize_identifier(label);
+        if !label.is_empty() && !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+
+    let workflow = config.workflow();
+    let (status, state, closed_at) = if issue.closed {
+        let closed_at = issue.closed_at.or(issue.updated_at).unwrap_or(created_at);
+        (
+            Status::Closed,
+            config
+                .has_workflow()
+                .then(|| workflow.closed_state().to_string()),
+            Some(closed_at),
+        )
+    } else {
+        (Status::Open, config.initial_state(), None)
+    };
+
+    let frontmatter = Frontmatter {
+        id: unique_id(config.id_pattern(), created_at, ids)?,
+        title: issue.title.clone(),
+        author: issue
+            .author
+            .clone()
+            .or_else(|| config.user_name())
+            .unwrap_or_default(),
+        created_at,
+        updated_at: issue.updated_at.filter(|updated| *updated > created_at),
+        closed_at,
+        status,
+        state,
+        labels,
+        attachments: issue.url.iter().cloned().collect(),
+        due: issue.due,
+        ..Frontmatter::default()
+    };
+
+    let mut item = Item::new(frontmatter);
+    for name in &issue.assignees {
+        item.assign(name);
+    }
+    let body = issue.body.replace("\r\n", "\n");
+    if !body.trim().is_empty() {
+        item.set_description(&body);
+    }
+    schema::apply_to_new_item(config.field_schema(), &mut item, &args.fields)?;
+    Ok(item)
+}
+
+/// Generates an ID for the creation time that isn't taken yet.
+///
+/// Retries with new random parts, then moves on by a second at a time.
+fn unique_id(
+    pattern: &str,
+    created_at: DateTime<Utc>,
+    ids: &mut HashSet<String>,
+) -> Result<String> {
+    let mut time = created_at;
+    for attempt in 1..=MAX_ID_ATTEMPTS {
+        let id = id::generate_at(pattern, time);
+        if ids.insert(id.clone()) {
+            return Ok(id);
+        }
+        if attempt % 16 == 0 {
+            time += Duration::seconds(1);
+        }
+    }
+    bail!("Could not generate a unique ID with pattern '{pattern}'")
+}
+
+// =============================================================================
+// Parsing
+// =============================================================================
+
+/// Parses an export file into issues.
+///
+/// Pull requests in GitHub REST exports are skipped.
+pub fn parse(source: Source, text: &str) -> Result<Vec<Issue>> {
+    let issues: Vec<Issue> = match source {
+        Source::GithubJson => parse_json(text)?
+            .as_array()
+            .context("Expected a JSON array of issues")?
+            .iter()
+            .filter(|issue| issue.get("pull_request").is_none())
+            .map(github_issue)
+            .collect(),
+        Source::GitlabJson => parse_json(text)?
+            .as_array()
+            .context("Expected a JSON array of issues")?
+            .iter()
+            .map(gitlab_issue)
+            .collect(),
+        Source::JiraJson => {
+            let value = parse_json(text)?;
+            value
+                .get("issues")
+                .unwrap_or(&value)
+                .as_array()
+                .context("Expected a JSON object with an \"issues\" array")?
+                .iter()
+                .map(jira_issue)
+                .collect()
+        }
+        Source::GitlabCsv => Table::parse(text)?.rows().map(gitlab_row).collect(),
+        Source::JiraCsv => Table::parse(text)?.rows().map(jira_row).collect(),
+    };
+
+    if let Some(n) = issues.iter().position(|issue| issue.title.is_empty()) {
+        bail!("Issue {} has no title", n + 1);
+    }
+    Ok(issues)
+}
+
+fn parse_json(text: &str) -> Result<Value> {
+    serde_json::from_str(text).context("Failed to parse JSON")
+}
+
+/// Maps a `gh issue list --json` or REST API issue.
+fn github_issue(issue: &Value) -> Issue {
+    Issue {
+        title: text(issue, &["title"]).unwrap_or_default(),
+        body: text(issue, &["body"]).unwrap_or_default(),
+        labels: names(issue.get("labels")),
+        author: person(issue, &["author", "user"]),
+        assignees: names(issue.get("assignees")),
+        created_at: time(issue, &["createdAt", "created_at"]),
+        updated_at: time(issue, &["updatedAt", "updated_at"]),
+        closed_at: time(issue, &["closedAt", "closed_at"]),
+        closed: text(issue, &["state"]).is_some_and(|state| state.eq_ignore_ascii_case("closed")),
+        due: None,
+        // The REST API's `url` points at the API, `html_url` at the issue
+        url: text(issue, &["html_url", "url"]),
+    }
+}
+
+/// Maps a GitLab REST API issue.
+fn gitlab_issue(issue: &Value) -> Issue {
+    Issue {
+        title: text(issue, &["title"]).unwrap_or_default(),
+        body: text(issue, &["description"]).unwrap_or_default(),
+        labels: names(issue.get("labels")),
+        author: person(issue, &["author"]),
+        assignees: names(issue.get("assignees")),
+        created_at: time(issue, &["created_at"]),
+        updated_at: time(issue, &["updated_at"]),
+        closed_at: time(issue, &["closed_at"]),
+        closed: text(issue, &["state"]).as_deref() == Some("closed"),
+        due: text(issue, &["due_date"]).and_then(|due| parse_date(&due)),
+        url: text(issue, &["web_url"]),
+    }
+}
+
+/// Maps a Jira REST API issue.
+fn jira_issue(issue: &Value) -> Issue {
+    let empty = Value::Null;
+    let fields = issue.get("fields").unwrap_or(&empty);
+    let status = fields.get("status").unwrap_or(&empty);
+    let done = status
+        .pointer("/statusCategory/key")
+        .and_then(Value::as_str)
+        .map_or_else(
+            || text(status, &["name"]).is_some_and(|name| is_done(&name)),
+            |key| key == "done",
+        );
+
+    // The browse URL lives on the same host as the API (`self`)
+    let url = text(issue, &["self"])
+        .zip(text(issue, &["key"]))
+        .and_then(|(api, key)| {
+            api.find("/rest/")
+                .map(|end| format!("{}/browse/{key}", &api[..end]))
+        });
+
+    Issue {
+        title: text(fields, &["summary"]).unwrap_or_default(),
+        body: fields.get("description").map(adf_text).unwrap_or_default(),
+        labels: names(fields.get("labels")),
+        author: person(fields, &["reporter", "creator"]),
+        assignees: person(fields, &["assignee"]).into_iter().collect(),
+        created_at: time(fields, &["created"]),
+        updated_at: time(fields, &["updated"]),
+        closed_at: time(fields, &["resolutiondate"]),
+        closed: done || fields.get("resolutiondate").is_some_and(Value::is_string),
+        due: text(fields, &["duedate"]).and_then(|due| parse_date(&due)),
+        url,
+    }
+}
+
+/// Maps a row of GitLab's CSV export.
+fn gitlab_row(row: Row<'_>) -> Issue {
+    Issue {
+        title: row.get("Title").unwrap_or_default(),
+        body: row.get("Description").unwrap_or_default(),
+        labels: row.list("Labels"),
+        author: row.get("Author"),
+        assignees: row.list("Assignee"),
+        created_at: row.get("Created At (UTC)").and_then(|t| parse_time(&t)),
+        updated_at: row.get("Updated At (UTC)").and_then(|t| parse_time(&t)),
+        closed_at: row.get("Closed At (UTC)").and_then(|t| parse_time(&t)),
+        closed: row
+            .get("State")
+            .is_some_and(|state| state.eq_ignore_ascii_case("closed")),
+        due: row.get("Due Date").and_then(|due| parse_date(&due)),
+        url: row.get("URL"),
+    }
+}
+
+/// Maps a row of Jira's CSV export.
+///
+/// The export has no issue URLs, so no attachment is recorded.
+fn jira_row(row: Row<'_>) -> Issue {
+    let resolved = row.get("Resolved");
+    Issue {
+        title: row.get("Summary").unwrap_or_default(),
+        body: row.get("Description").unwrap_or_default(),
+        // Jira repeats the column once per label
+        labels: row.all("Labels"),
+        author: row.get("Reporter").or_else(|| row.get("Creator")),
+        assignees: row.get("Assignee").into_iter().collect(),
+        created_at: row.get("Created").and_then(|t| parse_time(&t)),
+        updated_at: row.get("Updated").and_then(|t| parse_time(&t)),
+        closed_at: resolved.as_deref().and_then(parse_time),
+        closed: resolved.is_some() || row.get("Status").is_some_and(|status| is_done(&status)),
+        due: row.get("Due Date").and_then(|due| parse_date(&due)),
+        url: None,
+    }
+}
+
+/// Returns true for status names that mean the issue is finished.
+fn is_done(status: &str) -> bool {
+    ["done", "closed", "resolved"]
+        .iter()
+        .any(|done| status.eq_ignore_ascii_case(done))
+}
+
+// =============================================================================
+// JSON Helpers
+// =============================================================================
+
+/// Returns the first of `keys` holding a non-empty string.
+fn text(value: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| value.get(key)?.as_str())
+        .map(str::trim)
+        .find(|text| !text.is_empty())
+        .map(String::from)
+}
+
+/// Returns a person's name from the first of `keys` holding a user object.
+fn person(value: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| value.get(key))
+        .find_map(person_name)
+}
+
+/// Returns the display name of a user object (or a plain string).
+fn person_name(user: &Value) -> Option<String> {
+    match user {
+        Value::String(name) => Some(name.clone()).filter(|name| !name.is_empty()),
+        user => text(user, &["name", "displayName", "login", "username"]),
+    }
+}
+
+/// Returns the names in an array of strings, label objects, or user objects.
+fn names(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(person_name).collect())
+        .unwrap_or_default()
+}
+
+/// Returns the first of `keys` holding a parseable timestamp.
+fn time(value: &Value, keys: &[&str]) -> Option<DateTime<Utc>> {
+    text(value, keys).and_then(|t| parse_time(&t))
+}
+
+/// Extracts plain text from a Jira description, which is either a string or
+/// an Atlassian Document Format tree.
+fn adf_text(node: &Value) -> String {
+    match node {
+        Value::String(text) => text.clone(),
+        Value::Object(map) => {
+            if let Some(Value::String(text)) = map.get("text") {
+                return text.clone();
+            }
+            if map.get("type").and_then(Value::as_str) == Some("hardBreak") {
+                return "\n".to_string();
+            }
+            let children = map
+                .get("content")
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+            // Blocks are separated by blank lines, inline nodes run together
+            let blocks = children.iter().any(|child| child.get("content").is_some());
+            children
+                .iter()
+                .map(adf_text)
+                .collect::<Vec<_>>()
+                .join(if blocks { "\n\n" } else { "" })
+        }
+        _ => String::new(),
+    }
+}
+
+// =============================================================================
+// Dates
+// =============================================================================
+
+/// Parses the timestamp formats used by the supported exports.
+///
+/// Timestamps without a zone are taken as UTC.
+fn parse_time(text: &str) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Some(time.with_timezone(&Utc));
+    }
+    // Jira REST: 2024-03-05T09:15:00.000+0000
+    if let Ok(time) = DateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(time.with_timezone(&Utc));
+    }
+    // GitLab CSV: 2024-03-05 09:15:00; Jira CSV: 05/Mar/24 9:15 AM
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%d/%b/%y %I:%M %p"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .map(|time| time.and_utc())
+        .or_else(|| {
+            parse_date(text)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|t| t.and_utc())
+        })
+}
+
+/// Parses a date, ignoring any time of day.
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    let date = text.split([' ', 'T']).next().unwrap_or(text);
+    ["%Y-%m-%d", "%d/%b/%y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+}
+
+// =============================================================================
+// CSV Tables
+// =============================================================================
+
+/// A CSV file with a header row.
+struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// A row of a [`Table`], with cells looked up by column name.
+#[derive(Clone, Copy)]
+struct Row<'a> {
+    header: &'a [String],
+    cells: &'a [String],
+}
+
+impl Table {
+    fn parse(text: &str) -> Result<Self> {
+        let mut rows = csv::parse(text)?.into_iter();
+        let Some(header) = rows.next() else {
+            bail!("The CSV file is empty");
+        };
+        let header = header
+            .into_iter()
+            .map(|name| name.trim().to_string())
+            .collect();
+        Ok(Self {
+            header,
+            rows: rows.collect(),
+        })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        self.rows.iter().map(|cells| Row {
+            header: &self.header,
+            cells,
+        })
+    }
+}
+
+impl Row<'_> {
+    /// Returns the non-empty cells of every column with this name.
+    fn all(self, column: &str) -> Vec<String> {
+        self.header
+            .iter()
+            .zip(self.cells)
+            .filter(|(name, _)| name.eq_ignore_ascii_case(column))
+            .map(|(_, cell)| cell.trim())
+            .filter(|cell| !cell.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    /// Returns the first non-empty cell of the column.
+    fn get(self, column: &str) -> Option<String> {
+        self.all(column).into_iter().next()
+    }
+
+    /// Returns the comma-separated values of the column.
+    fn list(self, column: &str) -> Vec<String> {
+        self.get(column)
+            .map(|cell| {
+                cell.split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_formats() {
+        let expected = "2024-03-05T09:15:00Z";
+        for text in [
+            "2024-03-05T09:15:00Z",
+            "2024-03-05T10:15:00+01:00",
+            "2024-03-05T09:15:00.000+0000",
+            "2024-03-05 09:15:00",
+            "05/Mar/24 9:15 AM",
+        ] {
+            let time = parse_time(text).unwrap_or_else(|| panic!("{text}"));
+            assert_eq!(
+                time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                expected
+            );
+        }
+        assert_eq!(parse_date("05/Mar/24"), NaiveDate::from_ymd_opt(2024, 3, 5));
+        assert!(parse_time("yesterday").is_none());
+    }
+
+    #[test]
+    fn test_adf_text() {
+        let doc: Value = serde_json::from_str(
+            r#"{"type": "doc", "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "One "}, {"type": "text", "text": "line"}]},
+                {"type": "paragraph", "content": [{"type": "text", "text": "a"}, {"type": "hardBreak"}, {"type": "text", "text": "b"}]}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(adf_text(&doc), "One line\n\na\nb");
+    }
+}
@@ -0,0 +1,137 @@
+//! # Link Command
+//!
+//! Links items to each other (`blocks`, `blocked_by`, `related`).
+//!
+//! Both sides of a link are updated, so `A blocks B` also records
+//! `B blocked_by A`. Links store item IDs and survive renames and archiving.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, item::LinkKind, storage, ui};
+
+/// Arguments for the link command
+#[derive(Default)]
+pub struct LinkArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    pub blocks: Vec<String>,
+    pub blocked_by: Vec<String>,
+    pub related: Vec<String>,
+    /// Remove the given links instead of adding them
+    pub remove: bool,
+}
+
+/// Executes the link command.
+pub fn execute(args: &LinkArgs) -> Result<()> {
+    let targets: Vec<(LinkKind, &String)> = args
+        .blocks
+        .iter()
+        .map(|t| (LinkKind::Blocks, t))
+        .chain(args.blocked_by.iter().map(|t| (LinkKind::BlockedBy, t)))
+        .chain(args.related.iter().map(|t| (LinkKind::Related, t)))
+        .collect();
+
+    if targets.is_empty() {
+        bail!("No link targets specified (use --blocks, --blocked-by, or --related)");
+    }
+
+    let config = Config::load()?;
+
+    // Resolve source item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+
+    // Resolve and validate every target before writing anything, so a bad
+    // target leaves all items untouched
+    let mut loaded_targets: Vec<(storage::LoadedItem, bool)> = Vec::new();
+    let mut messages = Vec::new();
+
+    for (kind, target_ref) in targets {
+        let loaded = storage::find_and_load(&config, target_ref)?;
+
+        if loaded.item.id() == item.id() {
+            bail!("Cannot link item '{}' to itself", item.id());
+        }
+
+        // The same target may be named more than once (e.g. --blocks and --related)
+        let index = loaded_targets
+            .iter()
+            .position(|(t, _)| t.item.id() == loaded.item.id())
+            .unwrap_or_else(|| {
+                loaded_targets.push((loaded, false));
+                loaded_targets.len() - 1
+            });
+        let (storage::LoadedItem { item: target, .. }, target_dirty) = &mut loaded_targets[index];
+
+        let (source_changed, target_changed) = if args.remove {
+            (
+                item.remove_link(kind, target.id()),
+                target.remove_link(kind.inverse(), item.id()),
+            )
+        } else {
+            // Reject direct cycles (A blocks B while B blocks A)
+            if kind != LinkKind::Related
+                && item.links(kind.inverse()).iter().any(|l| l == target.id())
+            {
+                bail!(
+                    "Cannot link: '{}' {} '{}' (would create a cycle)",
+                    item.id(),
+                    kind.inverse(),
+                    target.id()
+                );
+            }
+            (
+                item.add_link(kind, target.id()),
+                target.add_link(kind.inverse(), item.id()),
+            )
+        };
+
+        *target_dirty |= target_changed;
+
+        if source_changed || target_changed {
+            let verb = if args.remove { "Unlinked" } else { "Linked" };
+            messages.push(format!(
+                "{} {verb}: {} {kind} {}",
+                "✓".green(),
+                item.id(),
+                target.id()
+            ));
+        }
+    }
+
+    let changed = !messages.is_empty();
+
+    for (
+        storage::LoadedItem {
+            path: target_path,
+            item: mut target,
+        },
+        dirty,
+    ) in loaded_targets
+    {
+        if dirty {
+            target.touch();
+            target.save(&target_path)?;
+        }
+    }
+    for message in messages {
+        println!("{message}");
+    }
+
+    if !changed {
+        println!("{}", "No changes to apply.".dimmed());
+        return Ok(());
+    }
+
+    item.touch();
+    item.save(&path)?;
+    ui::print_success("Updated", &config, &path);
+
+    Ok(())
+}
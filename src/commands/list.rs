@@ -7,18 +7,23 @@
 //! Licensed under the MIT License.
 
 use std::cmp::Reverse;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
-use std::process::Command;
 
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use owo_colors::OwoColorize;
 
 use crate::{
-    commands,
-    config::Config,
-    item::{matches_filter, FilterCriteria, Item},
-    storage,
-    tui::screens::ItemAction,
+    commands::{
+        self,
+        bulk::{self, BulkOp},
+    },
+    config::{schema, Config},
+    item::{links, matches_filter, FieldFilter, FilterCriteria, Item, Query},
+    output::{self, AttachmentRecord, CountRecord, ItemRecord, OutputFormat},
+    storage::{self, ParseFailure},
+    tui::screens::{BulkAction, ItemAction},
     ui,
     ui::InteractiveArgs,
 };
@@ -29,7 +34,13 @@ pub enum SortBy {
     #[default]
     Id,
     Date,
+    /// Most recently updated first
+    Updated,
     Title,
+    /// Highest priority first (order from the `priorities` config)
+    Priority,
+    /// Earliest due date first
+    Due,
 }
 
 /// Status filter for item listing
@@ -62,55 +73,82 @@ pub enum ListMode {
     Templates,
 }
 
+/// Dependency filter for item listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyFilter {
+    /// Show only items blocked by open items
+    Blocked,
+    /// Show only items without open blockers
+    Ready,
+}
+
 /// Filter options for listing
+// One field per command-line flag
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Default)]
 pub struct ListOptions {
     pub mode: ListMode,
     pub status: StatusFilter,
     pub labels: Vec<String>,
     pub author: Option<String>,
+    /// Assignees to include (OR logic, `none` for unassigned)
+    pub assignees: Vec<String>,
+    /// Show only items assigned to the current user
+    pub mine: bool,
     pub category: Option<String>,
-    pub sort: SortBy,
+    pub priorities: Vec<String>,
+    /// Show only open items past their due date
+    pub overdue: bool,
+    /// Show only items due before this date
+    pub due_before: Option<NaiveDate>,
+    /// Show only items due after this date
+    pub due_after: Option<NaiveDate>,
+    /// Show only items not updated since this date
+    pub stale_since: Option<NaiveDate>,
+    /// Show only blocked or only ready items
+    pub dependencies: Option<DependencyFilter>,
+    /// Frontmatter field conditions (AND logic)
+    pub fields: Vec<FieldFilter>,
+    /// Query language expression
+    pub query: Option<Query>,
+    /// Workflow states to include (OR logic)
+    pub states: Vec<String>,
+    /// Saved view to start from (other filters are added to it)
+    pub view: Option<String>,
+    /// Sort order (`None` uses the view's sort order, if any, or `Id`)
+    pub sort: Option<SortBy>,
     pub interactive: InteractiveArgs,
     /// Item ID (required for --attachments and --meta modes)
     pub id: Option<String>,
     /// Item file path (alternative to id)
     pub file: Option<PathBuf>,
-}
-
-impl Default for ListOptions {
-    fn default() -> Self {
-        Self {
-            mode: ListMode::default(),
-            status: StatusFilter::default(),
-            labels: Vec::new(),
-            author: None,
-            category: None,
-            sort: SortBy::Id,
-            interactive: InteractiveArgs::default(),
-            id: None,
-            file: None,
-        }
-    }
+    /// Output format (text, json, jsonl)
+    pub format: OutputFormat,
+    /// Include item bodies in machine-readable output
+    pub with_body: bool,
+    /// Fail instead of skipping item files that cannot be parsed
+    pub strict: bool,
 }
 
 /// Collects and filters items from storage.
 ///
 /// If `include_archived` is true, collects from archive directory,
-/// otherwise collects from the main stack directory.
+/// otherwise collects from the main stack directory. Also returns the files
+/// that failed to parse.
 pub fn collect_items(
     config: &Config,
     include_archived: bool,
     filter: &FilterCriteria,
-) -> Vec<Item> {
+) -> (Vec<Item>, Vec<ParseFailure>) {
     let paths: Vec<_> = if include_archived {
         storage::walk_archived(config).collect()
     } else {
         storage::walk_items(config).collect()
     };
 
-    paths
+    let (items, failures) = storage::load_items(config, paths);
+    let items = items
         .into_iter()
-        .filter_map(|path| Item::load(&path).ok())
         .filter(|item| {
             let category = item
                 .path
@@ -118,15 +156,37 @@ pub fn collect_items(
                 .and_then(|p| storage::derive_category(config, p));
             matches_filter(item, filter, category.as_deref())
         })
-        .collect()
+        .collect();
+    (items, failures)
 }
 
 /// Sorts items in place by the given sort order.
-pub fn sort_items(items: &mut [Item], sort: SortBy) {
+///
+/// Priority sorting follows the configured levels; items with an unknown
+/// priority come after known levels, items without a priority come last.
+/// Due sorting puts items without a due date last.
+pub fn sort_items(items: &mut [Item], sort: SortBy, config: &Config) {
     match sort {
         SortBy::Id => items.sort_by(|a, b| a.id().cmp(b.id())),
         SortBy::Date => items.sort_by_key(|item| Reverse(item.created_at())),
+        SortBy::Updated => items.sort_by_key(|item| Reverse(item.last_updated())),
         SortBy::Title => items.sort_by_key(|item| item.title().to_lowercase()),
+        SortBy::Priority => {
+            let levels = config.priorities();
+            items.sort_by_cached_key(|item| {
+                let rank = item.priority().map_or(levels.len() + 1, |p| {
+                    levels
+                        .iter()
+                        .position(|l| l.eq_ignore_ascii_case(p))
+                        .unwrap_or(levels.len())
+                });
+                (rank, item.id().to_string())
+            });
+        }
+        SortBy::Due => items.sort_by(|a, b| {
+            // `None` sorts before `Some`, so compare presence first
+            (a.due().is_none(), a.due(), a.id()).cmp(&(b.due().is_none(), b.due(), b.id()))
+        }),
     }
 }
 
@@ -142,6 +202,18 @@ fn collect_unique_labels(items: &[Item]) -> Vec<String> {
     labels
 }
 
+/// Collect unique assignees from items, sorted alphabetically (case-insensitive).
+fn collect_unique_assignees(items: &[Item]) -> Vec<String> {
+    let mut assignees: Vec<String> = Vec::new();
+    for name in items.iter().flat_map(Item::assignees) {
+        if !assignees.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+            assignees.push(name.clone());
+        }
+    }
+    assignees.sort_by_key(|a| a.to_lowercase());
+    assignees
+}
+
 /// Collect unique categories from items, sorted alphabetically.
 fn collect_unique_categories(items: &[Item], config: &Config) -> Vec<String> {
     let mut categories: Vec<String> = items
@@ -173,65 +245,187 @@ pub fn execute(filter: &ListOptions) -> Result<()> {
 }
 
 /// Lists items (default mode).
+///
+/// The interactive list can switch to another saved view, which lists the
+/// items again with that view (and the same command-line filters).
 fn execute_items(filter: &ListOptions, config: &Config) -> Result<()> {
+    let mut config = config.clone();
+    let mut view = filter.view.clone();
+    loop {
+        match list_items(filter, view.as_deref(), &config)? {
+            Some(ItemAction::ShowView(next)) => {
+                // The TUI may have saved new views in the meantime
+                config = Config::load()?;
+                view = next;
+            }
+            Some(action) => return handle_item_action(action, &config),
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Returns the items matching the filters, with an optional saved view
+/// applied, in sort order, and the item files that failed to parse.
+///
+/// Fails on unparseable files in strict mode; otherwise reporting them is
+/// left to the caller. Shared by `qs list` and `qs export`.
+pub fn filter_items(
+    filter: &ListOptions,
+    view_name: Option<&str>,
+    config: &Config,
+) -> Result<(Vec<Item>, Vec<storage::ParseFailure>)> {
+    // --mine adds the current user to the assignee filter
+    let mut assignees = filter.assignees.clone();
+    if filter.mine {
+        let Some(name) = config.user_name() else {
+            anyhow::bail!(
+                "Cannot resolve the current user for --mine. Set user_name in ~/.queuestack or configure git user.name"
+            );
+        };
+        assignees.push(name);
+    }
+
     // Collect items based on status filter
-    let item_filter = FilterCriteria {
+    let mut item_filter = FilterCriteria {
         labels: filter.labels.clone(),
         author: filter.author.clone(),
+        assignees,
         category: filter.category.clone(),
+        priorities: filter.priorities.clone(),
+        overdue: filter.overdue,
+        due_before: filter.due_before,
+        due_after: filter.due_after,
+        stale_since: filter.stale_since,
+        fields: filter.fields.clone(),
+        query: filter.query.clone(),
         ..FilterCriteria::default()
     };
+    let mut state_names = filter.states.clone();
+    let mut status = filter.status;
+    let mut sort = filter.sort;
+
+    // A saved view is the base; command-line filters are added to it
+    let view = view_name.map(|name| config.view(name)).transpose()?;
+    if let Some(view) = view {
+        view.apply(&mut item_filter)?;
+        state_names.extend(view.states.iter().cloned());
+        if view.closed && status == StatusFilter::Open {
+            status = StatusFilter::Closed;
+        }
+        if let (None, Some(view_sort)) = (sort, &view.sort) {
+            sort = Some(
+                <SortBy as clap::ValueEnum>::from_str(view_sort, true).map_err(|_| {
+                    anyhow::anyhow!("Invalid sort '{view_sort}' in view '{}'", view.name)
+                })?,
+            );
+        }
+    }
+
+    // Workflow states decide open vs. archived themselves, so search everywhere
+    let workflow = config.workflow();
+    let wanted_states = state_names
+        .iter()
+        .map(|s| workflow.resolve(s).map(String::from))
+        .collect::<Result<Vec<_>>>()?;
+    if !wanted_states.is_empty() {
+        status = StatusFilter::All;
+    }
 
-    let mut items = match filter.status {
+    let (mut items, failures) = match status {
         StatusFilter::Open => collect_items(config, false, &item_filter),
         StatusFilter::Closed => collect_items(config, true, &item_filter),
         StatusFilter::All => {
-            let mut open = collect_items(config, false, &item_filter);
-            let closed = collect_items(config, true, &item_filter);
-            open.extend(closed);
-            open
+            let (mut items, mut failures) = collect_items(config, false, &item_filter);
+            let (closed, closed_failures) = collect_items(config, true, &item_filter);
+            items.extend(closed);
+            failures.extend(closed_failures);
+            (items, failures)
         }
     };
 
+    if !wanted_states.is_empty() {
+        items.retain(|item| wanted_states.contains(&workflow.current_state(item)));
+    }
+
+    // Dependency filters need the status of other items
+    if let Some(dependencies) = filter.dependencies {
+        let open_ids = storage::collect_open_ids(config);
+        let want_blocked = dependencies == DependencyFilter::Blocked;
+        items.retain(|item| links::is_blocked(item, &open_ids) == want_blocked);
+    }
+
+    storage::check_parse_failures(config, filter.strict, &failures)?;
+
     // Sort items
-    sort_items(&mut items, filter.sort);
+    sort_items(&mut items, sort.unwrap_or_default(), config);
+    Ok((items, failures))
+}
 
-    // Display
-    if items.is_empty() {
+/// Lists items with an optional saved view applied.
+///
+/// Returns the action chosen in the interactive list, if any.
+fn list_items(
+    filter: &ListOptions,
+    view_name: Option<&str>,
+    config: &Config,
+) -> Result<Option<ItemAction>> {
+    let (items, failures) = filter_items(filter, view_name, config)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+    let view = view_name.map(|name| config.view(name)).transpose()?;
+
+    if filter.format.is_machine() {
+        print_items(&items, config, filter.format, filter.with_body)?;
+        return Ok(None);
+    }
+
+    // Display (the interactive list stays open without items, so views can
+    // still be switched)
+    let interactive = filter.interactive.should_run(config);
+    if items.is_empty() && (!interactive || config.views().is_empty()) {
         println!("No items found.");
-        return Ok(());
+        return Ok(None);
     }
 
     // Check interactive mode
-    if !filter.interactive.should_run(config) {
+    if !interactive {
         // Non-interactive: print file paths
         for item in &items {
             if let Some(ref path) = item.path {
                 println!("{}", config.relative_path(path).display());
             }
         }
-        return Ok(());
+        return Ok(None);
     }
 
-    // Collect available labels and categories for filter overlay
+    // Collect available labels, assignees, and categories for filter overlay
     let available_labels = collect_unique_labels(&items);
+    let available_assignees = collect_unique_assignees(&items);
     let available_categories = collect_unique_categories(&items, config);
 
-    // Interactive: TUI selection with actions
-    let Some(action) = ui::select_item_with_actions(
+    // Interactive: TUI selection with actions (`None` if cancelled)
+    ui::select_item_with_actions(
         "Select an item",
         &items,
         config,
         available_labels,
+        available_assignees,
         available_categories,
-    )?
-    else {
-        return Ok(()); // User cancelled
-    };
-
-    handle_item_action(action, config)?;
+        view.map(|view| view.name.as_str()),
+    )
+}
 
-    Ok(())
+/// Prints items as JSON records.
+pub fn print_items(
+    items: &[Item],
+    config: &Config,
+    format: OutputFormat,
+    with_body: bool,
+) -> Result<()> {
+    let records: Vec<ItemRecord> = items
+        .iter()
+        .map(|item| ItemRecord::new(item, config, with_body))
+        .collect();
+    output::print_records(format, &records)
 }
 
 /// Handle an action selected from the item action popup.
@@ -252,26 +446,21 @@ fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
         ItemAction::Reopen(path) => {
             commands::execute_reopen(None, Some(path))?;
         }
+        ItemAction::ShowView(_) => unreachable!("view switches are handled by execute_items"),
+        ItemAction::Bulk(action, paths) => {
+            execute_bulk_action(action, &paths, config)?;
+        }
         ItemAction::Delete(path) => {
             // Show confirmation dialog
             let item = Item::load(&path)?;
             let message = format!("Delete '{}'?", item.title());
             if ui::confirm(&message)? == Some(true) {
-                // Move to trash
-                let status = Command::new("trash")
-                    .arg(&path)
-                    .status()
-                    .context("Failed to execute trash command")?;
-
-                if status.success() {
-                    println!(
-                        "{} Moved to trash: {}",
-                        "✓".green(),
-                        config.relative_path(&path).display()
-                    );
-                } else {
-                    anyhow::bail!("Failed to move item to trash");
-                }
+                storage::trash_item(&path)?;
+                println!(
+                    "{} Moved to trash: {}",
+                    "✓".green(),
+                    config.relative_path(&path).display()
+                );
             }
         }
     }
@@ -279,6 +468,71 @@ fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Asks for the details of a bulk action, confirms it once, and applies it to
+/// every marked item.
+fn execute_bulk_action(action: BulkAction, paths: &[PathBuf], config: &Config) -> Result<()> {
+    let op = match action {
+        BulkAction::Close => Some(BulkOp::Close),
+        BulkAction::Reopen => Some(BulkOp::Reopen),
+        BulkAction::AddLabel => {
+            ui::prompt_text("Add a label to the marked items", "Label")?.map(BulkOp::AddLabel)
+        }
+        BulkAction::RemoveLabel => {
+            let labels: Vec<String> = paths
+                .iter()
+                .filter_map(|path| Item::load(path).ok())
+                .flat_map(|item| item.frontmatter.labels)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            ui::select_from_list("Remove a label from the marked items", &labels)?
+                .map(|i| BulkOp::RemoveLabel(labels[i].clone()))
+        }
+        BulkAction::MoveCategory => select_bulk_move(config)?,
+        BulkAction::Delete => Some(BulkOp::Delete),
+    };
+    let Some(op) = op else {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    };
+
+    if ui::confirm(&format!("{}?", op.describe(paths.len())))? != Some(true) {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    }
+
+    let outcomes = bulk::apply(config, &op, paths, false)?;
+    bulk::print_summary(config, op.verb(), &outcomes, false)
+}
+
+/// Asks for the target category of a bulk move, offering the existing
+/// categories, none, or a new one.
+///
+/// Returns `Ok(None)` if cancelled.
+fn select_bulk_move(config: &Config) -> Result<Option<BulkOp>> {
+    let (categories, _) = commands::new::collect_existing_metadata(config);
+    let mut options = vec!["(uncategorized)".to_string()];
+    options.extend(categories.iter().cloned());
+    options.push("New category...".to_string());
+
+    let Some(choice) = ui::select_from_list("Move the marked items to", &options)? else {
+        return Ok(None);
+    };
+    let category = if choice == 0 {
+        None
+    } else if choice <= categories.len() {
+        Some(categories[choice - 1].clone())
+    } else {
+        let Some(category) =
+            ui::prompt_text("Move the marked items to a new category", "Category")?
+        else {
+            return Ok(None);
+        };
+        Some(category)
+    };
+    Ok(Some(BulkOp::MoveCategory(category)))
+}
+
 /// Execute the edit wizard for an existing item.
 fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
     use crate::tui::{self, screens::NewItemWizard};
@@ -298,6 +552,7 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
         .with_attachments(item.attachments().to_vec())
         .with_category(current_category.clone())
         .with_labels(item.labels())
+        .with_fields(config.field_schema(), item.custom_fields())
         .with_item_id(item.id())
         .for_editing();
 
@@ -311,6 +566,14 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
     let mut updated = item;
     updated.set_title(output.title);
     updated.frontmatter.labels = output.labels;
+    for (key, raw) in &output.fields {
+        if raw.is_empty() {
+            updated.remove_custom_field(key);
+        } else {
+            let value = schema::coerce_field(config.field_schema(), key, raw)?;
+            updated.set_custom_field(key, value)?;
+        }
+    }
 
     // Handle new attachments
     if !output.attachments.is_empty() {
@@ -346,6 +609,7 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
     }
 
     // Save the item
+    updated.touch();
     updated.save(path)?;
 
     // Handle category change - need to move file
@@ -371,17 +635,20 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
 fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
     let item_filter = FilterCriteria::default();
 
-    // Load all items to get complete label vocabulary
-    let all_items = storage::load_all_items(config);
-    let all_label_counts = ui::count_by_many(&all_items, |item: &Item| item.labels().to_vec());
+    // Load all items to get complete label vocabulary, and count only open
+    // items per label (for display and selectability)
+    let (all_items, mut failures) = storage::load_all_items(config);
+    let (open_items, open_failures) = collect_items(config, false, &item_filter);
+    failures.extend(open_failures);
+    storage::check_parse_failures(config, filter.strict, &failures)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
 
-    if all_label_counts.is_empty() {
+    let all_label_counts = ui::count_by_many(&all_items, |item: &Item| item.labels().to_vec());
+    if all_label_counts.is_empty() && !filter.format.is_machine() {
         println!("No labels found.");
         return Ok(());
     }
 
-    // Count only open items per label (for display and selectability)
-    let open_items = collect_items(config, false, &item_filter);
     let open_label_counts = ui::count_by_many(&open_items, |item: &Item| item.labels().to_vec());
 
     // Build label list: all labels with their open counts
@@ -396,6 +663,17 @@ fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
     // Sort by open count (descending), then alphabetically
     labels.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
+    if filter.format.is_machine() {
+        let records: Vec<CountRecord> = labels
+            .into_iter()
+            .map(|(name, count)| CountRecord {
+                name: Some(name),
+                count,
+            })
+            .collect();
+        return output::print_records(filter.format, &records);
+    }
+
     // Check interactive mode
     if !filter.interactive.should_run(config) {
         // Non-interactive: print labels with open count, one per line
@@ -455,21 +733,24 @@ fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
 fn execute_categories(filter: &ListOptions, config: &Config) -> Result<()> {
     let item_filter = FilterCriteria::default();
 
-    // Load all items to get complete category vocabulary
-    let all_items = storage::load_all_items(config);
+    // Load all items to get complete category vocabulary, and count only
+    // open items per category (for display and selectability)
+    let (all_items, mut failures) = storage::load_all_items(config);
+    let (open_items, open_failures) = collect_items(config, false, &item_filter);
+    failures.extend(open_failures);
+    storage::check_parse_failures(config, filter.strict, &failures)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+
     let all_category_counts = ui::count_by(&all_items, |item: &Item| {
         item.path
             .as_ref()
             .and_then(|p| storage::derive_category(config, p))
     });
-
-    if all_category_counts.is_empty() {
+    if all_category_counts.is_empty() && !filter.format.is_machine() {
         println!("No categories found.");
         return Ok(());
     }
 
-    // Count only open items per category (for display and selectability)
-    let open_items = collect_items(config, false, &item_filter);
     let open_category_counts = ui::count_by(&open_items, |item: &Item| {
         item.path
             .as_ref()
@@ -495,6 +776,14 @@ fn execute_categories(filter: &ListOptions, config: &Config) -> Result<()> {
         })
     });
 
+    if filter.format.is_machine() {
+        let records: Vec<CountRecord> = categories
+            .into_iter()
+            .map(|(name, count)| CountRecord { name, count })
+            .collect();
+        return output::print_records(filter.format, &records);
+    }
+
     // Check interactive mode
     if !filter.interactive.should_run(config) {
         // Non-interactive: print categories with count, one per line
@@ -567,6 +856,10 @@ fn execute_attachments(filter: &ListOptions, config: &Config) -> Result<()> {
     // Find and load the item
     let storage::LoadedItem { item, .. } = item_ref.resolve(config)?;
 
+    if filter.format.is_machine() {
+        return output::print_records(filter.format, &AttachmentRecord::for_item(&item));
+    }
+
     let attachments = item.attachments();
 
     if attachments.is_empty() {
@@ -589,11 +882,28 @@ fn execute_meta(filter: &ListOptions, config: &Config) -> Result<()> {
     // Find and load the item
     let storage::LoadedItem { path, item } = item_ref.resolve(config)?;
 
+    if filter.format.is_machine() {
+        let record = ItemRecord::new(&item, config, filter.with_body);
+        return output::print_record(filter.format, &record);
+    }
+
     // Print frontmatter fields
     println!("id: {}", item.id());
     println!("title: {}", item.title());
     println!("author: {}", item.author());
+
+    let assignees = item.assignees();
+    if !assignees.is_empty() {
+        println!("assignees: {}", assignees.join(", "));
+    }
+
     println!("created_at: {}", item.created_at());
+    if let Some(updated_at) = item.updated_at() {
+        println!("updated_at: {updated_at}");
+    }
+    if let Some(closed_at) = item.closed_at() {
+        println!("closed_at: {closed_at}");
+    }
     println!("status: {}", item.status());
 
     let labels = item.labels();
@@ -620,18 +930,22 @@ fn execute_meta(filter: &ListOptions, config: &Config) -> Result<()> {
 /// Lists all templates.
 fn execute_templates(filter: &ListOptions, config: &Config) -> Result<()> {
     // Collect all templates
-    let mut templates: Vec<Item> = storage::walk_templates(config)
-        .filter_map(|path| Item::load(&path).ok())
-        .collect();
+    let (mut templates, failures) = storage::load_items(config, storage::walk_templates(config));
+    storage::check_parse_failures(config, filter.strict, &failures)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+
+    // Sort templates by ID (default)
+    sort_items(&mut templates, filter.sort.unwrap_or_default(), config);
+
+    if filter.format.is_machine() {
+        return print_items(&templates, config, filter.format, filter.with_body);
+    }
 
     if templates.is_empty() {
         println!("No templates found.");
         return Ok(());
     }
 
-    // Sort templates by ID (default)
-    sort_items(&mut templates, filter.sort);
-
     // Check interactive mode
     if !filter.interactive.should_run(config) {
         // Non-interactive: print file paths
@@ -6,26 +6,49 @@
 //! Licensed under the MIT License.
 
 pub mod attach;
+pub mod board;
+pub mod bulk;
 pub mod close;
+pub mod comment;
 pub mod completions;
+pub mod doctor;
+pub mod export;
+pub mod import;
 pub mod init;
+pub mod link;
 pub mod list;
 pub mod new;
 pub mod search;
+pub mod serve;
 pub mod setup;
+pub mod show;
+pub mod status;
 pub mod update;
+pub mod view;
 
 pub use self::{
     attach::{
         execute_add as attach_add, execute_remove as attach_remove, AttachAddArgs, AttachRemoveArgs,
     },
+    board::{execute as board, BoardArgs, GroupBy},
+    bulk::{execute as bulk, BulkOp, BulkTargets},
     close::{execute_close, execute_reopen},
+    comment::{execute as comment, CommentArgs},
     completions::execute as completions,
+    doctor::{execute as doctor, DoctorArgs},
+    export::{execute as export, ExportArgs},
+    import::{execute as import, ImportArgs, Source as ImportSource},
     init::execute as init,
-    list::{execute as list, ListMode, ListOptions, SortBy, StatusFilter},
+    link::{execute as link, LinkArgs},
+    list::{execute as list, DependencyFilter, ListMode, ListOptions, SortBy, StatusFilter},
     new::{execute as new, NewArgs},
     search::{execute as search, SearchArgs},
+    serve::execute as serve,
     setup::execute as setup,
+    show::{execute as show, ShowArgs},
+    status::{execute as status, StatusArgs},
     update::{execute as update, UpdateArgs},
+    view::execute_list as view_list,
 };
+pub use crate::output::OutputFormat;
 pub use crate::ui::InteractiveArgs;
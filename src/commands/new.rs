@@ -7,26 +7,38 @@
 
 use std::collections::HashSet;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use owo_colors::OwoColorize;
 
 use crate::{
-    config::Config,
+    config::{schema, Config},
     editor, id,
-    item::{is_url, normalize_identifier, Frontmatter, Item, Status},
+    item::{due::parse_due, is_url, normalize_identifier, Frontmatter, Item, Status},
     storage,
     tui::{self, screens::NewItemWizard},
     ui::{self, InteractiveArgs},
 };
 
 /// Arguments for the new command
+#[derive(Default)]
 pub struct NewArgs {
     pub title: Option<String>,
     pub labels: Vec<String>,
+    /// People to assign
+    pub assignees: Vec<String>,
     pub category: Option<String>,
     pub attachments: Vec<String>,
+    pub priority: Option<String>,
+    pub due: Option<String>,
+    /// Custom fields as `(key, value)` pairs
+    pub fields: Vec<(String, String)>,
+    /// Body text (`-` reads from stdin)
+    pub body: Option<String>,
+    /// File to read the body from
+    pub body_file: Option<PathBuf>,
     pub interactive: InteractiveArgs,
     pub as_template: bool,
     #[allow(clippy::option_option)]
@@ -34,6 +46,7 @@ pub struct NewArgs {
 }
 
 /// Executes the new command.
+#[allow(clippy::needless_pass_by_value)]
 pub fn execute(args: NewArgs) -> Result<()> {
     let mut config = Config::load()?;
 
@@ -50,7 +63,46 @@ pub fn execute(args: NewArgs) -> Result<()> {
         return execute_wizard(&config, args.as_template);
     }
 
-    let title = args.title.unwrap();
+    // Read the body before creating anything
+    let body = ui::read_body(args.body.as_deref(), args.body_file.as_deref())?;
+
+    // Get author name (prompts if not available)
+    let author = config.user_name_or_prompt()?;
+
+    let (path, mut item) = create(&config, &args, author, body)?;
+
+    // Process attachments if any
+    if !args.attachments.is_empty() {
+        ui::process_and_save_attachments(&mut item, &path, &args.attachments)?;
+    }
+
+    // Resolve interactive mode (editor doesn't require terminal check)
+    let interactive = args.interactive.is_enabled(&config);
+
+    // Open editor if interactive
+    if interactive {
+        editor::open(&path, &config).context("Failed to open editor")?;
+    }
+
+    // Output the path (for scripting)
+    println!("{}", config.relative_path(&path).display());
+
+    Ok(())
+}
+
+/// Validates the arguments and creates the item (or template) file.
+///
+/// `body` is the description as given. Attachments, the editor, and output
+/// are left to the caller. Returns the new path and the saved item.
+pub fn create(
+    config: &Config,
+    args: &NewArgs,
+    author: String,
+    body: Option<String>,
+) -> Result<(PathBuf, Item)> {
+    let Some(title) = args.title.clone() else {
+        anyhow::bail!("Title is required");
+    };
 
     // Validate title is not empty
     if title.trim().is_empty() {
@@ -71,8 +123,15 @@ pub fn execute(args: NewArgs) -> Result<()> {
         }
     }
 
-    // Get author name (prompts if not available)
-    let author = config.user_name_or_prompt()?;
+    // Validate priority against configured levels
+    let priority = args
+        .priority
+        .as_deref()
+        .map(|p| config.resolve_priority(p))
+        .transpose()?;
+
+    // Parse due date expression
+    let due = args.due.as_deref().map(parse_due).transpose()?;
 
     // Generate ID
     let id = id::generate(config.id_pattern());
@@ -99,37 +158,36 @@ pub fn execute(args: NewArgs) -> Result<()> {
         author,
         created_at: Utc::now(),
         status,
+        state: if args.as_template {
+            None
+        } else {
+            config.initial_state()
+        },
         labels,
         attachments: vec![],
+        priority,
+        due,
+        ..Frontmatter::default()
     };
 
-    // Create item
+    // Create item and validate custom fields against the schema
     let mut item = Item::new(frontmatter);
+    for name in &args.assignees {
+        item.assign(name);
+    }
+    if let Some(body) = body {
+        item.set_description(&body);
+    }
+    schema::apply_to_new_item(config.field_schema(), &mut item, &args.fields)?;
 
     // Save to disk (category determines folder placement)
     let path = if args.as_template {
-        storage::create_template(&config, &item, category.as_deref())?
+        storage::create_template(config, &item, category.as_deref())?
     } else {
-        storage::create_item(&config, &item, category.as_deref())?
+        storage::create_item(config, &item, category.as_deref())?
     };
 
-    // Process attachments if any
-    if !args.attachments.is_empty() {
-        ui::process_and_save_attachments(&mut item, &path, &args.attachments)?;
-    }
-
-    // Resolve interactive mode (editor doesn't require terminal check)
-    let interactive = args.interactive.is_enabled(&config);
-
-    // Open editor if interactive
-    if interactive {
-        editor::open(&path, &config).context("Failed to open editor")?;
-    }
-
-    // Output the path (for scripting)
-    println!("{}", config.relative_path(&path).display());
-
-    Ok(())
+    Ok((path, item))
 }
 
 /// Collect existing categories and labels from all items.
@@ -137,17 +195,19 @@ pub fn collect_existing_metadata(config: &Config) -> (Vec<String>, Vec<String>)
     let mut categories: HashSet<String> = HashSet::new();
     let mut labels: HashSet<String> = HashSet::new();
 
-    let paths: Vec<_> = storage::walk_all(config).collect();
-
-    for path in paths {
-        if let Ok(item) = Item::load(&path) {
-            // Derive category from path
-            if let Some(cat) = storage::derive_category(config, &path) {
-                categories.insert(cat);
-            }
-            for label in item.labels() {
-                labels.insert(label.clone());
-            }
+    // Only used for suggestions, so unparseable files are simply left out
+    let (items, _) = storage::load_all_items(config);
+    for item in items {
+        // Derive category from path
+        if let Some(cat) = item
+            .path
+            .as_ref()
+            .and_then(|path| storage::derive_category(config, path))
+        {
+            categories.insert(cat);
+        }
+        for label in item.labels() {
+            labels.insert(label.clone());
         }
     }
 
@@ -165,7 +225,8 @@ fn execute_wizard(config: &Config, as_template: bool) -> Result<()> {
     let (existing_categories, existing_labels) = collect_existing_metadata(config);
 
     // Run the wizard
-    let wizard = NewItemWizard::new(existing_categories, existing_labels);
+    let wizard = NewItemWizard::new(existing_categories, existing_labels)
+        .with_fields(config.field_schema(), &serde_yml::Mapping::new());
     let Some(output) = tui::run(wizard)? else {
         println!("{}", "Cancelled.".dimmed());
         return Ok(());
@@ -200,12 +261,19 @@ fn execute_wizard(config: &Config, as_template: bool) -> Result<()> {
         author,
         created_at: Utc::now(),
         status,
+        state: if as_template {
+            None
+        } else {
+            config.initial_state()
+        },
         labels,
         attachments: vec![],
+        ..Frontmatter::default()
     };
 
-    // Create item
+    // Create item with the wizard's custom field values
     let mut item = Item::new(frontmatter);
+    schema::apply_to_new_item(config.field_schema(), &mut item, &output.fields)?;
 
     // Save to disk (category determines folder placement)
     let path = if as_template {
@@ -278,12 +346,28 @@ fn execute_from_template(
         }
     }
 
+    // Use CLI priority if specified, otherwise inherit from template
+    let priority = match args.priority.as_deref() {
+        Some(p) => Some(config.resolve_priority(p)?),
+        None => template.frontmatter.priority.clone(),
+    };
+
+    // Due dates are absolute, so they are never inherited from the template
+    let due = args.due.as_deref().map(parse_due).transpose()?;
+
     // If no title provided, launch wizard with template data pre-filled
     if args.title.is_none() {
         if !std::io::stdout().is_terminal() {
             anyhow::bail!("Title is required in non-interactive mode");
         }
-        return execute_wizard_from_template(config, &template, category.as_deref(), &labels);
+        return execute_wizard_from_template(
+            config,
+            &template,
+            category.as_deref(),
+            &labels,
+            priority,
+            due,
+        );
     }
 
     let title = args.title.clone().unwrap();
@@ -293,6 +377,9 @@ fn execute_from_template(
         anyhow::bail!("Title cannot be empty");
     }
 
+    // Read the body (replaces the template's body)
+    let body = ui::read_body(args.body.as_deref(), args.body_file.as_deref())?;
+
     // Get author name
     let author = config.user_name_or_prompt()?;
 
@@ -306,13 +393,27 @@ fn execute_from_template(
         author,
         created_at: Utc::now(),
         status: Status::Open,
+        state: config.initial_state(),
         labels,
         attachments: vec![],
+        priority,
+        due,
+        ..Frontmatter::default()
     };
 
-    // Create item with template's body content
+    // Create item with template's body content, assignees, and custom fields
     let mut item = Item::new(frontmatter);
+    for name in template.assignees().iter().chain(&args.assignees) {
+        item.assign(name);
+    }
     item.body.clone_from(&template.body);
+    if let Some(body) = body {
+        item.set_description(&body);
+    }
+    item.frontmatter
+        .extra
+        .clone_from(&template.frontmatter.extra);
+    schema::apply_to_new_item(config.field_schema(), &mut item, &args.fields)?;
 
     // Save to disk
     let path = storage::create_item(config, &item, category.as_deref())?;
@@ -345,6 +446,8 @@ fn execute_wizard_from_template(
     template: &Item,
     category: Option<&str>,
     labels: &[String],
+    priority: Option<String>,
+    due: Option<NaiveDate>,
 ) -> Result<()> {
     // Collect existing metadata for autocomplete
     let (existing_categories, existing_labels) = collect_existing_metadata(config);
@@ -358,7 +461,8 @@ fn execute_wizard_from_template(
         .with_title(template.title())
         .with_attachments(template_attachments)
         .with_category(category.map(String::from))
-        .with_labels(labels);
+        .with_labels(labels)
+        .with_fields(config.field_schema(), &template.frontmatter.extra);
 
     let Some(output) = tui::run(wizard)? else {
         println!("{}", "Cancelled.".dimmed());
@@ -382,13 +486,25 @@ fn execute_wizard_from_template(
         author,
         created_at: Utc::now(),
         status: Status::Open,
+        state: config.initial_state(),
         labels: output.labels,
         attachments: vec![],
+        priority,
+        due,
+        ..Frontmatter::default()
     };
 
-    // Create item with template's body content
+    // Create item with template's body content and custom fields
+    // (schema fields come from the wizard, which was pre-filled from the template)
     let mut item = Item::new(frontmatter);
     item.body.clone_from(&template.body);
+    item.frontmatter
+        .extra
+        .clone_from(&template.frontmatter.extra);
+    for (key, _) in &output.fields {
+        item.remove_custom_field(key);
+    }
+    schema::apply_to_new_item(config.field_schema(), &mut item, &output.fields)?;
 
     // Save to disk
     let path = storage::create_item(&config, &item, category.as_deref())?;
@@ -493,9 +609,8 @@ fn copy_template_attachments(
 
 /// Show template selection TUI and return selected template.
 fn select_template(config: &Config) -> Result<Option<Item>> {
-    let templates: Vec<Item> = storage::walk_templates(config)
-        .filter_map(|path| Item::load(&path).ok())
-        .collect();
+    let (templates, failures) = storage::load_items(config, storage::walk_templates(config));
+    ui::print_parse_failures(config, &failures);
 
     if templates.is_empty() {
         anyhow::bail!(
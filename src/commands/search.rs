@@ -2,6 +2,11 @@
 //!
 //! Search for items and interactively select one to open.
 //!
+//! By default the search text is a query expression (see `item::search`), so
+//! plain words still work while `label:bug AND NOT author:alice` narrows the
+//! results; text that isn't a valid expression is matched as typed. `--regex` and `--fuzzy` match a single pattern instead. Results are
+//! ranked by relevance (see `item::rank`).
+//!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
@@ -9,56 +14,167 @@ use std::io::IsTerminal;
 
 use anyhow::Result;
 
-use super::list::{collect_items, sort_items, SortBy};
-use crate::item::FilterCriteria;
-use crate::{config::Config, item::search::matches_query, ui, ui::InteractiveArgs};
+use super::list::{print_items, sort_items, SortBy};
+use crate::item::{
+    rank::{self, Matcher, SearchHit, SearchMode},
+    Item, Query,
+};
+use crate::{
+    config::Config,
+    markdown,
+    output::OutputFormat,
+    storage::{self, ParseFailure},
+    ui,
+    ui::InteractiveArgs,
+};
 
 /// Arguments for the search command
+// One field per command-line flag
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Default)]
 pub struct SearchArgs {
     pub query: String,
     pub full_text: bool,
+    /// How the query is matched (query language, regex, or fuzzy)
+    pub mode: SearchMode,
     pub interactive: InteractiveArgs,
     pub closed: bool,
+    /// Output format (text, json, jsonl)
+    pub format: OutputFormat,
+    /// Include item bodies in machine-readable output
+    pub with_body: bool,
+    /// Fail instead of skipping item files that cannot be parsed
+    pub strict: bool,
 }
 
 /// Executes the search command.
 pub fn execute(args: &SearchArgs) -> Result<()> {
+    // Parse before loading anything so syntax errors are reported first
+    let (query, matchers) = parse_query(args)?;
+
+    let config = Config::load()?;
+    search(args, &config, query.as_ref(), &matchers)
+}
+
+/// Returns the items matching a search, best match first.
+///
+/// Unparseable item files are reported on stderr.
+pub fn find_items(args: &SearchArgs, config: &Config) -> Result<Vec<Item>> {
+    let (query, matchers) = parse_query(args)?;
+    let (items, _, failures) = find(args, config, query.as_ref(), &matchers)?;
+    ui::print_parse_failures(config, &failures);
+    Ok(items)
+}
+
+/// Parses the search into a query (query language only) and its matchers.
+fn parse_query(args: &SearchArgs) -> Result<(Option<Query>, Vec<Matcher>)> {
     // Empty query = no matches
     if args.query.trim().is_empty() {
         anyhow::bail!("No items found matching \"{}\"", args.query);
     }
 
-    let config = Config::load()?;
-
-    // Collect all items (no pre-filtering, search applied after)
-    let mut items = collect_items(&config, args.closed, &FilterCriteria::default());
+    Ok(match args.mode {
+        SearchMode::Plain => match Query::parse(&args.query) {
+            Ok(query) => {
+                let matchers = query
+                    .text_terms()
+                    .into_iter()
+                    .map(|term| Matcher::new(term, SearchMode::Plain))
+                    .collect::<Result<Vec<_>>>()?;
+                (Some(query), matchers)
+            }
+            // Not a query expression (like "Fix: login" or "(draft"): match the
+            // text as typed, as the TUI search box does
+            Err(_) => (None, vec![Matcher::new(&args.query, SearchMode::Plain)?]),
+        },
+        mode => (None, vec![Matcher::new(&args.query, mode)?]),
+    })
+}
 
-    // Filter by search query
-    items.retain(|item| matches_query(item, &args.query, args.full_text));
+/// Loads and ranks the items matching a search, with their hits and the item
+/// files that failed to parse (an error in strict mode).
+fn find(
+    args: &SearchArgs,
+    config: &Config,
+    query: Option<&Query>,
+    matchers: &[Matcher],
+) -> Result<(Vec<Item>, Vec<SearchHit>, Vec<ParseFailure>)> {
+    // Collect all items (search applied after); with the item index enabled,
+    // files that can't contain the query's required words are skipped
+    let paths: Vec<_> = if args.closed {
+        storage::walk_archived(config).collect()
+    } else {
+        storage::walk_items(config).collect()
+    };
+    let words = query.map(Query::required_words).unwrap_or_default();
+    let (mut items, failures) = storage::load_items_containing(config, paths, &words);
+    storage::check_parse_failures(config, args.strict, &failures)?;
+
+    // Sort by ID so equally relevant items keep a consistent order
+    sort_items(&mut items, SortBy::Id, config);
+
+    let mut results: Vec<(Item, SearchHit)> = items
+        .into_iter()
+        .filter_map(|item| {
+            let hit = match query {
+                Some(query) => {
+                    let category = item
+                        .path
+                        .as_deref()
+                        .and_then(|path| storage::derive_category(config, path));
+                    query
+                        .matches(&item, category.as_deref(), args.full_text)
+                        .then(|| rank::score_all(matchers, &item, args.full_text))?
+                }
+                None => matchers[0].score(&item, args.full_text)?,
+            };
+            Some((item, hit))
+        })
+        .collect();
+    rank::sort_by_score(&mut results);
+    let (items, hits) = results.into_iter().unzip();
+    Ok((items, hits, failures))
+}
 
-    // Sort by ID for consistent ordering
-    sort_items(&mut items, SortBy::Id);
+/// Loads, matches, and shows the items of a search.
+fn search(
+    args: &SearchArgs,
+    config: &Config,
+    query: Option<&Query>,
+    matchers: &[Matcher],
+) -> Result<()> {
+    let (items, hits, failures) = find(args, config, query, matchers)?;
+    let _footer = ui::ParseFailureFooter::new(config, failures);
+
+    // Machine-readable output: an empty result is a valid (empty) document
+    if args.format.is_machine() {
+        return print_items(&items, config, args.format, args.with_body);
+    }
 
     if items.is_empty() {
         anyhow::bail!("No items found matching \"{}\"", args.query);
     }
 
     // Resolve interactive mode (without terminal check - handled separately)
-    let interactive = args.interactive.is_enabled(&config);
+    let interactive = args.interactive.is_enabled(config);
 
-    // Non-interactive mode: just print the list
+    // Non-interactive mode: print the list with snippets of body matches
     if !interactive {
-        for item in &items {
+        let color = ui::use_color();
+        for (item, hit) in items.iter().zip(&hits) {
             if let Some(ref path) = item.path {
                 println!("{}", config.relative_path(path).display());
             }
+            if let Some(ref snippet) = hit.snippet {
+                print!("{}", markdown::to_ansi(&ui::snippet_lines(snippet), color));
+            }
         }
         return Ok(());
     }
 
     // Single match: open directly
     if items.len() == 1 {
-        ui::open_item_in_editor(&items[0], &config)?;
+        ui::open_item_in_editor(&items[0], config)?;
         return Ok(());
     }
 
@@ -70,10 +186,13 @@ pub fn execute(args: &SearchArgs) -> Result<()> {
         );
     }
 
-    let Some(selection) = ui::select_item("Select an item", &items, &config)? else {
+    let snippets: Vec<_> = hits.iter().map(|hit| hit.snippet.as_ref()).collect();
+    let Some(selection) =
+        ui::select_item_with_snippets("Select an item", &items, &snippets, config)?
+    else {
         return Ok(()); // User cancelled
     };
-    ui::open_item_in_editor(&items[selection], &config)?;
+    ui::open_item_in_editor(&items[selection], config)?;
 
     Ok(())
 }
@@ -0,0 +1,20 @@
+//! # Serve Command
+//!
+//! Runs the MCP server on stdin and stdout until the client disconnects.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::io;
+
+use anyhow::Result;
+
+use crate::{config::Config, mcp};
+
+/// Executes the serve command.
+pub fn execute() -> Result<()> {
+    // Fail at startup, not on the first request, outside a project
+    Config::load()?;
+
+    mcp::serve(io::stdin().lock(), io::stdout().lock())
+}
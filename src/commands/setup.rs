@@ -269,7 +269,7 @@ fn print_activation_instructions(shell: Shell, install_path: &Path) {
             );
         }
         Shell::Elvish => {
-            eprintln!("\n  {} Add to ~/.config/elvish/rc.elv:", "→".cyan(),);
+            eprintln!("\n  {} Add to ~/.config/elvish/rc.elv:", "→".cyan());
             eprintln!("    {}", "use qs".dimmed());
         }
         _ => {}
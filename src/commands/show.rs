@@ -0,0 +1,67 @@
+//! # Show Command
+//!
+//! Prints an item in the terminal: a header with its metadata, the Markdown
+//! description with terminal styling, and the item's comments.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::HashSet, fs, path::PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    config::Config,
+    markdown,
+    output::{self, ItemRecord, OutputFormat},
+    storage, ui,
+};
+
+/// Maximum width of rules between header and body.
+const MAX_RULE_WIDTH: usize = 80;
+
+/// Arguments for the show command
+#[derive(Default)]
+pub struct ShowArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Print the item file unchanged
+    pub raw: bool,
+    pub format: OutputFormat,
+}
+
+/// Executes the show command.
+pub fn execute(args: &ShowArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    // Resolve item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, item } = item_ref.resolve(&config)?;
+
+    if args.format.is_machine() {
+        let record = ItemRecord::new(&item, &config, true);
+        return output::print_record(args.format, &record);
+    }
+
+    if args.raw {
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        print!("{content}");
+        return Ok(());
+    }
+
+    let width = crossterm::terminal::size()
+        .map_or(MAX_RULE_WIDTH, |(cols, _)| usize::from(cols))
+        .min(MAX_RULE_WIDTH);
+
+    // Open blockers are highlighted, which needs the status of other items
+    let open_ids = if item.blocked_by().is_empty() {
+        HashSet::new()
+    } else {
+        storage::collect_open_ids(&config)
+    };
+    let lines = ui::item_lines(&config, &item, &open_ids, width);
+
+    print!("{}", markdown::to_ansi(&lines, ui::use_color()));
+    Ok(())
+}
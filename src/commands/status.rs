@@ -0,0 +1,78 @@
+//! # Status Command
+//!
+//! Moves an item to another workflow state.
+//!
+//! States and allowed transitions come from the `[workflow]` table of the
+//! project config. Moving into an archived state closes the item and moves
+//! it into the archive; moving out of one reopens it.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+
+use crate::{
+    commands::close::{apply_state, report_dependents},
+    config::Config,
+    item::Status,
+    storage, ui,
+};
+
+/// Arguments for the status command
+#[derive(Default)]
+pub struct StatusArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Target state
+    pub state: String,
+    /// Skip the transition check
+    pub force: bool,
+}
+
+/// Executes the status command.
+pub fn execute(args: &StatusArgs) -> Result<()> {
+    let config = Config::load()?;
+    let workflow = config.workflow();
+    let target = workflow.resolve(&args.state)?.to_string();
+
+    // Resolve item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+
+    if item.status() == Status::Template {
+        bail!("Templates have no workflow state");
+    }
+
+    let current = workflow.current_state(&item);
+    if current == target {
+        bail!("Item '{}' is already {target}", item.id());
+    }
+
+    if !args.force && !workflow.can_transition(&current, &target) {
+        let targets = workflow.targets(&current);
+        let allowed = if targets.is_empty() {
+            "none".to_string()
+        } else {
+            targets.join(", ")
+        };
+        bail!(
+            "Cannot move '{}' from '{current}' to '{target}' (allowed: {allowed}; use --force to override)",
+            item.id()
+        );
+    }
+
+    let was_open = item.status() == Status::Open;
+    let (new_path, warnings) = apply_state(&config, &path, &mut item, &target)?;
+
+    ui::print_success(&format!("Moved to '{target}'"), &config, &new_path);
+    ui::print_warnings(&warnings);
+
+    // Closing through a workflow state unblocks dependents like `qs close`
+    if was_open && item.status() == Status::Closed {
+        report_dependents(&config, &item);
+    }
+
+    Ok(())
+}
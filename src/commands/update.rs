@@ -5,75 +5,161 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use owo_colors::OwoColorize;
 
-use crate::{config::Config, item::normalize_identifier, storage, ui};
+use crate::{
+    commands::bulk::{self, BulkOutcome, BulkTargets},
+    config::{schema, Config, FieldDef},
+    item::{due::parse_due, fields, normalize_identifier, Item},
+    storage, ui,
+};
 
 /// Arguments for the update command
+#[derive(Default)]
 pub struct UpdateArgs {
     pub id: Option<String>,
     pub file: Option<PathBuf>,
     pub title: Option<String>,
     pub labels: Vec<String>,
     pub remove_labels: Vec<String>,
+    /// People to assign
+    pub assign: Vec<String>,
+    /// People to unassign
+    pub unassign: Vec<String>,
     pub category: Option<String>,
     pub remove_category: bool,
+    pub priority: Option<String>,
+    pub remove_priority: bool,
+    pub due: Option<String>,
+    pub remove_due: bool,
+    /// Custom fields to set as `(key, value)` pairs
+    pub set_fields: Vec<(String, String)>,
+    /// Custom fields to remove
+    pub unset_fields: Vec<String>,
+    /// New description (`-` reads from stdin)
+    pub body: Option<String>,
+    /// File to read the new description from
+    pub body_file: Option<PathBuf>,
+    /// Text to append to the description (`-` reads from stdin)
+    pub append_body: Option<String>,
+    /// Update several items (`--query`, `--stdin`) or preview (`--dry-run`)
+    pub targets: BulkTargets,
+}
+
+/// Parsed input shared by every updated item.
+struct UpdateInput {
+    args: UpdateArgs,
+    due: Option<NaiveDate>,
+    body: Option<String>,
+    append_body: Option<String>,
 }
 
 /// Executes the update command.
 pub fn execute(args: UpdateArgs) -> Result<()> {
-    // Validate title is not empty (if provided)
-    if let Some(ref title) = args.title {
-        if title.trim().is_empty() {
-            anyhow::bail!("Title cannot be empty");
-        }
-    }
+    validate(&args)?;
 
-    // Validate labels are not empty
-    for label in &args.labels {
-        if label.trim().is_empty() {
-            anyhow::bail!("Label cannot be empty");
+    // Parse due date expression (if provided)
+    let due = args.due.as_deref().map(parse_due).transpose()?;
+
+    // Read body input before touching the item
+    let (body, append_body) = read_body_input(&args)?;
+
+    let config = Config::load()?;
+    let input = UpdateInput {
+        args,
+        due,
+        body,
+        append_body,
+    };
+    let args = &input.args;
+
+    if args.targets.is_bulk() {
+        let dry_run = args.targets.dry_run;
+        let (paths, mut outcomes) = bulk::select(
+            &config,
+            args.id.clone(),
+            args.file.clone(),
+            &args.targets,
+            false,
+        )?;
+        if paths.is_empty() && outcomes.is_empty() {
+            println!("No items found.");
+            return Ok(());
         }
+        outcomes.extend(paths.into_iter().map(|path| BulkOutcome {
+            result: update_item(&config, &input, &path, dry_run),
+            path,
+            dependents: None,
+        }));
+        return bulk::print_summary(&config, "Updated", &outcomes, dry_run);
     }
 
-    // Validate remove labels are not empty
-    for label in &args.remove_labels {
-        if label.trim().is_empty() {
-            anyhow::bail!("Label cannot be empty");
-        }
+    // Resolve item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let path = item_ref.resolve(&config)?.path;
+
+    match update_item(&config, &input, &path, false)? {
+        Some(path) => ui::print_success("Updated", &config, &path),
+        None => println!("{}", "No changes to apply.".dimmed()),
     }
 
-    // Validate category is not empty (if provided)
-    if let Some(ref cat) = args.category {
-        if cat.trim().is_empty() {
-            anyhow::bail!("Category cannot be empty");
-        }
+    Ok(())
+}
+
+/// Updates the item at `path`, taking body text literally instead of from stdin.
+///
+/// Returns the item's new path, or `None` if nothing changed.
+pub fn update(config: &Config, args: UpdateArgs, path: &Path) -> Result<Option<PathBuf>> {
+    validate(&args)?;
+    if args
+        .append_body
+        .as_ref()
+        .is_some_and(|text| text.trim().is_empty())
+    {
+        anyhow::bail!("Text to append cannot be empty");
     }
 
-    let config = Config::load()?;
+    let input = UpdateInput {
+        due: args.due.as_deref().map(parse_due).transpose()?,
+        body: args.body.clone(),
+        append_body: args.append_body.clone(),
+        args,
+    };
+    update_item(config, &input, path, false)
+}
 
-    // Resolve item from --id or --file
-    let item_ref = storage::ItemRef::from_options(args.id, args.file)?;
-    let storage::LoadedItem { mut path, mut item } = item_ref.resolve(&config)?;
+/// Applies the changes to one item.
+///
+/// Returns the item's new path, or `None` if nothing changed. With `dry_run`
+/// the changes are checked but not written.
+fn update_item(
+    config: &Config,
+    input: &UpdateInput,
+    path: &Path,
+    dry_run: bool,
+) -> Result<Option<PathBuf>> {
+    let args = &input.args;
+    let storage::LoadedItem { mut path, mut item } = storage::load_from_file(path)?;
 
     let mut changed = false;
     let old_filename = item.filename();
 
     // Update title
-    if let Some(new_title) = args.title {
+    if let Some(new_title) = &args.title {
         if new_title != item.title() {
-            item.set_title(new_title);
+            item.set_title(new_title.clone());
             changed = true;
         }
     }
 
     // Add labels
     for label in &args.labels {
+        changed |= !item.labels().contains(&normalize_identifier(label));
         item.add_label(label);
-        changed = true;
     }
 
     // Remove labels
@@ -85,11 +171,53 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
         }
     }
 
+    // Assign and unassign people
+    for name in &args.assign {
+        changed |= item.assign(name);
+    }
+    for name in &args.unassign {
+        changed |= item.unassign(name);
+    }
+
+    // Update priority
+    if let Some(ref priority) = args.priority {
+        let priority = config.resolve_priority(priority)?;
+        if item.priority() != Some(priority.as_str()) {
+            item.set_priority(Some(priority));
+            changed = true;
+        }
+    } else if args.remove_priority && item.priority().is_some() {
+        item.set_priority(None);
+        changed = true;
+    }
+
+    // Update due date
+    if let Some(due) = input.due {
+        if item.due() != Some(due) {
+            item.set_due(Some(due));
+            changed = true;
+        }
+    } else if args.remove_due && item.due().is_some() {
+        item.set_due(None);
+        changed = true;
+    }
+
+    // Replace or extend the description (comments are kept)
+    changed |= apply_body(
+        &mut item,
+        input.body.as_deref(),
+        input.append_body.as_deref(),
+    );
+
+    // Set and remove custom fields
+    let schema = config.field_schema();
+    changed |= apply_custom_fields(&mut item, schema, &args.set_fields, &args.unset_fields)?;
+
     // Normalize category
     let new_category = args.category.as_deref().map(normalize_identifier);
 
     // Check for category change (derived from path, not stored in metadata)
-    let current_category = storage::derive_category(&config, &path);
+    let current_category = storage::derive_category(config, &path);
     let category_changed = if args.remove_category {
         current_category.is_some()
     } else if let Some(ref cat) = new_category {
@@ -103,11 +231,14 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
     }
 
     if !changed {
-        println!("{}", "No changes to apply.".dimmed());
-        return Ok(());
+        return Ok(None);
+    }
+    if dry_run {
+        return Ok(Some(path));
     }
 
     // Save updated frontmatter
+    item.touch();
     item.save(&path)?;
 
     // Handle filename change (title changed)
@@ -123,14 +254,116 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
         } else {
             new_category.as_deref()
         };
-        let (new_path, warnings) = storage::move_to_category(&config, &path, category)?;
+        let (new_path, warnings) = storage::move_to_category(config, &path, category)?;
         path = new_path;
 
         // Print any attachment move warnings
         ui::print_warnings(&warnings);
     }
 
-    ui::print_success("Updated", &config, &path);
+    Ok(Some(path))
+}
+
+/// Rejects empty titles, labels, and categories.
+fn validate(args: &UpdateArgs) -> Result<()> {
+    // Validate title is not empty (if provided)
+    if let Some(ref title) = args.title {
+        if title.trim().is_empty() {
+            anyhow::bail!("Title cannot be empty");
+        }
+    }
+
+    // Validate labels are not empty
+    for label in &args.labels {
+        if label.trim().is_empty() {
+            anyhow::bail!("Label cannot be empty");
+        }
+    }
+
+    // Validate remove labels are not empty
+    for label in &args.remove_labels {
+        if label.trim().is_empty() {
+            anyhow::bail!("Label cannot be empty");
+        }
+    }
+
+    // A title is unique to an item, and stdin can only be read once
+    if args.targets.query.is_some() || args.targets.stdin {
+        if args.title.is_some() {
+            anyhow::bail!("--title cannot be used with --query or --stdin");
+        }
+        let reads_stdin = |value: &Option<String>| value.as_deref() == Some("-");
+        if args.targets.stdin && (reads_stdin(&args.body) || reads_stdin(&args.append_body)) {
+            anyhow::bail!("--stdin cannot be used with body text from stdin");
+        }
+    }
+
+    // Validate category is not empty (if provided)
+    if let Some(ref cat) = args.category {
+        if cat.trim().is_empty() {
+            anyhow::bail!("Category cannot be empty");
+        }
+    }
 
     Ok(())
 }
+
+/// Reads `--body`/`--body-file` and `--append-body` text (from stdin for `-`).
+fn read_body_input(args: &UpdateArgs) -> Result<(Option<String>, Option<String>)> {
+    let body = ui::read_body(args.body.as_deref(), args.body_file.as_deref())?;
+    let append = args
+        .append_body
+        .as_deref()
+        .map(|text| ui::read_text_arg(text, "body text"))
+        .transpose()?;
+    if append.as_ref().is_some_and(|text| text.trim().is_empty()) {
+        anyhow::bail!("Text to append cannot be empty");
+    }
+    Ok((body, append))
+}
+
+/// Applies body changes, returning true if anything changed.
+fn apply_body(item: &mut Item, body: Option<&str>, append: Option<&str>) -> bool {
+    let before = item.body.clone();
+    if let Some(body) = body {
+        item.set_description(body);
+    }
+    if let Some(text) = append {
+        item.append_description(text);
+    }
+    item.body.trim_end() != before.trim_end()
+}
+
+/// Applies `--set`/`--unset` custom field changes, returning true if anything changed.
+///
+/// Values of schema fields are validated against their declared type.
+fn apply_custom_fields(
+    item: &mut Item,
+    schema: &[FieldDef],
+    set: &[(String, String)],
+    unset: &[String],
+) -> Result<bool> {
+    let mut changed = false;
+
+    for (key, raw) in set {
+        let value = schema::coerce_field(schema, key, raw)?;
+        if item.custom_fields().get(key) != Some(&value) {
+            item.set_custom_field(key, value)?;
+            changed = true;
+        }
+    }
+
+    for key in unset {
+        if fields::is_reserved(key) {
+            anyhow::bail!("'{key}' is a built-in field; use its dedicated option instead");
+        }
+        if schema.iter().any(|def| def.name == *key && def.required) {
+            anyhow::bail!("Field '{key}' is required and cannot be removed");
+        }
+        if item.remove_custom_field(key) {
+            changed = true;
+        }
+    }
+
+    Ok(changed)
+}
@@ -0,0 +1,48 @@
+//! # View Command
+//!
+//! Lists the saved views declared in the project config.
+//!
+//! Views are defined as `[[views]]` tables in `.queuestack` (or saved from the
+//! TUI filter overlay) and used with `qs list --view <name>`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    output::{self, OutputFormat},
+};
+
+/// Executes `qs view list`.
+pub fn execute_list(format: OutputFormat) -> Result<()> {
+    let config = Config::load()?;
+    let views = config.views();
+
+    if format.is_machine() {
+        return output::print_records(format, views);
+    }
+
+    if views.is_empty() {
+        println!("No views defined. Add [[views]] tables to .queuestack.");
+        return Ok(());
+    }
+
+    let width = views.iter().map(|view| view.name.len()).max().unwrap_or(0);
+    for view in views {
+        let summary = view.summary();
+        if summary.is_empty() {
+            println!("{}", view.name.bold());
+        } else {
+            println!(
+                "{}  {}",
+                format!("{:width$}", view.name).bold(),
+                summary.dimmed()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -8,16 +8,27 @@
 
 pub mod global;
 pub mod project;
+pub mod schema;
+pub mod view;
+pub mod workflow;
 
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 pub use self::{
     global::{set_home_override, ConfigValidation, GlobalConfig},
     project::ProjectConfig,
+    schema::{FieldDef, FieldType},
+    view::{Column, View, DEFAULT_COLUMNS},
+    workflow::Workflow,
+};
+use crate::{
+    constants::{DEFAULT_PRIORITIES, INDEX_FILE},
+    id::DEFAULT_PATTERN,
+    item::{Item, Status},
+    storage::git,
 };
-use crate::{id::DEFAULT_PATTERN, storage::git};
 
 /// Merged configuration with project settings overriding global
 #[derive(Debug, Clone)]
@@ -42,6 +53,26 @@ impl Config {
         })?;
 
         let project = ProjectConfig::load(&project_root)?;
+        schema::check_schema(&project.fields).with_context(|| {
+            format!(
+                "Invalid field schema in {}",
+                ProjectConfig::path(&project_root).display()
+            )
+        })?;
+        if let Some(workflow) = &project.workflow {
+            workflow.check().with_context(|| {
+                format!(
+                    "Invalid workflow in {}",
+                    ProjectConfig::path(&project_root).display()
+                )
+            })?;
+        }
+        view::check_views(&project.views).with_context(|| {
+            format!(
+                "Invalid view in {}",
+                ProjectConfig::path(&project_root).display()
+            )
+        })?;
 
         Ok(Self {
             global,
@@ -160,6 +191,107 @@ impl Config {
             .or_else(|| std::env::var("EDITOR").ok())
     }
 
+    /// Returns the configured priority levels, highest first (project-only)
+    pub fn priorities(&self) -> Vec<String> {
+        self.project
+            .priorities
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PRIORITIES.iter().map(ToString::to_string).collect())
+    }
+
+    /// Validates a priority against the configured levels.
+    ///
+    /// Matching is case-insensitive; returns the level as spelled in the config.
+    pub fn resolve_priority(&self, priority: &str) -> Result<String> {
+        let levels = self.priorities();
+        levels
+            .iter()
+            .find(|p| p.eq_ignore_ascii_case(priority.trim()))
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown priority '{priority}' (expected one of: {})",
+                    levels.join(", ")
+                )
+            })
+    }
+
+    /// Returns the typed custom fields declared in the project config
+    pub fn field_schema(&self) -> &[FieldDef] {
+        &self.project.fields
+    }
+
+    /// Whether parsed items are cached in the on-disk index (project-only)
+    pub fn use_index(&self) -> bool {
+        self.project.index.unwrap_or(false)
+    }
+
+    /// Whether listing commands fail on item files that cannot be parsed
+    /// (project-only)
+    pub fn strict(&self) -> bool {
+        self.project.strict.unwrap_or(false)
+    }
+
+    /// Returns the saved views declared in the project config
+    pub fn views(&self) -> &[View] {
+        &self.project.views
+    }
+
+    /// Finds a saved view by name (case-insensitive)
+    pub fn view(&self, name: &str) -> Result<&View> {
+        self.project
+            .views
+            .iter()
+            .find(|view| view.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                let names: Vec<_> = self.project.views.iter().map(|v| v.name.as_str()).collect();
+                if names.is_empty() {
+                    anyhow::anyhow!("Unknown view '{name}' (no views are defined in .queuestack)")
+                } else {
+                    anyhow::anyhow!(
+                        "Unknown view '{name}' (expected one of: {})",
+                        names.join(", ")
+                    )
+                }
+            })
+    }
+
+    /// Saves a new view to the project config
+    pub fn save_view(&mut self, view: View) -> Result<()> {
+        view::save_view(&self.project_root, &self.project.views, &view)?;
+        self.project.views.push(view);
+        Ok(())
+    }
+
+    /// Returns the project workflow (built-in open/closed if not configured)
+    pub fn workflow(&self) -> Workflow {
+        self.project.workflow.clone().unwrap_or_default()
+    }
+
+    /// Whether the project defines its own workflow states
+    pub const fn has_workflow(&self) -> bool {
+        self.project.workflow.is_some()
+    }
+
+    /// Returns the state to record on a new item (`None` without a workflow)
+    pub fn initial_state(&self) -> Option<String> {
+        self.project
+            .workflow
+            .as_ref()
+            .map(|w| w.initial().to_string())
+    }
+
+    /// Returns the state shown for an item (workflow state or plain status)
+    pub fn display_state(&self, item: &Item) -> String {
+        self.project.workflow.as_ref().map_or_else(
+            || item.status().to_string(),
+            |workflow| match item.status() {
+                Status::Template => Status::Template.to_string(),
+                Status::Open | Status::Closed => workflow.current_state(item),
+            },
+        )
+    }
+
     // -------------------------------------------------------------------------
     // Path helpers
     // -------------------------------------------------------------------------
@@ -174,6 +306,11 @@ impl Config {
         self.project_root.join(self.stack_dir())
     }
 
+    /// Returns the item index file path
+    pub fn index_path(&self) -> PathBuf {
+        self.stack_path().join(INDEX_FILE)
+    }
+
     /// Returns the archive directory path
     pub fn archive_path(&self) -> PathBuf {
         self.stack_path().join(self.archive_dir())
@@ -13,6 +13,8 @@ use std::{
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use super::{schema::FieldDef, view::View, workflow::Workflow};
+
 /// Project configuration file name
 pub const PROJECT_CONFIG_FILE: &str = ".queuestack";
 
@@ -52,6 +54,30 @@ pub struct ProjectConfig {
     /// Directory name for templates (overrides global)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_dir: Option<String>,
+
+    /// Priority levels, highest first (project-only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priorities: Option<Vec<String>>,
+
+    /// Typed custom fields (project-only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldDef>,
+
+    /// Workflow states and transitions (project-only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<Workflow>,
+
+    /// Whether to cache parsed items in an on-disk index (project-only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<bool>,
+
+    /// Whether listing commands fail on unparseable item files (project-only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+
+    /// Saved list views (project-only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub views: Vec<View>,
 }
 
 impl ProjectConfig {
@@ -152,6 +178,69 @@ impl ProjectConfig {
 # Subdirectory name for templates within the queuestack directory.
 # If not set, falls back to global config (default: ".templates").
 # template_dir = ".templates"
+
+# Priority levels for items, from highest to lowest.
+# Used to validate --priority and to order `qs list --sort priority`.
+# Default: ["critical", "high", "medium", "low"]
+# priorities = ["critical", "high", "medium", "low"]
+
+# Typed custom fields. Each [[fields]] table declares one frontmatter key.
+# Types: "string", "int", "enum", "date", "bool".
+# `values` lists allowed values (required for enums), `default` is applied to
+# new items, and `required = true` rejects new items without a value.
+#
+# [[fields]]
+# name = "severity"
+# type = "enum"
+# values = ["minor", "major", "blocker"]
+# default = "minor"
+#
+# [[fields]]
+# name = "estimate"
+# type = "int"
+
+# Workflow states, stored in each item's `state` key and changed with
+# `qs status --id <id> <state>`. States listed in `archived` close the item
+# and move it into the archive; all other states keep it open.
+# `initial` is the state of new and reopened items (default: first state),
+# and `qs close` uses the first archived state.
+# If [workflow.transitions] is omitted, any transition is allowed.
+# Default: the built-in open/closed workflow.
+#
+# [workflow]
+# states = ["todo", "in-progress", "review", "done"]
+# archived = ["done"]
+#
+# [workflow.transitions]
+# todo = ["in-progress"]
+# in-progress = ["review", "todo"]
+# review = ["done", "in-progress"]
+# done = ["todo"]
+
+# Cache parsed items in queuestack/.index.json to speed up listing and searching
+# large stacks. Changed files are re-read automatically; the file is safe to
+# delete and should not be committed.
+# Default: false
+# index = true
+
+# Fail `qs list`, `qs search`, and `qs board` when an item file cannot be
+# parsed, instead of skipping it with a warning (same as --strict; for CI).
+# Default: false
+# strict = true
+
+# Saved views for `qs list --view <name>` (list them with `qs view list`).
+# A view combines filters (query, labels, category, author, assignees,
+# priorities, states, where, closed), a sort order, and the columns of the
+# interactive list. Filters given on the command line are added to the view's.
+# Columns: id, status, priority, title, labels, category, assignees, author,
+# due, created, updated.
+#
+# [[views]]
+# name = "triage"
+# labels = ["bug"]
+# category = "backend"
+# sort = "date"
+# columns = ["id", "priority", "title", "assignees"]
 "#;
 
         fs::write(&path, content)
@@ -162,6 +251,7 @@ impl ProjectConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::FieldType;
 
     #[test]
     fn test_default_config() {
@@ -174,6 +264,11 @@ mod tests {
         assert!(config.stack_dir.is_none());
         assert!(config.archive_dir.is_none());
         assert!(config.template_dir.is_none());
+        assert!(config.priorities.is_none());
+        assert!(config.fields.is_empty());
+        assert!(config.workflow.is_none());
+        assert!(config.strict.is_none());
+        assert!(config.views.is_empty());
     }
 
     #[test]
@@ -193,6 +288,55 @@ interactive = false
         assert_eq!(config.interactive, Some(false));
     }
 
+    #[test]
+    fn test_parse_priorities() {
+        let toml = r#"priorities = ["p0", "p1", "p2"]"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.priorities,
+            Some(vec!["p0".to_string(), "p1".to_string(), "p2".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let toml = r#"
+[[fields]]
+name = "severity"
+type = "enum"
+values = ["minor", "major"]
+default = "minor"
+
+[[fields]]
+name = "estimate"
+type = "int"
+required = true
+"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.fields.len(), 2);
+        assert_eq!(config.fields[0].name, "severity");
+        assert_eq!(config.fields[0].kind, FieldType::Enum);
+        assert_eq!(config.fields[1].kind, FieldType::Int);
+        assert!(config.fields[1].required);
+    }
+
+    #[test]
+    fn test_parse_workflow() {
+        let toml = r#"
+[workflow]
+states = ["todo", "doing", "done"]
+archived = ["done"]
+
+[workflow.transitions]
+todo = ["doing"]
+"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let workflow = config.workflow.unwrap();
+        assert_eq!(workflow.states, ["todo", "doing", "done"]);
+        assert_eq!(workflow.archived, ["done"]);
+        assert_eq!(workflow.transitions["todo"], ["doing"]);
+    }
+
     #[test]
     fn test_parse_minimal_config() {
         // Empty config should work - all fields are optional
@@ -200,5 +344,6 @@ interactive = false
         let config: ProjectConfig = toml::from_str(toml).unwrap();
         assert!(config.stack_dir.is_none());
         assert!(config.archive_dir.is_none());
+        assert!(config.index.is_none());
     }
 }
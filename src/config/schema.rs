@@ -0,0 +1,300 @@
+//! # Field Schema
+//!
+//! Typed custom fields declared in the project config (`[[fields]]` tables).
+//!
+//! A schema field has a type (`string`, `int`, `enum`, `date`, `bool`), an
+//! optional list of allowed values, an optional default, and can be marked
+//! required. Values from the CLI and the wizard are coerced through the
+//! schema before they are written to an item's frontmatter; keys without a
+//! schema entry stay free-form.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_yml::Value;
+
+use crate::item::{
+    due::{format_due, parse_due},
+    fields, Item,
+};
+
+/// Value type of a schema field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    /// Free text
+    #[default]
+    String,
+    /// Whole number
+    Int,
+    /// One of the declared `values`
+    Enum,
+    /// Calendar date (stored as `YYYY-MM-DD`, accepts `--due` expressions)
+    Date,
+    /// `true` or `false`
+    Bool,
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Int => write!(f, "int"),
+            Self::Enum => write!(f, "enum"),
+            Self::Date => write!(f, "date"),
+            Self::Bool => write!(f, "bool"),
+        }
+    }
+}
+
+/// A custom field declared in the project config.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDef {
+    /// Frontmatter key
+    pub name: String,
+
+    /// Value type
+    #[serde(rename = "type", default)]
+    pub kind: FieldType,
+
+    /// Allowed values (required for `enum`, optional for other types)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+
+    /// Value applied to new items that don't set the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<toml::Value>,
+
+    /// Whether new items must have a value (after defaults)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+}
+
+impl FieldDef {
+    /// Checks that the definition itself is usable.
+    pub fn check(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            bail!("Field name cannot be empty");
+        }
+        if fields::is_reserved(&self.name) {
+            bail!(
+                "'{}' is a built-in field and cannot be redefined",
+                self.name
+            );
+        }
+        if self.kind == FieldType::Enum && self.values.is_empty() {
+            bail!("Enum field '{}' needs a list of values", self.name);
+        }
+        if let Some(default) = self.default_raw() {
+            self.coerce(&default)
+                .map_err(|e| anyhow::anyhow!("Invalid default for '{}': {e}", self.name))?;
+        }
+        Ok(())
+    }
+
+    /// Coerces a raw value into the field's type.
+    ///
+    /// Enum values and allowed values match case-insensitively and are
+    /// returned as spelled in the config.
+    pub fn coerce(&self, raw: &str) -> Result<Value> {
+        let raw = raw.trim();
+        let value = match self.kind {
+            FieldType::String => Value::String(raw.to_string()),
+            FieldType::Int => raw.parse::<i64>().map(Value::from).map_err(|_| {
+                anyhow::anyhow!("'{raw}' is not a whole number (field '{}')", self.name)
+            })?,
+            FieldType::Enum => return self.resolve_allowed(raw).map(Value::String),
+            FieldType::Date => Value::String(format_due(parse_due(raw)?)),
+            FieldType::Bool => match raw.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "y" | "1" => Value::Bool(true),
+                "false" | "no" | "n" | "0" => Value::Bool(false),
+                _ => bail!("'{raw}' is not a boolean (field '{}')", self.name),
+            },
+        };
+
+        if self.values.is_empty() {
+            return Ok(value);
+        }
+        self.resolve_allowed(&fields::display_value(&value))?;
+        Ok(value)
+    }
+
+    /// Returns the default value, coerced into the field's type.
+    pub fn default_value(&self) -> Option<Value> {
+        self.default_raw().and_then(|raw| self.coerce(&raw).ok())
+    }
+
+    /// Returns the values the wizard cycles through (empty for free input).
+    pub fn choices(&self) -> Vec<String> {
+        if self.kind == FieldType::Bool && self.values.is_empty() {
+            return vec!["true".to_string(), "false".to_string()];
+        }
+        self.values.clone()
+    }
+
+    /// Short description of the type, e.g. `enum: low, high`.
+    pub fn describe(&self) -> String {
+        if self.values.is_empty() {
+            self.kind.to_string()
+        } else {
+            format!("{}: {}", self.kind, self.values.join(", "))
+        }
+    }
+
+    fn default_raw(&self) -> Option<String> {
+        self.default.as_ref().map(|value| match value {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+    }
+
+    fn resolve_allowed(&self, raw: &str) -> Result<String> {
+        self.values
+            .iter()
+            .find(|v| v.eq_ignore_ascii_case(raw))
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid value '{raw}' for '{}' (expected one of: {})",
+                    self.name,
+                    self.values.join(", ")
+                )
+            })
+    }
+}
+
+/// Checks a whole schema for invalid definitions and duplicate names.
+pub fn check_schema(schema: &[FieldDef]) -> Result<()> {
+    for (i, def) in schema.iter().enumerate() {
+        def.check()?;
+        if schema[..i].iter().any(|other| other.name == def.name) {
+            bail!("Field '{}' is defined more than once", def.name);
+        }
+    }
+    Ok(())
+}
+
+/// Coerces a `key=value` pair through the schema.
+///
+/// Keys without a schema entry are parsed as free-form YAML scalars.
+pub fn coerce_field(schema: &[FieldDef], key: &str, raw: &str) -> Result<Value> {
+    schema
+        .iter()
+        .find(|def| def.name == key)
+        .map_or_else(|| Ok(fields::parse_value(raw)), |def| def.coerce(raw))
+}
+
+/// Applies field values to a new item, fills in defaults, and checks
+/// required fields.
+///
+/// Empty values are skipped, so the wizard can pass every schema field.
+pub fn apply_to_new_item(
+    schema: &[FieldDef],
+    item: &mut Item,
+    values: &[(String, String)],
+) -> Result<()> {
+    for (key, raw) in values {
+        if raw.trim().is_empty() {
+            continue;
+        }
+        item.set_custom_field(key, coerce_field(schema, key, raw)?)?;
+    }
+
+    for def in schema {
+        if item.custom_fields().get(&def.name).is_some() {
+            continue;
+        }
+        if let Some(default) = def.default_value() {
+            item.set_custom_field(&def.name, default)?;
+        } else if def.required {
+            bail!(
+                "Field '{}' is required (use --set {}=<{}>)",
+                def.name,
+                def.name,
+                def.describe()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn def(toml: &str) -> FieldDef {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_coerce_types() {
+        let int = def("name = \"estimate\"\ntype = \"int\"");
+        assert_eq!(int.coerce("3").unwrap(), Value::from(3_i64));
+        assert!(int.coerce("three").is_err());
+
+        let boolean = def("name = \"flag\"\ntype = \"bool\"");
+        assert_eq!(boolean.coerce("yes").unwrap(), Value::Bool(true));
+        assert!(boolean.coerce("maybe").is_err());
+
+        let date = def("name = \"review\"\ntype = \"date\"");
+        assert_eq!(
+            date.coerce("2026-03-15").unwrap(),
+            Value::String("2026-03-15".to_string())
+        );
+        assert!(date.coerce("someday").is_err());
+    }
+
+    #[test]
+    fn test_enum_and_allowed_values() {
+        let severity = def("name = \"severity\"\ntype = \"enum\"\nvalues = [\"Minor\", \"Major\"]");
+        assert_eq!(
+            severity.coerce("major").unwrap(),
+            Value::String("Major".to_string())
+        );
+        assert!(severity.coerce("blocker").is_err());
+
+        let points = def("name = \"points\"\ntype = \"int\"\nvalues = [\"1\", \"2\", \"3\"]");
+        assert!(points.coerce("2").is_ok());
+        assert!(points.coerce("5").is_err());
+    }
+
+    #[test]
+    fn test_check_schema() {
+        assert!(def("name = \"kind\"\ntype = \"enum\"").check().is_err());
+        assert!(def("name = \"status\"").check().is_err());
+        assert!(def("name = \"estimate\"\ntype = \"int\"\ndefault = \"x\"")
+            .check()
+            .is_err());
+
+        let dup = vec![def("name = \"a\""), def("name = \"a\"")];
+        assert!(check_schema(&dup).is_err());
+    }
+
+    #[test]
+    fn test_apply_defaults_and_required() {
+        let schema = vec![
+            def("name = \"estimate\"\ntype = \"int\"\ndefault = 1"),
+            def("name = \"component\"\nrequired = true"),
+        ];
+        let mut item = Item::new(Frontmatter::default());
+
+        assert!(apply_to_new_item(&schema, &mut item, &[]).is_err());
+
+        let values = vec![("component".to_string(), "api".to_string())];
+        apply_to_new_item(&schema, &mut item, &values).unwrap();
+        assert_eq!(
+            item.custom_fields().get("estimate"),
+            Some(&Value::from(1_i64))
+        );
+        assert_eq!(
+            item.custom_fields().get("component"),
+            Some(&Value::String("api".to_string()))
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! # Saved Views
+//!
+//! Named list presets declared in the project config (`[[views]]` tables).
+//!
+//! A view bundles filter criteria, a sort order, and the columns shown in the
+//! interactive list. `qs list --view <name>` starts from the view and adds any
+//! filters given on the command line; `qs view list` shows all views, and the
+//! TUI can cycle through them or save its current filter as a new one.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt, fs, io::Write, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::project::ProjectConfig;
+use crate::item::{FieldFilter, FilterCriteria, Query};
+
+/// A column of the interactive item list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Id,
+    Status,
+    Priority,
+    Title,
+    Labels,
+    Category,
+    Assignees,
+    Author,
+    Due,
+    Created,
+    Updated,
+}
+
+/// Columns shown when no view sets its own.
+pub const DEFAULT_COLUMNS: &[Column] = &[
+    Column::Id,
+    Column::Status,
+    Column::Priority,
+    Column::Title,
+    Column::Labels,
+    Column::Category,
+];
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Id => "ID",
+            Self::Status => "Status",
+            Self::Priority => "Priority",
+            Self::Title => "Title",
+            Self::Labels => "Labels",
+            Self::Category => "Category",
+            Self::Assignees => "Assignees",
+            Self::Author => "Author",
+            Self::Due => "Due",
+            Self::Created => "Created",
+            Self::Updated => "Updated",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A named list preset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct View {
+    /// Name used with `qs list --view`
+    pub name: String,
+
+    /// Query expression (same syntax as `qs list --query`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Labels (AND logic)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Author (substring match)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Assignees (OR logic)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+
+    /// Priorities (OR logic)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub priorities: Vec<String>,
+
+    /// Workflow states (OR logic, includes archived items)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub states: Vec<String>,
+
+    /// Frontmatter field conditions like `sprint=12` (AND logic)
+    #[serde(rename = "where", default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+
+    /// Show closed items instead of open ones
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub closed: bool,
+
+    /// Sort order (same values as `qs list --sort`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+
+    /// Columns of the interactive list (default: `DEFAULT_COLUMNS`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    /// Checks that the name, query, and field conditions are valid.
+    pub fn check(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            bail!("View names cannot be empty");
+        }
+        self.parse_query()?;
+        self.parse_fields()?;
+        Ok(())
+    }
+
+    /// Returns the columns of the interactive list.
+    pub fn columns(&self) -> &[Column] {
+        if self.columns.is_empty() {
+            DEFAULT_COLUMNS
+        } else {
+            &self.columns
+        }
+    }
+
+    /// Adds the view's filters to `criteria`.
+    ///
+    /// List filters are extended, the category and author are only set if
+    /// `criteria` has none, and queries are combined with AND.
+    pub fn apply(&self, criteria: &mut FilterCriteria) -> Result<()> {
+        criteria.labels.extend(self.labels.iter().cloned());
+        criteria.assignees.extend(self.assignees.iter().cloned());
+        criteria.priorities.extend(self.priorities.iter().cloned());
+        criteria.fields.extend(self.parse_fields()?);
+        if criteria.category.is_none() {
+            criteria.category.clone_from(&self.category);
+        }
+        if criteria.author.is_none() {
+            criteria.author.clone_from(&self.author);
+        }
+        if let Some(query) = self.parse_query()? {
+            criteria.query = Some(match criteria.query.take() {
+                Some(existing) => Query::And(vec![query, existing]),
+                None => query,
+            });
+        }
+        Ok(())
+    }
+
+    /// Summarizes the view's settings in one line (e.g. for `qs view list`).
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(query) = &self.query {
+            parts.push(format!("query: {query}"));
+        }
+        let mut push_list = |name: &str, values: &[String]| {
+            if !values.is_empty() {
+                parts.push(format!("{name}: {}", values.join(", ")));
+            }
+        };
+        push_list("labels", &self.labels);
+        push_list("assignees", &self.assignees);
+        push_list("priorities", &self.priorities);
+        push_list("states", &self.states);
+        push_list("where", &self.fields);
+        if let Some(category) = &self.category {
+            parts.push(format!("category: {category}"));
+        }
+        if let Some(author) = &self.author {
+            parts.push(format!("author: {author}"));
+        }
+        if self.closed {
+            parts.push("closed".to_string());
+        }
+        if let Some(sort) = &self.sort {
+            parts.push(format!("sort: {sort}"));
+        }
+        if !self.columns.is_empty() {
+            let columns: Vec<_> = self.columns.iter().map(ToString::to_string).collect();
+            parts.push(format!("columns: {}", columns.join(", ")));
+        }
+        parts.join("; ")
+    }
+
+    fn parse_query(&self) -> Result<Option<Query>> {
+        self.query
+            .as_deref()
+            .filter(|query| !query.trim().is_empty())
+            .map(Query::parse)
+            .transpose()
+            .with_context(|| format!("Invalid query in view '{}'", self.name))
+    }
+
+    fn parse_fields(&self) -> Result<Vec<FieldFilter>> {
+        self.fields
+            .iter()
+            .map(|field| FieldFilter::parse(field))
+            .collect::<Result<_>>()
+            .with_context(|| format!("Invalid condition in view '{}'", self.name))
+    }
+}
+
+/// Checks every view and rejects duplicate names.
+pub fn check_views(views: &[View]) -> Result<()> {
+    for (i, view) in views.iter().enumerate() {
+        view.check()?;
+        if views[..i]
+            .iter()
+            .any(|other| other.name.eq_ignore_ascii_case(&view.name))
+        {
+            bail!("View '{}' is defined more than once", view.name);
+        }
+    }
+    Ok(())
+}
+
+/// A single view serialized as a `[[views]]` table.
+#[derive(Serialize)]
+struct ViewTable<'a> {
+    views: [&'a View; 1],
+}
+
+/// Appends a view to the project config as a `[[views]]` table.
+///
+/// The file is appended to rather than rewritten, so comments and formatting
+/// of the existing config are kept.
+pub fn save_view(project_root: &Path, views: &[View], view: &View) -> Result<()> {
+    view.check()?;
+    if views
+        .iter()
+        .any(|other| other.name.eq_ignore_ascii_case(&view.name))
+    {
+        bail!("View '{}' already exists", view.name);
+    }
+
+    let table =
+        toml::to_string(&ViewTable { views: [view] }).context("Failed to serialize view")?;
+
+    let path = ProjectConfig::path(project_root);
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    let separator = match existing.len() {
+        0 => "",
+        _ if existing.ends_with("\n\n") => "",
+        _ if existing.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open project config: {}", path.display()))?;
+    write!(file, "{separator}{table}")
+        .with_context(|| format!("Failed to write project config: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(name: &str) -> View {
+        View {
+            name: name.to_string(),
+            ..View::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_view() {
+        let toml = r#"
+[[views]]
+name = "triage"
+labels = ["bug"]
+category = "backend"
+where = ["estimate>=3"]
+sort = "date"
+columns = ["id", "title", "assignees"]
+"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let view = &config.views[0];
+        assert_eq!(view.name, "triage");
+        assert_eq!(view.fields, ["estimate>=3"]);
+        assert_eq!(
+            view.columns(),
+            [Column::Id, Column::Title, Column::Assignees]
+        );
+        assert_eq!(config.views.len(), 1);
+        assert!(
+            toml::from_str::<ProjectConfig>("[[views]]\nname = \"x\"\ncolumns = [\"nope\"]")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_views() {
+        assert!(check_views(&[view("a"), view("b")]).is_ok());
+        assert!(check_views(&[view("a"), view("A")]).is_err());
+        assert!(check_views(&[view(" ")]).is_err());
+
+        let bad_query = View {
+            query: Some("(label:bug".to_string()),
+            ..view("a")
+        };
+        assert!(bad_query.check().is_err());
+        let bad_where = View {
+            fields: vec!["estimate".to_string()],
+            ..view("a")
+        };
+        assert!(bad_where.check().is_err());
+    }
+
+    #[test]
+    fn test_apply_extends_criteria() {
+        let view = View {
+            labels: vec!["bug".to_string()],
+            category: Some("backend".to_string()),
+            query: Some("NOT label:wontfix".to_string()),
+            ..view("triage")
+        };
+        let mut criteria = FilterCriteria {
+            labels: vec!["ui".to_string()],
+            category: Some("frontend".to_string()),
+            query: Some(Query::parse("crash").unwrap()),
+            ..FilterCriteria::default()
+        };
+        view.apply(&mut criteria).unwrap();
+
+        assert_eq!(criteria.labels, ["ui", "bug"]);
+        // Command-line values win over the view's
+        assert_eq!(criteria.category.as_deref(), Some("frontend"));
+        assert!(matches!(criteria.query, Some(Query::And(ref parts)) if parts.len() == 2));
+    }
+
+    #[test]
+    fn test_save_view_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = ProjectConfig::path(dir.path());
+        fs::write(&path, "# comment\nstack_dir = \"queuestack\"\n").unwrap();
+
+        let triage = View {
+            labels: vec!["bug".to_string()],
+            columns: vec![Column::Id, Column::Title],
+            ..view("triage")
+        };
+        save_view(dir.path(), &[], &triage).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# comment\n"));
+        let config = ProjectConfig::load(dir.path()).unwrap();
+        assert_eq!(config.views, [triage]);
+
+        let err = save_view(dir.path(), &config.views, &view("Triage")).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+}
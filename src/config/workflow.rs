@@ -0,0 +1,243 @@
+//! # Workflow
+//!
+//! Project-defined workflow states (e.g. `todo → in-progress → review → done`)
+//! configured in the `[workflow]` table of `.queuestack`.
+//!
+//! The workflow state is stored in the item's `state` key. The built-in
+//! `status` stays the source of truth for where the item lives: states listed
+//! in `archived` close the item and move it into the archive, all other states
+//! keep it open. Without a `[workflow]` table the workflow is simply
+//! `open`/`closed` and no `state` key is written.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::item::{Item, Status};
+
+/// Workflow states and transition rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workflow {
+    /// All states, in workflow order
+    pub states: Vec<String>,
+
+    /// State of new and reopened items (default: first non-archived state)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial: Option<String>,
+
+    /// States that close the item and move it into the archive
+    #[serde(default)]
+    pub archived: Vec<String>,
+
+    /// Allowed transitions per state (empty = any transition is allowed)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub transitions: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Workflow {
+    /// The built-in `open`/`closed` workflow.
+    fn default() -> Self {
+        Self {
+            states: vec!["open".to_string(), "closed".to_string()],
+            initial: None,
+            archived: vec!["closed".to_string()],
+            transitions: BTreeMap::new(),
+        }
+    }
+}
+
+impl Workflow {
+    /// Checks that states, archived states, and transitions are consistent.
+    pub fn check(&self) -> Result<()> {
+        if self.states.is_empty() {
+            bail!("Workflow needs at least one state");
+        }
+        for (i, state) in self.states.iter().enumerate() {
+            if state.trim().is_empty() {
+                bail!("Workflow state names cannot be empty");
+            }
+            if self.states[..i].contains(state) {
+                bail!("Workflow state '{state}' is defined more than once");
+            }
+        }
+
+        let unknown = |state: &String| !self.states.contains(state);
+        if let Some(state) = self.archived.iter().find(|s| unknown(s)) {
+            bail!("Archived state '{state}' is not a workflow state");
+        }
+        if self.archived.is_empty() {
+            bail!("Workflow needs at least one archived state");
+        }
+        if self.states.iter().all(|s| self.is_archived(s)) {
+            bail!("Workflow needs at least one state that is not archived");
+        }
+        if let Some(initial) = &self.initial {
+            if unknown(initial) {
+                bail!("Initial state '{initial}' is not a workflow state");
+            }
+            if self.is_archived(initial) {
+                bail!("Initial state '{initial}' cannot be an archived state");
+            }
+        }
+        for (from, targets) in &self.transitions {
+            if unknown(from) {
+                bail!("Transition from unknown state '{from}'");
+            }
+            if let Some(to) = targets.iter().find(|s| unknown(s)) {
+                bail!("Transition from '{from}' to unknown state '{to}'");
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a state name (case-insensitive) to its configured spelling.
+    pub fn resolve(&self, state: &str) -> Result<&str> {
+        self.states
+            .iter()
+            .find(|s| s.eq_ignore_ascii_case(state.trim()))
+            .map(String::as_str)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown state '{state}' (expected one of: {})",
+                    self.states.join(", ")
+                )
+            })
+    }
+
+    /// State of new and reopened items.
+    pub fn initial(&self) -> &str {
+        self.initial.as_deref().unwrap_or_else(|| {
+            self.states
+                .iter()
+                .find(|s| !self.is_archived(s))
+                .map_or("open", String::as_str)
+        })
+    }
+
+    /// State applied by `qs close` (the first archived state).
+    pub fn closed_state(&self) -> &str {
+        self.archived.first().map_or("closed", String::as_str)
+    }
+
+    /// Returns true if the state closes the item.
+    pub fn is_archived(&self, state: &str) -> bool {
+        self.archived.iter().any(|s| s == state)
+    }
+
+    /// Returns the item's current state.
+    ///
+    /// Items without a (known) `state` key fall back to the initial state when
+    /// open and to the closed state when closed.
+    pub fn current_state(&self, item: &Item) -> String {
+        if let Some(state) = item.state().and_then(|s| self.resolve(s).ok()) {
+            return state.to_string();
+        }
+        match item.status() {
+            Status::Closed => self.closed_state().to_string(),
+            Status::Open | Status::Template => self.initial().to_string(),
+        }
+    }
+
+    /// Returns the states reachable from `from`.
+    pub fn targets(&self, from: &str) -> Vec<&str> {
+        if self.transitions.is_empty() {
+            return self
+                .states
+                .iter()
+                .filter(|s| *s != from)
+                .map(String::as_str)
+                .collect();
+        }
+        self.transitions
+            .get(from)
+            .map(|targets| targets.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if moving from `from` to `to` is allowed.
+    pub fn can_transition(&self, from: &str, to: &str) -> bool {
+        self.targets(from).contains(&to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn review_workflow() -> Workflow {
+        toml::from_str(
+            r#"
+states = ["todo", "in-progress", "review", "done", "wontfix"]
+archived = ["done", "wontfix"]
+
+[transitions]
+todo = ["in-progress", "wontfix"]
+in-progress = ["review", "todo"]
+review = ["done", "in-progress"]
+done = ["todo"]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_workflow() {
+        let workflow = Workflow::default();
+        assert!(workflow.check().is_ok());
+        assert_eq!(workflow.initial(), "open");
+        assert_eq!(workflow.closed_state(), "closed");
+        assert!(workflow.can_transition("open", "closed"));
+    }
+
+    #[test]
+    fn test_transitions() {
+        let workflow = review_workflow();
+        assert!(workflow.check().is_ok());
+        assert_eq!(workflow.initial(), "todo");
+        assert_eq!(workflow.closed_state(), "done");
+        assert!(workflow.can_transition("todo", "in-progress"));
+        assert!(!workflow.can_transition("todo", "done"));
+        assert!(workflow.targets("wontfix").is_empty());
+        assert_eq!(workflow.resolve("REVIEW").unwrap(), "review");
+        assert!(workflow.resolve("blocked").is_err());
+    }
+
+    #[test]
+    fn test_current_state_falls_back_to_status() {
+        let workflow = review_workflow();
+        let mut item = Item::new(Frontmatter::default());
+        assert_eq!(workflow.current_state(&item), "todo");
+
+        item.set_status(Status::Closed);
+        assert_eq!(workflow.current_state(&item), "done");
+
+        item.set_state(Some("wontfix".to_string()));
+        assert_eq!(workflow.current_state(&item), "wontfix");
+    }
+
+    #[test]
+    fn test_check_rejects_inconsistent_workflows() {
+        let mut workflow = review_workflow();
+        workflow.archived = vec!["finished".to_string()];
+        assert!(workflow.check().is_err());
+
+        let mut workflow = review_workflow();
+        workflow.initial = Some("done".to_string());
+        assert!(workflow.check().is_err());
+
+        let mut workflow = review_workflow();
+        workflow
+            .transitions
+            .insert("todo".to_string(), vec!["blocked".to_string()]);
+        assert!(workflow.check().is_err());
+
+        let mut workflow = review_workflow();
+        workflow.archived.clone_from(&workflow.states);
+        assert!(workflow.check().is_err());
+    }
+}
@@ -21,6 +21,27 @@ pub const UI_COL_ID_WIDTH: usize = 15;
 /// Column width for status in list display.
 pub const UI_COL_STATUS_WIDTH: usize = 6;
 
+/// Column width for priority in list display.
+pub const UI_COL_PRIORITY_WIDTH: usize = 8;
+
+/// Column width for names (category, author, assignees) in list display.
+pub const UI_COL_NAME_WIDTH: usize = 20;
+
+/// Column width for dates (due, created, updated) in list display.
+pub const UI_COL_DATE_WIDTH: usize = 10;
+
+/// Minimum width of a board column (narrower boards scroll horizontally).
+pub const UI_BOARD_MIN_COLUMN_WIDTH: u16 = 24;
+
+/// Minimum terminal width for showing the preview pane next to the item list.
+pub const UI_PREVIEW_MIN_WIDTH: u16 = 80;
+
+/// Maximum length of a search snippet line (clipped around the match).
+pub const UI_SNIPPET_WIDTH: usize = 100;
+
+/// Lines of context shown above and below a search snippet match.
+pub const UI_SNIPPET_CONTEXT_LINES: usize = 1;
+
 // =============================================================================
 // Item Format
 // =============================================================================
@@ -34,6 +55,9 @@ pub const FRONTMATTER_DELIMITER: &str = "---";
 /// Attachment filename infix (between item ID and counter).
 pub const ATTACHMENT_INFIX: &str = "-Attachment-";
 
+/// Default priority levels, highest first.
+pub const DEFAULT_PRIORITIES: &[&str] = &["critical", "high", "medium", "low"];
+
 // =============================================================================
 // File System
 // =============================================================================
@@ -50,6 +74,9 @@ pub const DEFAULT_ARCHIVE_DIR: &str = ".archive";
 /// Default subdirectory name for templates (inside `stack_dir`).
 pub const DEFAULT_TEMPLATE_DIR: &str = ".templates";
 
+/// Item index file name (inside `stack_dir`, safe to delete).
+pub const INDEX_FILE: &str = ".index.json";
+
 /// Global configuration file name.
 pub const GLOBAL_CONFIG_FILE: &str = ".queuestack";
 
@@ -0,0 +1,108 @@
+//! # CSV
+//!
+//! Minimal RFC 4180 reading and writing for `qs export` and `qs import`:
+//! comma-separated cells, double-quoted when they contain commas, quotes, or
+//! line breaks, with quotes doubled inside.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Result};
+
+/// Writes one row, quoting cells as needed. Rows end with CRLF.
+pub fn write_row(out: &mut String, cells: impl Iterator<Item = String>) {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            let _ = write!(out, "\"{}\"", cell.replace('"', "\"\""));
+        } else {
+            out.push_str(&cell);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+/// Parses CSV text into rows of cells.
+///
+/// Accepts LF and CRLF line endings and skips empty lines. A leading byte
+/// order mark is ignored.
+pub fn parse(text: &str) -> Result<Vec<Vec<String>>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                c => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => quoted = true,
+            ',' => row.push(std::mem::take(&mut cell)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut cell));
+                if row.len() > 1 || !row[0].is_empty() {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            c => cell.push(c),
+        }
+    }
+
+    if quoted {
+        bail!("Unterminated quoted cell in row {}", rows.len() + 1);
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_quoting() {
+        let mut out = String::new();
+        let cells = ["plain", "a, b", "say \"hi\"", "two\nlines"];
+        write_row(&mut out, cells.into_iter().map(String::from));
+        assert_eq!(out, "plain,\"a, b\",\"say \"\"hi\"\"\",\"two\nlines\"\r\n");
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let mut out = String::new();
+        let cells = ["plain", "a, b", "say \"hi\"", "two\r\nlines", ""];
+        write_row(&mut out, cells.iter().map(|c| (*c).to_string()));
+        write_row(&mut out, ["x".to_string()].into_iter());
+        assert_eq!(parse(&out).unwrap(), [cells.to_vec(), vec!["x"]]);
+    }
+
+    #[test]
+    fn test_parse_edge_cases() {
+        assert_eq!(
+            parse("\u{feff}a,b\n\n1,\n").unwrap(),
+            [vec!["a", "b"], vec!["1", ""]]
+        );
+        assert_eq!(parse("last,row").unwrap(), [vec!["last", "row"]]);
+        assert!(parse("\"open").is_err());
+    }
+}
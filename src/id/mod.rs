@@ -16,7 +16,7 @@ pub mod base32;
 
 use std::fmt::Write;
 
-use chrono::{Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use rand::Rng;
 
 /// Default ID pattern: YYMMDD-TTTTRRR
@@ -53,7 +53,13 @@ pub fn extract_from_filename(filename: &str) -> Option<&str> {
 /// # Returns
 /// A `String` containing the generated ID
 pub fn generate(pattern: &str) -> String {
-    let now = Utc::now();
+    generate_at(pattern, Utc::now())
+}
+
+/// Generates an ID as if it were created at the given time.
+///
+/// Used when importing items, so their IDs sort by the original creation date.
+pub fn generate_at(pattern: &str, now: DateTime<Utc>) -> String {
     let mut result = String::with_capacity(pattern.len() + 8);
     let mut chars = pattern.chars().peekable();
 
@@ -124,6 +130,15 @@ mod tests {
         assert_eq!(&id[6..7], "-");
     }
 
+    #[test]
+    fn test_generate_at() {
+        let time = DateTime::parse_from_rfc3339("2024-03-05T00:00:40Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let id = generate_at(DEFAULT_PATTERN, time);
+        assert!(id.starts_with("240305-0018"), "{id}");
+    }
+
     #[test]
     fn test_date_tokens() {
         let id = generate("%y%m%d");
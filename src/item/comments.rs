@@ -0,0 +1,213 @@
+//! # Comments
+//!
+//! Timestamped, author-attributed comments stored in a dedicated section at
+//! the end of the item's Markdown body:
+//!
+//! ```markdown
+//! <!-- qs:comments -->
+//! ## Comments
+//!
+//! ### 2026-01-09T12:34:56Z — Your Name
+//!
+//! Comment text in Markdown.
+//! ```
+//!
+//! Everything before the marker is the item's description. The section stays
+//! plain Markdown, so it reads fine in any editor or viewer.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// Line that starts the comments section.
+pub const COMMENTS_MARKER: &str = "<!-- qs:comments -->";
+
+/// Heading written below the marker.
+const COMMENTS_HEADING: &str = "## Comments";
+
+/// Prefix of a comment entry heading.
+const ENTRY_PREFIX: &str = "### ";
+
+/// Separator between timestamp and author in an entry heading.
+const ENTRY_SEPARATOR: &str = " — ";
+
+/// A single comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    /// Comment text (Markdown)
+    pub text: String,
+}
+
+impl Comment {
+    /// Creates a comment with surrounding whitespace trimmed from the text.
+    pub fn new(author: impl Into<String>, created_at: DateTime<Utc>, text: &str) -> Self {
+        Self {
+            author: author.into(),
+            created_at,
+            text: text.trim().to_string(),
+        }
+    }
+
+    /// Formats the entry heading, e.g. `### 2026-01-09T12:34:56Z — Your Name`.
+    fn heading(&self) -> String {
+        format!(
+            "{ENTRY_PREFIX}{}{ENTRY_SEPARATOR}{}",
+            self.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            self.author
+        )
+    }
+
+    /// Parses an entry heading into timestamp and author.
+    fn parse_heading(line: &str) -> Option<(DateTime<Utc>, String)> {
+        let (timestamp, author) = line
+            .strip_prefix(ENTRY_PREFIX)?
+            .split_once(ENTRY_SEPARATOR)?;
+        let created_at = DateTime::parse_from_rfc3339(timestamp.trim()).ok()?;
+        Some((created_at.with_timezone(&Utc), author.trim().to_string()))
+    }
+}
+
+/// Splits a body into the description and its comments.
+///
+/// Text in the comments section that doesn't belong to an entry is ignored.
+pub fn split(body: &str) -> (&str, Vec<Comment>) {
+    let Some(start) = find_marker(body) else {
+        return (body, Vec::new());
+    };
+
+    let mut comments: Vec<Comment> = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    for line in body[start..].lines().skip(1) {
+        if let Some((created_at, author)) = Comment::parse_heading(line) {
+            flush(&mut comments, &mut text);
+            comments.push(Comment::new(author, created_at, ""));
+        } else if !comments.is_empty() {
+            text.push(line);
+        }
+    }
+    flush(&mut comments, &mut text);
+
+    (body[..start].trim_end(), comments)
+}
+
+/// Returns the body with a comment appended to its comments section.
+///
+/// The section is created at the end of the body if it doesn't exist yet.
+pub fn append(body: &str, comment: &Comment) -> String {
+    let mut result = body.trim_end().to_string();
+    if find_marker(body).is_none() {
+        if !result.is_empty() {
+            result.push_str("\n\n");
+        }
+        result.push_str(COMMENTS_MARKER);
+        result.push('\n');
+        result.push_str(COMMENTS_HEADING);
+    }
+    result.push_str("\n\n");
+    result.push_str(&comment.heading());
+    result.push_str("\n\n");
+    result.push_str(&comment.text);
+    result.push('\n');
+    result
+}
+
+/// Returns the body with its description replaced, keeping the comments section.
+pub fn replace_description(body: &str, description: &str) -> String {
+    let description = description.trim_start_matches(['\n', '\r']).trim_end();
+    let Some(start) = find_marker(body) else {
+        return description.to_string();
+    };
+    if description.is_empty() {
+        body[start..].to_string()
+    } else {
+        format!("{description}\n\n{}", &body[start..])
+    }
+}
+
+/// Returns the byte offset of the marker line.
+fn find_marker(body: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        if line.trim() == COMMENTS_MARKER {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Moves collected text lines into the last comment.
+fn flush(comments: &mut [Comment], text: &mut Vec<&str>) {
+    if let Some(last) = comments.last_mut() {
+        last.text = text.join("\n").trim().to_string();
+    }
+    text.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_append_creates_section() {
+        let comment = Comment::new("Ann", at("2026-01-09T12:34:56Z"), "  Looks good.\n");
+        let body = append("Description.\n", &comment);
+        assert_eq!(
+            body,
+            "Description.\n\n<!-- qs:comments -->\n## Comments\n\n\
+             ### 2026-01-09T12:34:56Z — Ann\n\nLooks good.\n"
+        );
+
+        // Items without a description start with the section
+        assert!(append("", &comment).starts_with(COMMENTS_MARKER));
+    }
+
+    #[test]
+    fn test_split_round_trip() {
+        let first = Comment::new("Ann", at("2026-01-09T12:00:00Z"), "First\n\n- with a list");
+        let second = Comment::new("Bob — QA", at("2026-01-10T08:30:00Z"), "Second");
+        let body = append(&append("# Bug\n\nSteps.\n", &first), &second);
+
+        let (description, comments) = split(&body);
+        assert_eq!(description, "# Bug\n\nSteps.");
+        assert_eq!(comments, vec![first, second]);
+    }
+
+    #[test]
+    fn test_replace_description_keeps_comments() {
+        let comment = Comment::new("Ann", at("2026-01-09T12:00:00Z"), "Noted");
+        let body = append("Old text\n", &comment);
+
+        let body = replace_description(&body, "\nNew text\n\n");
+        let (description, comments) = split(&body);
+        assert_eq!(description, "New text");
+        assert_eq!(comments, vec![comment]);
+
+        assert_eq!(replace_description("Old text\n", "New text\n"), "New text");
+    }
+
+    #[test]
+    fn test_split_without_section() {
+        let (description, comments) = split("Just a body.\n### Not a comment");
+        assert_eq!(description, "Just a body.\n### Not a comment");
+        assert!(comments.is_empty());
+    }
+
+    #[test]
+    fn test_split_ignores_headings_that_are_not_entries() {
+        let body = "Body\n\n<!-- qs:comments -->\n## Comments\n\n\
+                    ### 2026-01-09T12:00:00Z — Ann\n\nSee below\n\n### Details\n\nMore text\n";
+        let (_, comments) = split(body);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].text, "See below\n\n### Details\n\nMore text");
+    }
+}
@@ -0,0 +1,238 @@
+//! # Due Dates
+//!
+//! Parses due date expressions into calendar dates.
+//!
+//! ## Accepted Formats
+//! - Absolute dates: `2026-03-15`
+//! - Keywords: `today`, `tomorrow`
+//! - Relative offsets: `+3d`, `+2w`, `+1m` (days, weeks, months; `-` goes back)
+//! - Weekdays: `friday`, `fri` (next occurrence, never today)
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Result};
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
+
+/// Date format used for due dates in frontmatter and output.
+pub const DUE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Returns today's date in the local timezone.
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Parses a due date expression relative to today.
+pub fn parse_due(input: &str) -> Result<NaiveDate> {
+    parse_due_from(input, today())
+}
+
+/// Parses a due date expression relative to the given date.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use queuestack::item::due::parse_due_from;
+///
+/// let today = NaiveDate::from_ymd_opt(2026, 1, 7).unwrap(); // Wednesday
+/// assert_eq!(parse_due_from("+3d", today).unwrap(), NaiveDate::from_ymd_opt(2026, 1, 10).unwrap());
+/// assert_eq!(parse_due_from("friday", today).unwrap(), NaiveDate::from_ymd_opt(2026, 1, 9).unwrap());
+/// ```
+pub fn parse_due_from(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let expr = input.trim().to_lowercase();
+
+    if expr.is_empty() {
+        bail!("Due date cannot be empty");
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(&expr, DUE_DATE_FORMAT) {
+        return Ok(date);
+    }
+
+    let date = match expr.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.checked_add_days(Days::new(1)),
+        _ => {
+            if let Some(weekday) = parse_weekday(&expr) {
+                Some(next_weekday(today, weekday))
+            } else if expr.starts_with(['+', '-']) {
+                parse_offset(&expr, today)?
+            } else {
+                bail!(
+                    "Invalid due date '{input}' (expected YYYY-MM-DD, today, tomorrow, \
+                     a weekday, or an offset like +3d)"
+                );
+            }
+        }
+    };
+
+    date.ok_or_else(|| anyhow::anyhow!("Due date '{input}' is out of range"))
+}
+
+/// Parses an age such as `30d`, `2w`, or `1m` into the date that long before `today`.
+///
+/// Used by `qs list --stale`; a bare number counts days.
+pub fn parse_age_from(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let expr = input.trim().to_lowercase();
+    let expr = expr.strip_prefix('+').unwrap_or(&expr);
+    let invalid = || anyhow::anyhow!("Invalid age '{input}' (expected e.g. 30d, 2w, 1m)");
+    if expr.is_empty() || expr.starts_with('-') {
+        return Err(invalid());
+    }
+    parse_offset(&format!("-{expr}"), today)
+        .map_err(|_| invalid())?
+        .ok_or_else(|| anyhow::anyhow!("Age '{input}' is out of range"))
+}
+
+/// Formats a due date for display and storage.
+pub fn format_due(date: NaiveDate) -> String {
+    date.format(DUE_DATE_FORMAT).to_string()
+}
+
+/// Parses `+Nd`, `+Nw`, `+Nm` (or `-` variants) into a date.
+fn parse_offset(expr: &str, today: NaiveDate) -> Result<Option<NaiveDate>> {
+    let (sign, rest) = expr.split_at(1);
+    let unit = rest.chars().last().unwrap_or('d');
+    let (amount, unit) = if unit.is_ascii_digit() {
+        (rest, 'd')
+    } else {
+        (&rest[..rest.len() - unit.len_utf8()], unit)
+    };
+
+    let Ok(amount) = amount.parse::<u32>() else {
+        bail!("Invalid due date offset '{expr}' (expected e.g. +3d, +2w, +1m)");
+    };
+
+    let date = match (sign, unit) {
+        ("+", 'd') => today.checked_add_days(Days::new(amount.into())),
+        ("-", 'd') => today.checked_sub_days(Days::new(amount.into())),
+        ("+", 'w') => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+        ("-", 'w') => today.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        ("+", 'm') => today.checked_add_months(Months::new(amount)),
+        ("-", 'm') => today.checked_sub_months(Months::new(amount)),
+        _ => bail!("Invalid due date unit in '{expr}' (expected d, w, or m)"),
+    };
+
+    Ok(date)
+}
+
+/// Parses full or abbreviated English weekday names.
+fn parse_weekday(expr: &str) -> Option<Weekday> {
+    let weekday = match expr {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" | "tues" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" | "thur" | "thurs" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        "sunday" | "sun" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+/// Returns the next date falling on `weekday`, strictly after `today`.
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let current = today.weekday().num_days_from_monday();
+    let target = weekday.num_days_from_monday();
+    let ahead = (target + 7 - current) % 7;
+    let ahead = if ahead == 0 { 7 } else { ahead };
+    today + Days::new(u64::from(ahead))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 2026-01-07 is a Wednesday
+    fn wednesday() -> NaiveDate {
+        date(2026, 1, 7)
+    }
+
+    #[test]
+    fn test_absolute_date() {
+        assert_eq!(
+            parse_due_from("2026-03-15", wednesday()).unwrap(),
+            date(2026, 3, 15)
+        );
+    }
+
+    #[test]
+    fn test_keywords() {
+        assert_eq!(parse_due_from("today", wednesday()).unwrap(), wednesday());
+        assert_eq!(
+            parse_due_from("Tomorrow", wednesday()).unwrap(),
+            date(2026, 1, 8)
+        );
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(
+            parse_due_from("+3d", wednesday()).unwrap(),
+            date(2026, 1, 10)
+        );
+        assert_eq!(
+            parse_due_from("+3", wednesday()).unwrap(),
+            date(2026, 1, 10)
+        );
+        assert_eq!(
+            parse_due_from("+2w", wednesday()).unwrap(),
+            date(2026, 1, 21)
+        );
+        assert_eq!(
+            parse_due_from("+1m", wednesday()).unwrap(),
+            date(2026, 2, 7)
+        );
+        assert_eq!(
+            parse_due_from("-1d", wednesday()).unwrap(),
+            date(2026, 1, 6)
+        );
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(
+            parse_due_from("friday", wednesday()).unwrap(),
+            date(2026, 1, 9)
+        );
+        assert_eq!(
+            parse_due_from("mon", wednesday()).unwrap(),
+            date(2026, 1, 12)
+        );
+        // Same weekday means next week, never today
+        assert_eq!(
+            parse_due_from("wednesday", wednesday()).unwrap(),
+            date(2026, 1, 14)
+        );
+    }
+
+    #[test]
+    fn test_ages() {
+        assert_eq!(
+            parse_age_from("30d", wednesday()).unwrap(),
+            date(2025, 12, 8)
+        );
+        assert_eq!(
+            parse_age_from("1w", wednesday()).unwrap(),
+            date(2025, 12, 31)
+        );
+        assert_eq!(parse_age_from("2", wednesday()).unwrap(), date(2026, 1, 5));
+        assert!(parse_age_from("-3d", wednesday()).is_err());
+        assert!(parse_age_from("", wednesday()).is_err());
+        assert!(parse_age_from("soon", wednesday()).is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse_due_from("", wednesday()).is_err());
+        assert!(parse_due_from("someday", wednesday()).is_err());
+        assert!(parse_due_from("+3y", wednesday()).is_err());
+        assert!(parse_due_from("+d", wednesday()).is_err());
+        assert!(parse_due_from("2026-13-01", wednesday()).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+//! # Custom Fields
+//!
+//! Arbitrary frontmatter keys that queuestack doesn't manage itself
+//! (e.g. `estimate:`, `sprint:`). They are kept verbatim on round-trip and
+//! can be set with `qs update --set` and filtered with `qs list --where`.
+//!
+//! `--where` filters compare numbers numerically and `YYYY-MM-DD` dates
+//! chronologically, so typed fields from the project schema can be filtered
+//! with `<`, `<=`, `>`, and `>=` as well as `=` and `!=`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::cmp::Ordering;
+
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use serde_yml::Value;
+
+use super::due::DUE_DATE_FORMAT;
+
+/// Frontmatter keys managed by queuestack (not settable as custom fields).
+pub const RESERVED_KEYS: &[&str] = &[
+    "id",
+    "title",
+    "author",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "status",
+    "state",
+    "labels",
+    "assignees",
+    "attachments",
+    "priority",
+    "due",
+    "blocks",
+    "blocked_by",
+    "related",
+];
+
+/// Returns true if the key is managed by queuestack.
+pub fn is_reserved(key: &str) -> bool {
+    RESERVED_KEYS.contains(&key)
+}
+
+/// Splits a `key=value` argument into its parts.
+///
+/// The key is trimmed and must not be empty; the value is kept as-is.
+pub fn parse_assignment(input: &str) -> Result<(String, String)> {
+    let Some((key, value)) = input.split_once('=') else {
+        bail!("Invalid field '{input}' (expected key=value)");
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        bail!("Field name cannot be empty in '{input}'");
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Parses a command-line value as a YAML scalar.
+///
+/// Numbers and booleans keep their type (`3`, `true`); anything that isn't a
+/// plain scalar is stored as a string.
+pub fn parse_value(raw: &str) -> Value {
+    match serde_yml::from_str::<Value>(raw) {
+        Ok(value @ (Value::Bool(_) | Value::Number(_) | Value::String(_))) => value,
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+/// Formats a field value for display and comparison.
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Sequence(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string().trim().to_string(),
+    }
+}
+
+/// Checks if a field value matches the expected text (case-insensitive).
+///
+/// Sequences match if any element matches.
+pub fn value_matches(value: &Value, expected: &str) -> bool {
+    match value {
+        Value::Sequence(items) => items.iter().any(|v| value_matches(v, expected)),
+        other => display_value(other).eq_ignore_ascii_case(expected),
+    }
+}
+
+/// Comparison operator of a field filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl FieldOp {
+    /// Returns true if `actual.cmp(expected)` satisfies the operator.
+    pub fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::Ne => ordering != Ordering::Equal,
+            Self::Lt => ordering == Ordering::Less,
+            Self::Le => ordering != Ordering::Greater,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// A `--where` condition such as `sprint=12` or `estimate>=3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldFilter {
+    pub key: String,
+    pub op: FieldOp,
+    pub value: String,
+}
+
+impl FieldFilter {
+    /// Parses `key<op>value` where op is one of `=`, `!=`, `<`, `<=`, `>`, `>=`.
+    pub fn parse(input: &str) -> Result<Self> {
+        let Some(pos) = input.find(['=', '!', '<', '>']) else {
+            bail!("Invalid filter '{input}' (expected key=value, key!=value, key<value, ...)");
+        };
+        let key = input[..pos].trim();
+        if key.is_empty() {
+            bail!("Field name cannot be empty in '{input}'");
+        }
+
+        let rest = &input[pos..];
+        let (op, len) = if rest.starts_with("!=") {
+            (FieldOp::Ne, 2)
+        } else if rest.starts_with("<=") {
+            (FieldOp::Le, 2)
+        } else if rest.starts_with(">=") {
+            (FieldOp::Ge, 2)
+        } else if rest.starts_with('=') {
+            (FieldOp::Eq, 1)
+        } else if rest.starts_with('<') {
+            (FieldOp::Lt, 1)
+        } else if rest.starts_with('>') {
+            (FieldOp::Gt, 1)
+        } else {
+            bail!("Invalid operator in '{input}'");
+        };
+
+        Ok(Self {
+            key: key.to_string(),
+            op,
+            value: rest[len..].trim().to_string(),
+        })
+    }
+
+    /// Checks a field value against the condition.
+    ///
+    /// Missing fields only match `!=`. For list fields, `!=` requires that no
+    /// element matches; every other operator needs one matching element.
+    pub fn matches(&self, value: Option<&Value>) -> bool {
+        match (value, self.op) {
+            (None, op) => op == FieldOp::Ne,
+            (Some(value), FieldOp::Ne) => !value_matches(value, &self.value),
+            (Some(Value::Sequence(items)), _) => items.iter().any(|v| self.matches(Some(v))),
+            (Some(value), FieldOp::Eq) => value_matches(value, &self.value),
+            (Some(value), op) => compare(&display_value(value), &self.value)
+                .is_some_and(|ordering| op.accepts(ordering)),
+        }
+    }
+}
+
+/// Compares two values as numbers, then as dates, then as case-insensitive text.
+fn compare(left: &str, right: &str) -> Option<Ordering> {
+    if let (Ok(l), Ok(r)) = (left.parse::<f64>(), right.parse::<f64>()) {
+        return l.partial_cmp(&r);
+    }
+    if let (Ok(l), Ok(r)) = (
+        NaiveDate::parse_from_str(left, DUE_DATE_FORMAT),
+        NaiveDate::parse_from_str(right, DUE_DATE_FORMAT),
+    ) {
+        return Some(l.cmp(&r));
+    }
+    Some(left.to_lowercase().cmp(&right.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("sprint=12").unwrap(),
+            ("sprint".to_string(), "12".to_string())
+        );
+        assert_eq!(
+            parse_assignment("note=a=b").unwrap(),
+            ("note".to_string(), "a=b".to_string())
+        );
+        assert!(parse_assignment("sprint").is_err());
+        assert!(parse_assignment("=12").is_err());
+    }
+
+    #[test]
+    fn test_parse_value_types() {
+        assert!(matches!(parse_value("3"), Value::Number(_)));
+        assert_eq!(parse_value("true"), Value::Bool(true));
+        assert_eq!(
+            parse_value("Q3 goals"),
+            Value::String("Q3 goals".to_string())
+        );
+        // Non-scalars and empty values are kept as strings
+        assert_eq!(parse_value("a: b"), Value::String("a: b".to_string()));
+        assert_eq!(parse_value(""), Value::String(String::new()));
+    }
+
+    #[test]
+    fn test_value_matches() {
+        assert!(value_matches(&parse_value("3"), "3"));
+        assert!(value_matches(&parse_value("Backend"), "backend"));
+        assert!(value_matches(&Value::Bool(true), "true"));
+        let seq = Value::Sequence(vec![parse_value("a"), parse_value("b")]);
+        assert!(value_matches(&seq, "b"));
+        assert!(!value_matches(&seq, "c"));
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = FieldFilter::parse("estimate>=3").unwrap();
+        assert_eq!(filter.key, "estimate");
+        assert_eq!(filter.op, FieldOp::Ge);
+        assert_eq!(filter.value, "3");
+
+        assert_eq!(FieldFilter::parse("a!=b").unwrap().op, FieldOp::Ne);
+        assert_eq!(FieldFilter::parse("a<b").unwrap().op, FieldOp::Lt);
+        assert_eq!(FieldFilter::parse("note=x=y").unwrap().value, "x=y");
+        assert!(FieldFilter::parse("estimate").is_err());
+        assert!(FieldFilter::parse(">3").is_err());
+    }
+
+    #[test]
+    fn test_filter_comparisons() {
+        let filter = |s: &str| FieldFilter::parse(s).unwrap();
+        let estimate = parse_value("5");
+        assert!(filter("estimate>3").matches(Some(&estimate)));
+        assert!(filter("estimate<=5").matches(Some(&estimate)));
+        assert!(!filter("estimate<10").matches(Some(&parse_value("20"))));
+
+        let review = parse_value("2026-03-15");
+        assert!(filter("review<2026-04-01").matches(Some(&review)));
+        assert!(!filter("review>2026-04-01").matches(Some(&review)));
+
+        // Missing fields only match !=
+        assert!(!filter("estimate>3").matches(None));
+        assert!(filter("estimate!=3").matches(None));
+    }
+
+    #[test]
+    fn test_reserved_keys() {
+        assert!(is_reserved("title"));
+        assert!(!is_reserved("sprint"));
+    }
+}
@@ -0,0 +1,113 @@
+//! # Item Links
+//!
+//! Relationships between items (`blocks`, `blocked_by`, `related`).
+//!
+//! Links store full item IDs rather than paths, so they keep resolving when an
+//! item is renamed, moved to another category, or archived.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::HashSet, fmt, hash::BuildHasher};
+
+use super::Item;
+
+/// Kind of relationship between two items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// This item blocks the target
+    Blocks,
+    /// This item is blocked by the target
+    BlockedBy,
+    /// Generic, symmetric relationship
+    Related,
+}
+
+impl LinkKind {
+    /// Returns the relationship as seen from the target item.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Blocks => Self::BlockedBy,
+            Self::BlockedBy => Self::Blocks,
+            Self::Related => Self::Related,
+        }
+    }
+}
+
+impl fmt::Display for LinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Blocks => write!(f, "blocks"),
+            Self::BlockedBy => write!(f, "is blocked by"),
+            Self::Related => write!(f, "is related to"),
+        }
+    }
+}
+
+/// Returns the IDs of the item's blockers that are still open.
+///
+/// `open_ids` holds the IDs of all open items; blockers that are closed or
+/// no longer exist do not block.
+pub fn open_blockers<'a, S: BuildHasher>(
+    item: &'a Item,
+    open_ids: &HashSet<String, S>,
+) -> Vec<&'a str> {
+    item.blocked_by()
+        .iter()
+        .filter(|id| open_ids.contains(id.as_str()))
+        .map(String::as_str)
+        .collect()
+}
+
+/// Returns true if the item has at least one open blocker.
+pub fn is_blocked<S: BuildHasher>(item: &Item, open_ids: &HashSet<String, S>) -> bool {
+    item.blocked_by()
+        .iter()
+        .any(|id| open_ids.contains(id.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn item_blocked_by(ids: &[&str]) -> Item {
+        Item::new(Frontmatter {
+            id: "260101-AAA".to_string(),
+            title: "Task".to_string(),
+            blocked_by: ids.iter().map(ToString::to_string).collect(),
+            ..Frontmatter::default()
+        })
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(LinkKind::Blocks.inverse(), LinkKind::BlockedBy);
+        assert_eq!(LinkKind::BlockedBy.inverse(), LinkKind::Blocks);
+        assert_eq!(LinkKind::Related.inverse(), LinkKind::Related);
+    }
+
+    #[test]
+    fn test_only_open_blockers_block() {
+        let item = item_blocked_by(&["260102-BBB", "260103-CCC"]);
+        let open: HashSet<String> = std::iter::once("260103-CCC".to_string()).collect();
+
+        assert!(is_blocked(&item, &open));
+        assert_eq!(open_blockers(&item, &open), vec!["260103-CCC"]);
+        assert!(!is_blocked(&item, &HashSet::new()));
+    }
+
+    #[test]
+    fn test_add_and_remove_link() {
+        let mut item = item_blocked_by(&[]);
+
+        assert!(item.add_link(LinkKind::Blocks, "260102-BBB"));
+        assert!(!item.add_link(LinkKind::Blocks, "260102-BBB")); // no duplicates
+        assert_eq!(item.blocks(), ["260102-BBB".to_string()]);
+
+        assert!(item.remove_link(LinkKind::Blocks, "260102-BBB"));
+        assert!(!item.remove_link(LinkKind::Blocks, "260102-BBB"));
+        assert!(item.blocks().is_empty());
+    }
+}
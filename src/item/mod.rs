@@ -5,7 +5,12 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod comments;
+pub mod due;
+pub mod fields;
+pub mod links;
 pub mod parser;
+pub mod rank;
 pub mod search;
 pub mod slug;
 
@@ -15,13 +20,17 @@ use std::{
 };
 
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    comments::Comment,
+    fields::{FieldFilter, FieldOp},
+    links::LinkKind,
     search::{
-        matches_any_label, matches_author_filter, matches_category_filter, matches_filter,
-        matches_query, matches_search_text, FilterCriteria,
+        matches_all_labels, matches_any_label, matches_assignee_filter, matches_author_filter,
+        matches_category_filter, matches_due_filter, matches_field_filter, matches_filter,
+        matches_priority_filter, matches_query, matches_search_text, FilterCriteria, Query, Term,
     },
     slug::slugify,
 };
@@ -66,7 +75,7 @@ impl fmt::Display for Status {
 }
 
 /// YAML frontmatter for an item
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Frontmatter {
     /// Unique sortable ID
     pub id: String,
@@ -80,17 +89,61 @@ pub struct Frontmatter {
     /// Creation timestamp (UTC)
     pub created_at: DateTime<Utc>,
 
+    /// Last modification timestamp (UTC, unset until the first change)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// Closing timestamp (UTC, only set while the item is closed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+
     /// Item status
     #[serde(default)]
     pub status: Status,
 
+    /// Workflow state (only set when the project defines a workflow)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
     /// Metadata labels/tags
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 
+    /// People working on the item
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+
     /// Attached files (relative paths) and URLs
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub attachments: Vec<String>,
+
+    /// Priority level (one of the configured `priorities`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    /// Due date (calendar date, no time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<NaiveDate>,
+
+    /// IDs of items this item blocks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<String>,
+
+    /// IDs of items blocking this item
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+
+    /// IDs of related items
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<String>,
+
+    /// Custom keys not managed by queuestack, preserved on round-trip
+    #[serde(flatten)]
+    pub extra: serde_yml::Mapping,
+
+    /// Top-level key order as read from disk (restored on save)
+    #[serde(skip)]
+    pub key_order: Vec<String>,
 }
 
 /// A complete item with frontmatter and body
@@ -174,16 +227,200 @@ impl Item {
         &self.frontmatter.labels
     }
 
+    /// Returns the assignees
+    pub fn assignees(&self) -> &[String] {
+        &self.frontmatter.assignees
+    }
+
+    /// Returns true if `name` is assigned (case-insensitive).
+    pub fn is_assigned(&self, name: &str) -> bool {
+        self.frontmatter
+            .assignees
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Adds an assignee. Returns false if already assigned or empty.
+    pub fn assign(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.is_assigned(name) {
+            return false;
+        }
+        self.frontmatter.assignees.push(name.to_string());
+        true
+    }
+
+    /// Removes an assignee (case-insensitive). Returns false if not assigned.
+    pub fn unassign(&mut self, name: &str) -> bool {
+        let before = self.frontmatter.assignees.len();
+        self.frontmatter
+            .assignees
+            .retain(|a| !a.eq_ignore_ascii_case(name.trim()));
+        self.frontmatter.assignees.len() != before
+    }
+
     /// Returns the creation timestamp
     pub const fn created_at(&self) -> DateTime<Utc> {
         self.frontmatter.created_at
     }
 
+    /// Returns the last modification timestamp, if the item was ever changed
+    pub const fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter.updated_at
+    }
+
+    /// Returns the time of the last activity (last change, or creation)
+    pub fn last_updated(&self) -> DateTime<Utc> {
+        self.frontmatter
+            .updated_at
+            .unwrap_or(self.frontmatter.created_at)
+    }
+
+    /// Records a modification at the current time
+    pub fn touch(&mut self) {
+        self.frontmatter.updated_at = Some(Utc::now());
+    }
+
+    /// Returns the closing timestamp
+    pub const fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter.closed_at
+    }
+
+    /// Sets the closing timestamp (`None` clears it)
+    pub fn set_closed_at(&mut self, closed_at: Option<DateTime<Utc>>) {
+        self.frontmatter.closed_at = closed_at;
+    }
+
+    /// Returns true if the item hasn't changed since before `cutoff` (local date).
+    ///
+    /// Items that were never updated count from their creation date.
+    pub fn is_stale(&self, cutoff: NaiveDate) -> bool {
+        self.last_updated().with_timezone(&Local).date_naive() <= cutoff
+    }
+
+    /// Returns the priority
+    pub fn priority(&self) -> Option<&str> {
+        self.frontmatter.priority.as_deref()
+    }
+
+    /// Sets the priority (`None` clears it)
+    pub fn set_priority(&mut self, priority: Option<String>) {
+        self.frontmatter.priority = priority;
+    }
+
+    /// Returns the due date
+    pub const fn due(&self) -> Option<NaiveDate> {
+        self.frontmatter.due
+    }
+
+    /// Sets the due date (`None` clears it)
+    pub fn set_due(&mut self, due: Option<NaiveDate>) {
+        self.frontmatter.due = due;
+    }
+
+    /// Returns true if the item is open and its due date is before `today`.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status() == Status::Open && self.due().is_some_and(|due| due < today)
+    }
+
+    /// Returns the IDs of items this item blocks
+    pub fn blocks(&self) -> &[String] {
+        &self.frontmatter.blocks
+    }
+
+    /// Returns the IDs of items blocking this item
+    pub fn blocked_by(&self) -> &[String] {
+        &self.frontmatter.blocked_by
+    }
+
+    /// Returns the IDs of related items
+    pub fn related(&self) -> &[String] {
+        &self.frontmatter.related
+    }
+
+    /// Returns the linked IDs for a relationship kind
+    pub fn links(&self, kind: LinkKind) -> &[String] {
+        match kind {
+            LinkKind::Blocks => self.blocks(),
+            LinkKind::BlockedBy => self.blocked_by(),
+            LinkKind::Related => self.related(),
+        }
+    }
+
+    /// Adds a link to another item's ID. Returns false if already linked.
+    pub fn add_link(&mut self, kind: LinkKind, id: &str) -> bool {
+        let links = self.links_mut(kind);
+        if links.iter().any(|l| l == id) {
+            return false;
+        }
+        links.push(id.to_string());
+        true
+    }
+
+    /// Removes a link to another item's ID. Returns false if not linked.
+    pub fn remove_link(&mut self, kind: LinkKind, id: &str) -> bool {
+        let links = self.links_mut(kind);
+        let before = links.len();
+        links.retain(|l| l != id);
+        links.len() != before
+    }
+
+    fn links_mut(&mut self, kind: LinkKind) -> &mut Vec<String> {
+        match kind {
+            LinkKind::Blocks => &mut self.frontmatter.blocks,
+            LinkKind::BlockedBy => &mut self.frontmatter.blocked_by,
+            LinkKind::Related => &mut self.frontmatter.related,
+        }
+    }
+
+    /// Returns the custom (non-reserved) frontmatter fields
+    pub const fn custom_fields(&self) -> &serde_yml::Mapping {
+        &self.frontmatter.extra
+    }
+
+    /// Returns a frontmatter value by key (custom or built-in)
+    pub fn field(&self, key: &str) -> Option<serde_yml::Value> {
+        if let Some(value) = self.frontmatter.extra.get(key) {
+            return Some(value.clone());
+        }
+        if !fields::is_reserved(key) {
+            return None;
+        }
+        match serde_yml::to_value(&self.frontmatter).ok()? {
+            serde_yml::Value::Mapping(map) => map.get(key).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Sets a custom field. Built-in keys must use their dedicated setters.
+    pub fn set_custom_field(&mut self, key: &str, value: serde_yml::Value) -> Result<()> {
+        if fields::is_reserved(key) {
+            anyhow::bail!("'{key}' is a built-in field; use its dedicated option instead");
+        }
+        self.frontmatter.extra.insert(key, value);
+        Ok(())
+    }
+
+    /// Removes a custom field. Returns false if it wasn't set.
+    pub fn remove_custom_field(&mut self, key: &str) -> bool {
+        self.frontmatter.extra.shift_remove(key).is_some()
+    }
+
     /// Sets the status
     pub fn set_status(&mut self, status: Status) {
         self.frontmatter.status = status;
     }
 
+    /// Returns the workflow state, if any
+    pub fn state(&self) -> Option<&str> {
+        self.frontmatter.state.as_deref()
+    }
+
+    /// Sets or clears the workflow state
+    pub fn set_state(&mut self, state: Option<String>) {
+        self.frontmatter.state = state;
+    }
+
     /// Sets the title
     pub fn set_title(&mut self, title: String) {
         self.frontmatter.title = title;
@@ -202,6 +439,37 @@ impl Item {
         self.frontmatter.labels.retain(|l| l != label);
     }
 
+    /// Returns the body without the comments section.
+    pub fn description(&self) -> &str {
+        comments::split(&self.body).0
+    }
+
+    /// Replaces the description, keeping the comments section.
+    pub fn set_description(&mut self, description: &str) {
+        self.body = comments::replace_description(&self.body, description);
+    }
+
+    /// Appends text to the description as a new paragraph, keeping the comments section.
+    pub fn append_description(&mut self, text: &str) {
+        let current = self.description().trim_end();
+        let description = if current.is_empty() {
+            text.to_string()
+        } else {
+            format!("{current}\n\n{}", text.trim_start_matches(['\n', '\r']))
+        };
+        self.set_description(&description);
+    }
+
+    /// Returns the comments, oldest first.
+    pub fn comments(&self) -> Vec<Comment> {
+        comments::split(&self.body).1
+    }
+
+    /// Appends a comment to the comments section of the body.
+    pub fn add_comment(&mut self, comment: &Comment) {
+        self.body = comments::append(&self.body, comment);
+    }
+
     /// Returns the directory containing this item (and its attachments).
     ///
     /// Returns `None` if the item has no path set.
@@ -272,6 +540,7 @@ mod tests {
             status: Status::Open,
             labels: vec![],
             attachments: vec![],
+            ..Frontmatter::default()
         }
     }
 
@@ -361,6 +630,20 @@ mod tests {
         assert_eq!(item.attachments()[1], "file3.txt");
     }
 
+    #[test]
+    fn test_assign_and_unassign() {
+        let mut item = Item::new(sample_frontmatter("260109-AAA"));
+        assert!(item.assign(" Ann "));
+        assert!(!item.assign("ann"), "Names are compared case-insensitively");
+        assert!(!item.assign("  "));
+        assert!(item.assign("Bob"));
+        assert_eq!(item.assignees(), ["Ann", "Bob"]);
+
+        assert!(item.unassign("ANN"));
+        assert!(!item.unassign("Ann"));
+        assert_eq!(item.assignees(), ["Bob"]);
+    }
+
     #[test]
     fn test_remove_attachment_invalid_index() {
         let fm = sample_frontmatter("260109-AAA");
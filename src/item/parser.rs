@@ -39,12 +39,49 @@ pub fn parse(content: &str) -> Result<(Frontmatter, String)> {
         .trim_start_matches(['\n', '\r'])
         .to_string();
 
-    let frontmatter: Frontmatter =
+    let mut frontmatter: Frontmatter =
         serde_yml::from_str(yaml_content).context("Failed to parse YAML frontmatter")?;
+    frontmatter.key_order = top_level_keys(yaml_content);
 
     Ok((frontmatter, body))
 }
 
+/// Returns the top-level mapping keys of a YAML document, in order.
+///
+/// Only looks at unindented `key:` lines, which is all frontmatter needs.
+fn top_level_keys(yaml: &str) -> Vec<String> {
+    yaml.lines()
+        .filter(|line| !line.starts_with([' ', '\t', '-', '#']))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, _)| key.trim().trim_matches(['\'', '"']).to_string())
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+/// Serializes frontmatter to YAML, restoring the on-disk key order.
+///
+/// Keys that were read from disk keep their position; new keys follow in
+/// declaration order.
+fn frontmatter_to_yaml(frontmatter: &Frontmatter) -> Result<String> {
+    let value = serde_yml::to_value(frontmatter).context("Failed to serialize frontmatter")?;
+
+    let value = match value {
+        serde_yml::Value::Mapping(mut map) if !frontmatter.key_order.is_empty() => {
+            let mut ordered = serde_yml::Mapping::new();
+            for key in &frontmatter.key_order {
+                if let Some(v) = map.shift_remove(key) {
+                    ordered.insert(key.clone(), v);
+                }
+            }
+            ordered.extend(map);
+            serde_yml::Value::Mapping(ordered)
+        }
+        other => other,
+    };
+
+    serde_yml::to_string(&value).context("Failed to serialize frontmatter")
+}
+
 /// Serializes frontmatter and body back to Markdown format.
 ///
 /// # Arguments
@@ -54,12 +91,16 @@ pub fn parse(content: &str) -> Result<(Frontmatter, String)> {
 /// # Returns
 /// The complete file content as a String
 pub fn serialize(frontmatter: &Frontmatter, body: &str) -> Result<String> {
-    let yaml = serde_yml::to_string(frontmatter).context("Failed to serialize frontmatter")?;
+    let yaml = frontmatter_to_yaml(frontmatter)?;
 
     let mut result = String::new();
     result.push_str(FRONTMATTER_DELIMITER);
     result.push('\n');
     result.push_str(&yaml);
+    // A trailing sequence (e.g. `labels`) leaves the YAML without a final newline
+    if !yaml.ends_with('\n') {
+        result.push('\n');
+    }
     result.push_str(FRONTMATTER_DELIMITER);
     result.push_str("\n\n\n"); // Two empty lines after frontmatter
 
@@ -92,6 +133,7 @@ mod tests {
             status: super::super::Status::Open,
             labels: vec!["bug".to_string()],
             attachments: vec![],
+            ..Frontmatter::default()
         }
     }
 
@@ -108,6 +150,49 @@ mod tests {
         assert_eq!(parsed_body.trim(), body.trim());
     }
 
+    #[test]
+    fn test_roundtrip_preserves_unknown_keys_and_order() {
+        let content = "---\n\
+id: 260109-02F7K9M\n\
+estimate: 3\n\
+title: Test Item\n\
+author: Test Author\n\
+created_at: 2026-01-09T12:00:00Z\n\
+sprint: Q1\n\
+status: open\n\
+meta:\n  owner: team-a\n\
+---\n\nBody\n";
+
+        let (mut fm, body) = parse(content).unwrap();
+        assert_eq!(fm.extra.len(), 3);
+        assert_eq!(fm.extra.get("sprint").unwrap().as_str(), Some("Q1"));
+
+        // A new built-in key is appended after the existing ones
+        fm.labels.push("bug".to_string());
+        let serialized = serialize(&fm, &body).unwrap();
+
+        let keys: Vec<&str> = serialized
+            .lines()
+            .filter(|l| !l.starts_with([' ', '-']) && l.contains(':'))
+            .map(|l| l.split(':').next().unwrap())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "id",
+                "estimate",
+                "title",
+                "author",
+                "created_at",
+                "sprint",
+                "status",
+                "meta",
+                "labels"
+            ]
+        );
+        assert!(serialized.contains("  owner: team-a"));
+    }
+
     #[test]
     fn test_parse_empty_body() {
         let fm = sample_frontmatter();
@@ -116,6 +201,15 @@ mod tests {
         assert!(body.is_empty());
     }
 
+    #[test]
+    fn test_serialize_closes_frontmatter_on_own_line() {
+        // serde_yml ends a document with a trailing sequence (`labels`)
+        // without a newline; the closing delimiter must still start a line
+        let serialized = serialize(&sample_frontmatter(), "Body").unwrap();
+        assert!(serialized.contains("labels:\n  - bug\n---\n"));
+        assert!(parse(&serialized).is_ok());
+    }
+
     #[test]
     fn test_missing_frontmatter() {
         let result = parse("No frontmatter here");
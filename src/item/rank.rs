@@ -0,0 +1,510 @@
+//! # Search Ranking
+//!
+//! Matchers for `qs search` and the TUI search box. A [`Matcher`] finds a
+//! pattern as plain text, as a regular expression, or as a fuzzy subsequence,
+//! scores where it hits, and extracts a highlighted snippet from the body.
+//!
+//! Scores are tiered so that any title hit ranks above any ID hit, and any ID
+//! hit above any body hit. Within a tier, closer matches (exact, at a word
+//! start, tightly clustered fuzzy characters, or many body occurrences) score
+//! higher. Hits in several places add up.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::ops::Range;
+
+use anyhow::{Context, Result};
+use regex::{Regex, RegexBuilder};
+
+use super::Item;
+use crate::constants::{UI_SNIPPET_CONTEXT_LINES, UI_SNIPPET_WIDTH};
+
+/// Score tier for a title hit.
+const TITLE_TIER: u32 = 300;
+
+/// Score tier for an ID hit.
+const ID_TIER: u32 = 200;
+
+/// Score tier for a body hit.
+const BODY_TIER: u32 = 100;
+
+/// Highest quality of a single hit (keeps tiers from overlapping).
+const MAX_QUALITY: u32 = 99;
+
+/// Fuzzy matches may spread over at most this many characters per pattern character.
+const FUZZY_MAX_SPREAD: usize = 3;
+
+/// How a search pattern is matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Case-insensitive substring
+    #[default]
+    Plain,
+    /// Case-insensitive regular expression
+    Regex,
+    /// Case-insensitive subsequence (characters in order, gaps allowed)
+    Fuzzy,
+}
+
+/// A compiled search pattern.
+#[derive(Debug, Clone)]
+pub enum Matcher {
+    /// Plain and regex patterns (plain text is escaped)
+    Regex(Regex),
+    /// Lowercased pattern characters
+    Fuzzy(Vec<char>),
+}
+
+/// A match within a single line of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LineMatch {
+    /// Match quality (0 to `MAX_QUALITY`)
+    quality: u32,
+    /// Byte ranges of the matched text
+    ranges: Vec<Range<usize>>,
+}
+
+/// Where and how well an item matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchHit {
+    /// Relevance score (higher is better)
+    pub score: u32,
+    /// Body lines around the best body match
+    pub snippet: Option<Snippet>,
+}
+
+/// Body lines around a match, clipped for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub lines: Vec<SnippetLine>,
+}
+
+/// A single snippet line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetLine {
+    pub text: String,
+    /// Byte ranges in `text` to highlight
+    pub highlights: Vec<Range<usize>>,
+    /// True for the matching line, false for context lines
+    pub is_match: bool,
+}
+
+impl Matcher {
+    /// Compiles a pattern for the given mode.
+    pub fn new(pattern: &str, mode: SearchMode) -> Result<Self> {
+        match mode {
+            SearchMode::Plain => Ok(Self::Regex(build_regex(&regex::escape(pattern))?)),
+            SearchMode::Regex => Ok(Self::Regex(
+                build_regex(pattern).with_context(|| format!("Invalid regex '{pattern}'"))?,
+            )),
+            SearchMode::Fuzzy => Ok(Self::Fuzzy(
+                pattern
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .flat_map(char::to_lowercase)
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Scores an item, returning `None` if the pattern doesn't match.
+    ///
+    /// The body is only searched when `full_text` is true.
+    pub fn score(&self, item: &Item, full_text: bool) -> Option<SearchHit> {
+        let title = self.find(item.title());
+        let id = self.find(item.id());
+        let body = if full_text {
+            self.search_body(&item.body)
+        } else {
+            None
+        };
+        if title.is_none() && id.is_none() && body.is_none() {
+            return None;
+        }
+
+        let score = title.map_or(0, |m| TITLE_TIER + m.quality)
+            + id.map_or(0, |m| ID_TIER + m.quality)
+            + body.as_ref().map_or(0, |(quality, _)| BODY_TIER + quality);
+        Some(SearchHit {
+            score,
+            snippet: body.map(|(_, snippet)| snippet),
+        })
+    }
+
+    /// Finds the best match in a single line.
+    fn find(&self, text: &str) -> Option<LineMatch> {
+        match self {
+            Self::Regex(regex) => {
+                let ranges: Vec<_> = regex
+                    .find_iter(text)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| m.range())
+                    .collect();
+                let first = ranges.first()?;
+                let quality = if first.start == 0 && first.end == text.len() {
+                    MAX_QUALITY
+                } else if is_word_start(text, first.start) {
+                    80
+                } else {
+                    60
+                };
+                Some(LineMatch { quality, ranges })
+            }
+            Self::Fuzzy(pattern) => fuzzy_match(pattern, text),
+        }
+    }
+
+    /// Searches the body line by line.
+    ///
+    /// Returns the body quality (more matching lines or closer fuzzy matches
+    /// score higher) and a snippet around the best line.
+    fn search_body(&self, body: &str) -> Option<(u32, Snippet)> {
+        let lines: Vec<&str> = body.lines().collect();
+        let mut best: Option<(usize, LineMatch)> = None;
+        let mut occurrences = 0;
+
+        for (index, line) in lines.iter().enumerate() {
+            let Some(m) = self.find(line) else {
+                continue;
+            };
+            occurrences += m.ranges.len();
+            if best.as_ref().map_or(true, |(_, b)| m.quality > b.quality) {
+                best = Some((index, m));
+            }
+        }
+
+        let (index, best) = best?;
+        let quality = match self {
+            // Plain and regex: more occurrences rank higher
+            Self::Regex(_) => u32::try_from(occurrences.min(9)).unwrap_or(9) * 10 + 9,
+            Self::Fuzzy(_) => best.quality,
+        };
+        Some((
+            quality.min(MAX_QUALITY),
+            build_snippet(&lines, index, &best.ranges),
+        ))
+    }
+}
+
+impl Snippet {
+    /// Returns the matching line.
+    pub fn match_line(&self) -> Option<&SnippetLine> {
+        self.lines.iter().find(|line| line.is_match)
+    }
+}
+
+impl SnippetLine {
+    /// Splits the text into `(segment, highlighted)` parts for rendering.
+    pub fn segments(&self) -> Vec<(&str, bool)> {
+        let mut segments = Vec::new();
+        let mut pos = 0;
+        for range in &self.highlights {
+            if range.start > pos {
+                segments.push((&self.text[pos..range.start], false));
+            }
+            segments.push((&self.text[range.clone()], true));
+            pos = range.end;
+        }
+        if pos < self.text.len() {
+            segments.push((&self.text[pos..], false));
+        }
+        segments
+    }
+}
+
+/// Builds a case-insensitive regex.
+fn build_regex(pattern: &str) -> Result<Regex> {
+    Ok(RegexBuilder::new(pattern).case_insensitive(true).build()?)
+}
+
+/// Returns true if a word starts at the byte offset.
+fn is_word_start(text: &str, offset: usize) -> bool {
+    text[..offset]
+        .chars()
+        .next_back()
+        .map_or(true, |c| !c.is_alphanumeric())
+}
+
+/// Finds the pattern characters in order, trying every start position and
+/// keeping the closest match.
+///
+/// Each matched character scores 1, plus 2 if it directly follows the previous
+/// match and 2 if it starts a word; the total is scaled down by how far the
+/// match spreads beyond the pattern length.
+fn fuzzy_match(pattern: &[char], text: &str) -> Option<LineMatch> {
+    let first = *pattern.first()?;
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let word_start = |i: usize| i == 0 || !chars[i - 1].1.is_alphanumeric();
+
+    let mut best: Option<LineMatch> = None;
+    for start in (0..chars.len()).filter(|&i| lower(chars[i].1) == first) {
+        let mut positions = vec![start];
+        let mut next = start + 1;
+        for &wanted in &pattern[1..] {
+            let Some(found) = (next..chars.len()).find(|&i| lower(chars[i].1) == wanted) else {
+                break;
+            };
+            positions.push(found);
+            next = found + 1;
+        }
+        if positions.len() < pattern.len() {
+            // Later starts can't find the rest either
+            break;
+        }
+
+        let spread = positions[positions.len() - 1] - start + 1;
+        if spread > pattern.len() * FUZZY_MAX_SPREAD {
+            continue;
+        }
+
+        let mut points = 0;
+        for (n, &pos) in positions.iter().enumerate() {
+            points += 1;
+            if n > 0 && pos == positions[n - 1] + 1 {
+                points += 2;
+            }
+            if word_start(pos) {
+                points += 2;
+            }
+        }
+        // First char can't follow a previous match
+        let max_points = pattern.len() * 5 - 2;
+        let percent = points * pattern.len() * 100 / (max_points * spread);
+        let quality = u32::try_from(percent).map_or(MAX_QUALITY, |q| q.min(MAX_QUALITY));
+
+        if best.as_ref().map_or(true, |b| quality > b.quality) {
+            let ranges = merge_ranges(
+                positions
+                    .iter()
+                    .map(|&i| chars[i].0..chars[i].0 + chars[i].1.len_utf8()),
+            );
+            best = Some(LineMatch { quality, ranges });
+        }
+    }
+    best
+}
+
+/// Merges adjacent byte ranges.
+fn merge_ranges(ranges: impl Iterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Builds a snippet of the matching line and its non-blank neighbours.
+fn build_snippet(lines: &[&str], index: usize, ranges: &[Range<usize>]) -> Snippet {
+    let first = index.saturating_sub(UI_SNIPPET_CONTEXT_LINES);
+    let last = (index + UI_SNIPPET_CONTEXT_LINES).min(lines.len() - 1);
+
+    let lines = (first..=last)
+        .filter(|&i| i == index || !lines[i].trim().is_empty())
+        .map(|i| {
+            let highlights = if i == index { ranges } else { &[] };
+            clip_line(lines[i], highlights, i == index)
+        })
+        .collect();
+    Snippet { lines }
+}
+
+/// Trims a line and clips it to `UI_SNIPPET_WIDTH` characters around the
+/// first highlight, adding ellipses where text was cut.
+fn clip_line(line: &str, highlights: &[Range<usize>], is_match: bool) -> SnippetLine {
+    let offset = line.len() - line.trim_start().len();
+    let chars: Vec<(char, bool)> = line
+        .char_indices()
+        .skip_while(|&(i, _)| i < offset)
+        .map(|(i, c)| (c, highlights.iter().any(|r| r.contains(&i))))
+        .collect();
+    let end = chars
+        .iter()
+        .rposition(|(c, _)| !c.is_whitespace())
+        .map_or(0, |i| i + 1);
+
+    let first_hit = chars.iter().position(|&(_, hit)| hit).unwrap_or(0);
+    let start = if end > UI_SNIPPET_WIDTH {
+        first_hit
+            .saturating_sub(UI_SNIPPET_WIDTH / 4)
+            .min(end - UI_SNIPPET_WIDTH)
+    } else {
+        0
+    };
+    let stop = end.min(start + UI_SNIPPET_WIDTH);
+
+    let mut text = String::new();
+    let mut ranges = Vec::new();
+    if start > 0 {
+        text.push('…');
+    }
+    for &(c, hit) in &chars[start..stop] {
+        let pos = text.len();
+        text.push(c);
+        if hit {
+            ranges.push(pos..text.len());
+        }
+    }
+    if stop < end {
+        text.push('…');
+    }
+
+    SnippetLine {
+        text,
+        highlights: merge_ranges(ranges.into_iter()),
+        is_match,
+    }
+}
+
+/// Scores an item against several patterns (e.g. the words of a query).
+///
+/// Scores add up; the snippet comes from the first pattern with a body hit.
+pub fn score_all(matchers: &[Matcher], item: &Item, full_text: bool) -> SearchHit {
+    let mut total = SearchHit::default();
+    for hit in matchers.iter().filter_map(|m| m.score(item, full_text)) {
+        total.score += hit.score;
+        if total.snippet.is_none() {
+            total.snippet = hit.snippet;
+        }
+    }
+    total
+}
+
+/// Ranks items by relevance, highest first.
+///
+/// Items with equal scores keep their current order.
+pub fn sort_by_score<T>(items: &mut [(T, SearchHit)]) {
+    items.sort_by_key(|(_, hit)| std::cmp::Reverse(hit.score));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn sample_item(title: &str, body: &str) -> Item {
+        let mut item = Item::new(Frontmatter {
+            id: "260109-02F7K9M".to_string(),
+            title: title.to_string(),
+            ..Frontmatter::default()
+        });
+        item.body = body.to_string();
+        item
+    }
+
+    fn score(pattern: &str, mode: SearchMode, item: &Item) -> u32 {
+        Matcher::new(pattern, mode)
+            .unwrap()
+            .score(item, true)
+            .map_or(0, |hit| hit.score)
+    }
+
+    #[test]
+    fn test_title_hits_outrank_body_hits() {
+        let title = sample_item("Login crash", "");
+        let body = sample_item(
+            "Other",
+            "login login login login login login login login login login",
+        );
+
+        for mode in [SearchMode::Plain, SearchMode::Regex, SearchMode::Fuzzy] {
+            assert!(score("login", mode, &title) > score("login", mode, &body));
+            assert!(score("login", mode, &body) > 0);
+        }
+    }
+
+    #[test]
+    fn test_plain_quality() {
+        let exact = sample_item("login", "");
+        let word = sample_item("Fix login", "");
+        let inner = sample_item("Relogin fails", "");
+
+        let plain = |item| score("LOGIN", SearchMode::Plain, item);
+        assert!(plain(&exact) > plain(&word));
+        assert!(plain(&word) > plain(&inner));
+        // Regex metacharacters are literal in plain mode
+        assert_eq!(score("a.c", SearchMode::Plain, &sample_item("abc", "")), 0);
+    }
+
+    #[test]
+    fn test_regex_mode() {
+        let item = sample_item("Crash in v2.3", "");
+        assert!(score(r"v\d+\.\d+", SearchMode::Regex, &item) > 0);
+        assert_eq!(score(r"^v\d", SearchMode::Regex, &item), 0);
+        assert!(Matcher::new("(unclosed", SearchMode::Regex).is_err());
+    }
+
+    #[test]
+    fn test_fuzzy_closer_matches_rank_higher() {
+        let tight = sample_item("Login button", "");
+        let loose = sample_item("Long lines in agenda", "");
+        let none = sample_item("Nothing here", "");
+
+        let fuzzy = |item| score("lgn", SearchMode::Fuzzy, item);
+        assert!(
+            fuzzy(&tight) > fuzzy(&loose),
+            "{} vs {}",
+            fuzzy(&tight),
+            fuzzy(&loose)
+        );
+        assert_eq!(fuzzy(&none), 0);
+        assert!(score("lgnbtn", SearchMode::Fuzzy, &tight) > 0);
+        // Characters spread too far apart don't match
+        assert_eq!(
+            score("ls", SearchMode::Fuzzy, &sample_item("La bamba tres", "")),
+            0
+        );
+    }
+
+    #[test]
+    fn test_body_snippet() {
+        let item = sample_item(
+            "Title",
+            "Intro\n\n  The app crashes on submit\nafter login\n",
+        );
+        let hit = Matcher::new("crash", SearchMode::Plain)
+            .unwrap()
+            .score(&item, true)
+            .unwrap();
+        let snippet = hit.snippet.unwrap();
+
+        // Blank neighbours are skipped
+        assert_eq!(snippet.lines.len(), 2);
+        let line = snippet.match_line().unwrap();
+        assert_eq!(line.text, "The app crashes on submit");
+        assert_eq!(
+            line.segments(),
+            [
+                ("The app ", false),
+                ("crash", true),
+                ("es on submit", false)
+            ]
+        );
+        assert!(!snippet.lines[1].is_match);
+
+        // No body search without full text
+        let hit = Matcher::new("crash", SearchMode::Plain)
+            .unwrap()
+            .score(&item, false);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_long_lines_are_clipped_around_the_match() {
+        let body = format!("{}needle{}", "x ".repeat(100), " y".repeat(100));
+        let item = sample_item("Title", &body);
+        let hit = Matcher::new("needle", SearchMode::Plain)
+            .unwrap()
+            .score(&item, true)
+            .unwrap();
+        let line = hit.snippet.unwrap().lines.remove(0);
+
+        assert!(line.text.starts_with('…') && line.text.ends_with('…'));
+        assert!(line.text.chars().count() <= UI_SNIPPET_WIDTH + 2);
+        assert_eq!(&line.text[line.highlights[0].clone()], "needle");
+    }
+}
@@ -3,10 +3,29 @@
 //! Item search and filtering logic. This module provides the single source of truth
 //! for all item filtering operations, used by both CLI commands and TUI.
 //!
+//! ## Query Language
+//!
+//! `qs list --query`, `qs search`, and the TUI search box accept expressions like
+//! `label:bug AND NOT label:wontfix AND (category:backend OR author:alice) AND created>2026-01-01`.
+//!
+//! - Terms: bare words and `"quoted phrases"` match title, ID, or body;
+//!   `key:value` and `key<op>value` (`=`, `!=`, `<`, `<=`, `>`, `>=`) match fields
+//! - Operators: `NOT` binds tightest, then `AND`, then `OR`; adjacent terms are AND-ed
+//! - Parentheses group sub-expressions
+//!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use super::Item;
+use std::fmt;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+use super::{
+    due,
+    fields::{FieldFilter, FieldOp},
+    Item,
+};
 
 // =============================================================================
 // Filter Criteria
@@ -20,12 +39,28 @@ use super::Item;
 pub struct FilterCriteria {
     /// Text search query (matches title, ID, and optionally body).
     pub search: String,
-    /// Labels to filter by (OR logic - item must have ANY of these).
+    /// Labels to filter by (AND logic - item must have ALL of these).
     pub labels: Vec<String>,
     /// Category to filter by (exact match, case-insensitive).
     pub category: Option<String>,
     /// Author to filter by (substring match, case-insensitive).
     pub author: Option<String>,
+    /// Assignees to filter by (OR logic - item must be assigned to ANY of these).
+    pub assignees: Vec<String>,
+    /// Priorities to filter by (OR logic - item must have ANY of these).
+    pub priorities: Vec<String>,
+    /// Only items due strictly before this date.
+    pub due_before: Option<NaiveDate>,
+    /// Only items due strictly after this date.
+    pub due_after: Option<NaiveDate>,
+    /// Only open items whose due date has passed.
+    pub overdue: bool,
+    /// Only items not updated after this date (see `Item::is_stale`).
+    pub stale_since: Option<NaiveDate>,
+    /// Frontmatter field conditions (AND logic).
+    pub fields: Vec<FieldFilter>,
+    /// Query language expression (see module docs).
+    pub query: Option<Query>,
 }
 
 impl FilterCriteria {
@@ -40,6 +75,14 @@ impl FilterCriteria {
             && self.labels.is_empty()
             && self.category.is_none()
             && self.author.is_none()
+            && self.assignees.is_empty()
+            && self.priorities.is_empty()
+            && self.due_before.is_none()
+            && self.due_after.is_none()
+            && !self.overdue
+            && self.stale_since.is_none()
+            && self.fields.is_empty()
+            && self.query.is_none()
     }
 }
 
@@ -63,7 +106,7 @@ pub fn matches_filter(item: &Item, criteria: &FilterCriteria, item_category: Opt
         return false;
     }
 
-    // Label filter (OR logic - item must have ANY of the specified labels)
+    // Label filter (AND logic - item must have ALL of the specified labels)
     if !criteria.labels.is_empty() && !matches_labels(item, &criteria.labels) {
         return false;
     }
@@ -82,6 +125,55 @@ pub fn matches_filter(item: &Item, criteria: &FilterCriteria, item_category: Opt
         }
     }
 
+    // Assignee filter (OR logic - item must be assigned to ANY of the specified people)
+    if !criteria.assignees.is_empty()
+        && !matches_assignee_filter(item.assignees(), &criteria.assignees)
+    {
+        return false;
+    }
+
+    // Priority filter (OR logic - item must have ANY of the specified priorities)
+    if !criteria.priorities.is_empty()
+        && !matches_priority_filter(item.priority(), &criteria.priorities)
+    {
+        return false;
+    }
+
+    // Due date range filter (items without a due date never match)
+    if (criteria.due_before.is_some() || criteria.due_after.is_some())
+        && !matches_due_filter(item.due(), criteria.due_before, criteria.due_after)
+    {
+        return false;
+    }
+
+    // Overdue filter
+    if criteria.overdue && !item.is_overdue(due::today()) {
+        return false;
+    }
+
+    // Stale filter (last update, or creation, on or before the cutoff)
+    if let Some(cutoff) = criteria.stale_since {
+        if !item.is_stale(cutoff) {
+            return false;
+        }
+    }
+
+    // Field filters (AND logic - every key must match)
+    if !criteria
+        .fields
+        .iter()
+        .all(|filter| matches_field_filter(item, filter))
+    {
+        return false;
+    }
+
+    // Query expression
+    if let Some(ref query) = criteria.query {
+        if !query.matches(item, item_category, true) {
+            return false;
+        }
+    }
+
     true
 }
 
@@ -107,6 +199,13 @@ pub fn matches_any_label(item_labels: &[String], filter_labels: &[String]) -> bo
         .any(|filter| item_labels.iter().any(|l| l.eq_ignore_ascii_case(filter)))
 }
 
+/// Checks if item has ALL of the specified labels (AND logic, case-insensitive).
+pub fn matches_all_labels(item_labels: &[String], filter_labels: &[String]) -> bool {
+    filter_labels
+        .iter()
+        .all(|filter| item_labels.iter().any(|l| l.eq_ignore_ascii_case(filter)))
+}
+
 /// Checks if item's category matches the filter (case-insensitive).
 ///
 /// Handles "uncategorized" as a special case for items with no category.
@@ -124,9 +223,50 @@ pub fn matches_author_filter(item_author: &str, filter_author: &str) -> bool {
         .contains(&filter_author.to_lowercase())
 }
 
+/// Checks if item is assigned to ANY of the specified people (case-insensitive).
+///
+/// Unassigned items only match the special value "none".
+pub fn matches_assignee_filter(item_assignees: &[String], filter_assignees: &[String]) -> bool {
+    filter_assignees.iter().any(|filter| {
+        if item_assignees.is_empty() {
+            filter.eq_ignore_ascii_case("none")
+        } else {
+            item_assignees
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(filter))
+        }
+    })
+}
+
+/// Checks if item's priority is ANY of the specified priorities (case-insensitive).
+///
+/// Items without a priority only match the special value "none".
+pub fn matches_priority_filter(item_priority: Option<&str>, filter_priorities: &[String]) -> bool {
+    let item_priority = item_priority.unwrap_or("none");
+    filter_priorities
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(item_priority))
+}
+
+/// Checks if a due date lies strictly between the given bounds.
+///
+/// Items without a due date never match.
+pub fn matches_due_filter(
+    item_due: Option<NaiveDate>,
+    before: Option<NaiveDate>,
+    after: Option<NaiveDate>,
+) -> bool {
+    item_due.is_some_and(|due| before.map_or(true, |b| due < b) && after.map_or(true, |a| due > a))
+}
+
+/// Checks if a frontmatter field (custom or built-in) satisfies a condition.
+pub fn matches_field_filter(item: &Item, filter: &FieldFilter) -> bool {
+    filter.matches(item.field(&filter.key).as_ref())
+}
+
 // Internal wrappers for Item
 fn matches_labels(item: &Item, labels: &[String]) -> bool {
-    matches_any_label(item.labels(), labels)
+    matches_all_labels(item.labels(), labels)
 }
 
 fn matches_category(item_category: Option<&str>, filter_category: &str) -> bool {
@@ -169,12 +309,373 @@ pub fn matches_query(item: &Item, query: &str, full_text: bool) -> bool {
     false
 }
 
+// =============================================================================
+// Query Language
+// =============================================================================
+
+/// A parsed query expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// A single condition
+    Term(Term),
+    /// Negated sub-expression
+    Not(Box<Self>),
+    /// All sub-expressions must match
+    And(Vec<Self>),
+    /// Any sub-expression must match
+    Or(Vec<Self>),
+}
+
+/// A single query condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    /// Substring of title or ID (and body for full-text matching)
+    Text(String),
+    /// Substring of the title
+    Title(String),
+    /// Substring of the body
+    Body(String),
+    /// Substring of the ID
+    Id(String),
+    /// Has this label (case-insensitive)
+    Label(String),
+    /// In this category (`uncategorized` for none)
+    Category(String),
+    /// Author contains this text (case-insensitive)
+    Author(String),
+    /// Assigned to this person (`none` for unassigned)
+    Assignee(String),
+    /// Creation date comparison (local date)
+    Created(FieldOp, NaiveDate),
+    /// Last update date comparison (local date, creation if never updated)
+    Updated(FieldOp, NaiveDate),
+    /// Any other frontmatter field (custom or built-in)
+    Field(FieldFilter),
+}
+
+impl Query {
+    /// Parses a query expression.
+    ///
+    /// # Example
+    /// ```
+    /// use queuestack::item::{Query, Term};
+    ///
+    /// let query = Query::parse("label:bug AND NOT label:wontfix").unwrap();
+    /// assert_eq!(
+    ///     query,
+    ///     Query::And(vec![
+    ///         Query::Term(Term::Label("bug".to_string())),
+    ///         Query::Not(Box::new(Query::Term(Term::Label("wontfix".to_string())))),
+    ///     ])
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            bail!("Query cannot be empty");
+        }
+        let mut parser = Parser { tokens, pos: 0 };
+        let query = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            bail!("Unexpected {token} in query '{input}'");
+        }
+        Ok(query)
+    }
+
+    /// Checks if an item matches the query.
+    ///
+    /// Plain text terms search title and ID, plus the body when `full_text` is true.
+    pub fn matches(&self, item: &Item, item_category: Option<&str>, full_text: bool) -> bool {
+        match self {
+            Self::Term(term) => term.matches(item, item_category, full_text),
+            Self::Not(inner) => !inner.matches(item, item_category, full_text),
+            Self::And(parts) => parts
+                .iter()
+                .all(|q| q.matches(item, item_category, full_text)),
+            Self::Or(parts) => parts
+                .iter()
+                .any(|q| q.matches(item, item_category, full_text)),
+        }
+    }
+
+    /// Returns the plain text terms that are not negated (used for ranking).
+    pub fn text_terms(&self) -> Vec<&str> {
+        match self {
+            Self::Term(Term::Text(text)) => vec![text.as_str()],
+            Self::Term(_) | Self::Not(_) => Vec::new(),
+            Self::And(parts) | Self::Or(parts) => parts.iter().flat_map(Self::text_terms).collect(),
+        }
+    }
+
+    /// Returns the words every matching item must contain in its title, ID,
+    /// or body (used to skip files via the item index).
+    pub fn required_words(&self) -> Vec<&str> {
+        match self {
+            Self::Term(
+                Term::Text(text) | Term::Title(text) | Term::Body(text) | Term::Id(text),
+            ) => {
+                vec![text.as_str()]
+            }
+            Self::And(parts) => parts.iter().flat_map(Self::required_words).collect(),
+            Self::Term(_) | Self::Not(_) | Self::Or(_) => Vec::new(),
+        }
+    }
+}
+
+impl Term {
+    /// Builds a term from a `key:value` or `key<op>value` word.
+    fn from_condition(filter: FieldFilter) -> Result<Query> {
+        let FieldFilter { key, op, value } = filter;
+        if value.is_empty() {
+            bail!("Missing value for '{key}' in query");
+        }
+
+        let term = match key.to_lowercase().as_str() {
+            "created" | "created_at" => Self::Created(op, parse_query_date(&key, &value)?),
+            "updated" | "updated_at" => Self::Updated(op, parse_query_date(&key, &value)?),
+            text_key => {
+                let term = match text_key {
+                    "text" => Self::Text(value),
+                    "title" => Self::Title(value),
+                    "body" => Self::Body(value),
+                    "id" => Self::Id(value),
+                    "label" | "labels" => Self::Label(value),
+                    "category" | "cat" => Self::Category(value),
+                    "author" => Self::Author(value),
+                    "assignee" | "assignees" => Self::Assignee(value),
+                    _ => return Ok(Query::Term(Self::Field(FieldFilter { key, op, value }))),
+                };
+                return match op {
+                    FieldOp::Eq => Ok(Query::Term(term)),
+                    FieldOp::Ne => Ok(Query::Not(Box::new(Query::Term(term)))),
+                    _ => bail!("'{key}' only supports ':', '=', and '!=' in queries"),
+                };
+            }
+        };
+        Ok(Query::Term(term))
+    }
+
+    /// Checks if an item satisfies the condition.
+    fn matches(&self, item: &Item, item_category: Option<&str>, full_text: bool) -> bool {
+        let contains =
+            |haystack: &str, needle: &str| haystack.to_lowercase().contains(&needle.to_lowercase());
+        match self {
+            Self::Text(text) => {
+                contains(item.title(), text)
+                    || contains(item.id(), text)
+                    || (full_text && contains(&item.body, text))
+            }
+            Self::Title(text) => contains(item.title(), text),
+            Self::Body(text) => contains(&item.body, text),
+            Self::Id(text) => contains(item.id(), text),
+            Self::Label(label) => matches_any_label(item.labels(), std::slice::from_ref(label)),
+            Self::Category(category) => matches_category_filter(item_category, category),
+            Self::Author(author) => matches_author_filter(item.author(), author),
+            Self::Assignee(name) => {
+                matches_assignee_filter(item.assignees(), std::slice::from_ref(name))
+            }
+            Self::Created(op, date) => op.accepts(local_date(item.created_at()).cmp(date)),
+            Self::Updated(op, date) => op.accepts(local_date(item.last_updated()).cmp(date)),
+            Self::Field(filter) => matches_field_filter(item, filter),
+        }
+    }
+}
+
+/// Parses a date in a query (absolute or relative, like due dates).
+fn parse_query_date(key: &str, value: &str) -> Result<NaiveDate> {
+    due::parse_due(value).map_err(|_| {
+        anyhow::anyhow!("Invalid date '{value}' for '{key}' (expected YYYY-MM-DD or e.g. -7d)")
+    })
+}
+
+/// Converts a timestamp to a calendar date in the local timezone.
+fn local_date(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&Local).date_naive()
+}
+
+/// A lexical token of the query language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    /// A word with quotes removed; `quoted` is true for `"whole phrases"`
+    Word {
+        text: String,
+        quoted: bool,
+    },
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LParen => write!(f, "'('"),
+            Self::RParen => write!(f, "')'"),
+            Self::And => write!(f, "'AND'"),
+            Self::Or => write!(f, "'OR'"),
+            Self::Not => write!(f, "'NOT'"),
+            Self::Word { text, .. } => write!(f, "'{text}'"),
+        }
+    }
+}
+
+/// Splits a query into tokens. Keywords are only recognized in upper case.
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c == '(' || c == ')' {
+            chars.next();
+            tokens.push(if c == '(' {
+                Token::LParen
+            } else {
+                Token::RParen
+            });
+            continue;
+        }
+
+        let quoted = c == '"';
+        let mut text = String::new();
+        let mut in_quotes = false;
+        while let Some(&c) = chars.peek() {
+            if !in_quotes && (c.is_whitespace() || c == '(' || c == ')') {
+                break;
+            }
+            chars.next();
+            if c == '"' {
+                in_quotes = !in_quotes;
+            } else {
+                text.push(c);
+            }
+        }
+        if in_quotes {
+            bail!("Unterminated quote in query '{input}'");
+        }
+
+        tokens.push(match text.as_str() {
+            "AND" if !quoted => Token::And,
+            "OR" if !quoted => Token::Or,
+            "NOT" if !quoted => Token::Not,
+            _ => Token::Word { text, quoted },
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser over query tokens.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// `or := and (OR and)*`
+    fn parse_or(&mut self) -> Result<Query> {
+        let mut parts = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            parts.push(self.parse_and()?);
+        }
+        Ok(flatten(parts, Query::Or))
+    }
+
+    /// `and := unary ((AND)? unary)*` (adjacent terms are AND-ed)
+    fn parse_and(&mut self) -> Result<Query> {
+        let mut parts = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.next();
+                }
+                Some(Token::Word { .. } | Token::Not | Token::LParen) => {}
+                _ => break,
+            }
+            parts.push(self.parse_unary()?);
+        }
+        Ok(flatten(parts, Query::And))
+    }
+
+    /// `unary := NOT unary | '(' or ')' | word`
+    fn parse_unary(&mut self) -> Result<Query> {
+        match self.next() {
+            Some(Token::Not) => Ok(Query::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => bail!("Missing ')' in query"),
+                }
+            }
+            Some(Token::Word { text, quoted }) => parse_word(text, quoted),
+            Some(token) => bail!("Unexpected {token} in query"),
+            None => bail!("Unexpected end of query"),
+        }
+    }
+}
+
+/// Wraps several parts with `combine`, or returns a single part unchanged.
+fn flatten(mut parts: Vec<Query>, combine: fn(Vec<Query>) -> Query) -> Query {
+    if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        combine(parts)
+    }
+}
+
+/// Turns a word into a term: a field condition if it starts with `key:` or
+/// `key<op>`, plain text otherwise. URLs (`https://...`) are plain text.
+fn parse_word(text: String, quoted: bool) -> Result<Query> {
+    if !quoted && !text.contains("://") {
+        if let Some(pos) = text.find([':', '=', '!', '<', '>']) {
+            let key = &text[..pos];
+            let is_key = key
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            if is_key {
+                let filter = if text[pos..].starts_with(':') {
+                    FieldFilter {
+                        key: key.to_string(),
+                        op: FieldOp::Eq,
+                        value: text[pos + 1..].to_string(),
+                    }
+                } else {
+                    FieldFilter::parse(&text)?
+                };
+                return Term::from_condition(filter);
+            }
+        }
+    }
+    Ok(Query::Term(Term::Text(text)))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
 
     use super::*;
-    use crate::item::{Frontmatter, Status};
+    use crate::item::{fields, Frontmatter, Status};
 
     fn sample_item(title: &str, body: &str) -> Item {
         let frontmatter = Frontmatter {
@@ -185,6 +686,7 @@ mod tests {
             status: Status::Open,
             labels: vec![],
             attachments: vec![],
+            ..Frontmatter::default()
         };
         let mut item = Item::new(frontmatter);
         item.body = body.to_string();
@@ -221,10 +723,254 @@ mod tests {
         assert!(matches_query(&item, "production", true));
     }
 
+    #[test]
+    fn test_priority_filter() {
+        let mut item = sample_item("Task", "");
+        let high = FilterCriteria {
+            priorities: vec!["HIGH".to_string(), "critical".to_string()],
+            ..FilterCriteria::default()
+        };
+        let none = FilterCriteria {
+            priorities: vec!["none".to_string()],
+            ..FilterCriteria::default()
+        };
+
+        assert!(!matches_filter(&item, &high, None));
+        assert!(matches_filter(&item, &none, None));
+
+        item.set_priority(Some("high".to_string()));
+        assert!(matches_filter(&item, &high, None));
+        assert!(!matches_filter(&item, &none, None));
+    }
+
+    #[test]
+    fn test_assignee_filter() {
+        let mut item = sample_item("Task", "");
+        let ann = FilterCriteria {
+            assignees: vec!["ANN".to_string(), "bob".to_string()],
+            ..FilterCriteria::default()
+        };
+        let none = FilterCriteria {
+            assignees: vec!["none".to_string()],
+            ..FilterCriteria::default()
+        };
+
+        assert!(!matches_filter(&item, &ann, None));
+        assert!(matches_filter(&item, &none, None));
+
+        item.assign("Ann");
+        assert!(matches_filter(&item, &ann, None));
+        assert!(!matches_filter(&item, &none, None));
+    }
+
+    #[test]
+    fn test_due_filters() {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let mut item = sample_item("Task", "");
+        let range = FilterCriteria {
+            due_after: Some(date(5)),
+            due_before: Some(date(10)),
+            ..FilterCriteria::default()
+        };
+        let overdue = FilterCriteria {
+            overdue: true,
+            ..FilterCriteria::default()
+        };
+
+        // No due date never matches a due filter
+        assert!(!matches_filter(&item, &range, None));
+        assert!(!matches_filter(&item, &overdue, None));
+
+        item.set_due(Some(date(7)));
+        assert!(matches_filter(&item, &range, None));
+        item.set_due(Some(date(10)));
+        assert!(!matches_filter(&item, &range, None)); // bounds are exclusive
+
+        item.set_due(Some(due::today() - chrono::Days::new(1)));
+        assert!(matches_filter(&item, &overdue, None));
+        item.set_status(Status::Closed);
+        assert!(!matches_filter(&item, &overdue, None)); // closed items are never overdue
+
+        item.set_status(Status::Open);
+        item.set_due(Some(due::today()));
+        assert!(!matches_filter(&item, &overdue, None)); // due today is not overdue yet
+    }
+
+    #[test]
+    fn test_stale_filter() {
+        let mut item = sample_item("Task", "");
+        item.frontmatter.created_at = Utc::now() - chrono::Days::new(40);
+        let stale = FilterCriteria {
+            stale_since: Some(due::today() - chrono::Days::new(30)),
+            ..FilterCriteria::default()
+        };
+
+        assert!(matches_filter(&item, &stale, None));
+        item.touch();
+        assert!(!matches_filter(&item, &stale, None));
+    }
+
+    #[test]
+    fn test_field_filters() {
+        let mut item = sample_item("Task", "");
+        item.set_custom_field("sprint", fields::parse_value("12"))
+            .unwrap();
+        item.set_custom_field("team", fields::parse_value("Backend"))
+            .unwrap();
+
+        let criteria = |conditions: &[&str]| FilterCriteria {
+            fields: conditions
+                .iter()
+                .map(|c| FieldFilter::parse(c).unwrap())
+                .collect(),
+            ..FilterCriteria::default()
+        };
+
+        assert!(matches_filter(&item, &criteria(&["sprint=12"]), None));
+        assert!(matches_filter(
+            &item,
+            &criteria(&["sprint=12", "team=backend"]),
+            None
+        ));
+        assert!(!matches_filter(
+            &item,
+            &criteria(&["sprint=12", "team=frontend"]),
+            None
+        ));
+        assert!(!matches_filter(&item, &criteria(&["estimate=3"]), None));
+        assert!(matches_filter(&item, &criteria(&["sprint>10"]), None));
+        assert!(!matches_filter(&item, &criteria(&["sprint!=12"]), None));
+        // Built-in fields are matched too
+        assert!(matches_filter(&item, &criteria(&["status=open"]), None));
+    }
+
     #[test]
     fn test_full_text_still_matches_title_and_id() {
         let item = sample_item("Important Task", "Body text");
         assert!(matches_query(&item, "important", true));
         assert!(matches_query(&item, "260109", true));
     }
+    #[test]
+    fn test_label_filter_requires_all_labels() {
+        let mut item = sample_item("Task", "");
+        item.add_label("bug");
+        let criteria = |labels: &[&str]| FilterCriteria {
+            labels: labels.iter().map(ToString::to_string).collect(),
+            ..FilterCriteria::default()
+        };
+
+        assert!(matches_filter(&item, &criteria(&["BUG"]), None));
+        assert!(!matches_filter(&item, &criteria(&["bug", "ui"]), None));
+        item.add_label("ui");
+        assert!(matches_filter(&item, &criteria(&["bug", "ui"]), None));
+    }
+
+    #[test]
+    fn test_query_precedence() {
+        let term = |s: &str| Query::Term(Term::Label(s.to_string()));
+        let not = |q| Query::Not(Box::new(q));
+
+        assert_eq!(
+            Query::parse("label:a OR label:b AND NOT label:c").unwrap(),
+            Query::Or(vec![term("a"), Query::And(vec![term("b"), not(term("c"))]),])
+        );
+        assert_eq!(
+            Query::parse("(label:a OR label:b) label:c").unwrap(),
+            Query::And(vec![Query::Or(vec![term("a"), term("b")]), term("c")])
+        );
+        assert_eq!(Query::parse("label!=a").unwrap(), not(term("a")));
+    }
+
+    #[test]
+    fn test_query_text_terms() {
+        let query = Query::parse("login (crash OR \"on submit\") NOT slow label:bug").unwrap();
+        assert_eq!(query.text_terms(), ["login", "crash", "on submit"]);
+    }
+
+    #[test]
+    fn test_query_required_words() {
+        let query = Query::parse("login (crash OR slow) NOT bug title:page label:ui").unwrap();
+        assert_eq!(query.required_words(), ["login", "page"]);
+    }
+
+    #[test]
+    fn test_query_terms() {
+        let parse = |s: &str| Query::parse(s).unwrap();
+        let text = |s: &str| Query::Term(Term::Text(s.to_string()));
+
+        assert_eq!(parse("\"login bug\""), text("login bug"));
+        assert_eq!(parse("\"label:bug\""), text("label:bug"));
+        // Lowercase keywords are plain words
+        assert_eq!(
+            parse("fix or break"),
+            Query::And(vec![text("fix"), text("or"), text("break")])
+        );
+        assert_eq!(
+            parse("author:\"Jane Doe\""),
+            Query::Term(Term::Author("Jane Doe".to_string()))
+        );
+        assert_eq!(
+            parse("estimate>=3"),
+            Query::Term(Term::Field(FieldFilter::parse("estimate>=3").unwrap()))
+        );
+        assert!(matches!(
+            parse("created>2026-01-01"),
+            Query::Term(Term::Created(FieldOp::Gt, _))
+        ));
+        // Quoting turns conditions into text
+        assert_eq!(parse("\"http://x\""), text("http://x"));
+        // So does a URL scheme
+        assert_eq!(parse("https://x"), text("https://x"));
+    }
+
+    #[test]
+    fn test_query_errors() {
+        for input in [
+            "",
+            "   ",
+            "(label:bug",
+            "label:bug)",
+            "NOT",
+            "label:bug AND",
+            "OR label:bug",
+            "\"unterminated",
+            "label:",
+            "label>bug",
+            "created>soon",
+        ] {
+            assert!(Query::parse(input).is_err(), "{input:?} should fail");
+        }
+    }
+
+    #[test]
+    fn test_query_matches() {
+        let mut item = sample_item("Fix login", "Crashes on submit");
+        item.add_label("bug");
+        item.assign("Ann");
+        item.set_custom_field("sprint", fields::parse_value("12"))
+            .unwrap();
+        item.frontmatter.created_at = "2026-01-09T12:00:00Z".parse().unwrap();
+        let matches = |s: &str| {
+            Query::parse(s)
+                .unwrap()
+                .matches(&item, Some("backend"), true)
+        };
+
+        assert!(matches("label:bug AND NOT label:wontfix"));
+        assert!(matches("label:ui OR category:backend"));
+        assert!(!matches(
+            "label:bug AND (category:frontend OR author:nobody)"
+        ));
+        assert!(matches("assignee:ann author:test"));
+        assert!(matches("submit title:login"));
+        assert!(!matches("title:submit"));
+        assert!(matches("sprint>10 status=open"));
+        assert!(matches("created>=2026-01-01 created<2026-02-01"));
+        assert!(!matches("updated>2026-01-10"));
+        assert!(!matches("NOT label:bug"));
+
+        // Bare words only search the body with full text enabled
+        let query = Query::parse("submit").unwrap();
+        assert!(!query.matches(&item, None, false));
+    }
 }
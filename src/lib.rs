@@ -18,9 +18,13 @@
 pub mod commands;
 pub mod config;
 pub mod constants;
+pub mod csv;
 pub mod editor;
 pub mod id;
 pub mod item;
+pub mod markdown;
+pub mod mcp;
+pub mod output;
 pub mod storage;
 pub mod tui;
 pub mod ui;
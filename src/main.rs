@@ -12,9 +12,14 @@ use owo_colors::OwoColorize;
 
 use clap::CommandFactory;
 use clap_complete::Shell;
-use queuestack::commands::{
-    self, AttachAddArgs, AttachRemoveArgs, InteractiveArgs, ListMode, ListOptions, NewArgs,
-    SearchArgs, SortBy, StatusFilter, UpdateArgs,
+use queuestack::{
+    commands::{
+        self, AttachAddArgs, AttachRemoveArgs, BoardArgs, BulkOp, BulkTargets, CommentArgs,
+        DependencyFilter, DoctorArgs, ExportArgs, GroupBy, ImportArgs, InteractiveArgs, LinkArgs,
+        ListMode, ListOptions, NewArgs, OutputFormat, SearchArgs, ShowArgs, SortBy, StatusArgs,
+        StatusFilter, UpdateArgs,
+    },
+    item::{due, fields, rank::SearchMode, FieldFilter, Query},
 };
 
 const STYLES: Styles = Styles::styled()
@@ -98,6 +103,24 @@ Each item gets a unique ID, a slugified filename, and can be organized into cate
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Output format for listing commands
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value = "text",
+        help = "Output format: text, json, or jsonl (list, search, show, board, and view list); csv or html (export)"
+    )]
+    format: OutputFormat,
+
+    /// Fail on item files that cannot be parsed
+    #[arg(
+        long,
+        global = true,
+        help = "Fail instead of skipping item files that cannot be parsed (list, search, board)"
+    )]
+    strict: bool,
 }
 
 #[derive(Subcommand)]
@@ -133,14 +156,25 @@ The author is determined from (in order):\n  \
 3. Interactive prompt (saved to ~/.queuestack for future use)\n\n\
 Templates:\n  \
 --as-template     Create a template instead of an item\n  \
---from-template   Create an item from an existing template",
+--from-template   Create an item from an existing template\n\n\
+Custom fields declared in the project config ([[fields]]) are validated by type, \
+and their defaults are applied to new items.\n\n\
+The body can be given with --body, --body-file, or --body - (stdin), so scripts don't \
+need to edit the file afterwards. With --from-template it replaces the template's body.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs new "), a!("\"Fix login bug\""), "\n  ",
             c!("qs new "), a!("\"Add dark mode\""), c!(" --label "), a!("feature ui"), "\n  ",
             c!("qs new "), a!("\"Memory leak\""), c!(" --label "), a!("bug urgent"), c!(" --category "), a!("bugs"), "\n  ",
             c!("qs new "), a!("\"Bug report\""), c!(" --attachment "), a!("screenshot.png debug.log"), "\n  ",
-            c!("qs new "), a!("\"Quick note\""), c!(" --no-interactive"), "       Skip editor\n\n",
+            c!("qs new "), a!("\"Outage\""), c!(" --priority "), a!("critical"), "\n  ",
+            c!("qs new "), a!("\"Review PR\""), c!(" --assign "), a!("\"Jane Doe\""), "\n  ",
+            c!("qs new "), a!("\"Release notes\""), c!(" --due "), a!("friday"), "\n  ",
+            c!("qs new "), a!("\"Crash on save\""), c!(" --set "), a!("severity=major estimate=3"), "\n  ",
+            c!("qs new "), a!("\"Quick note\""), c!(" --no-interactive"), "       Skip editor\n  ",
+            c!("qs new "), a!("\"Crash\""), c!(" --body "), a!("\"Steps to reproduce...\""), c!(" --no-interactive"), "\n  ",
+            c!("qs new "), a!("\"Crash\""), c!(" --body-file "), a!("report.md"), c!(" --no-interactive"), "\n  ",
+            c!("cat log.md | qs new "), a!("\"Crash\""), c!(" --body - --no-interactive"), "   Body from stdin\n\n",
             h!("Templates:"), "\n  ",
             c!("qs new --as-template "), a!("\"Bug Report\""), "      Create a template\n  ",
             c!("qs new --from-template "), a!("bug-report"), " ", a!("\"Fix login\""), "  From template\n  ",
@@ -156,6 +190,10 @@ Templates:\n  \
         #[arg(short, long, num_args = 1.., help = "Labels/tags for the item")]
         label: Vec<String>,
 
+        /// People to assign (multiple values allowed)
+        #[arg(long, value_name = "NAME", num_args = 1.., help = "Assign people to the item")]
+        assign: Vec<String>,
+
         /// Category subdirectory for the item
         #[arg(short, long, help = "Category subdirectory for the item")]
         category: Option<String>,
@@ -164,6 +202,38 @@ Templates:\n  \
         #[arg(short, long, num_args = 1.., help = "Files or URLs to attach")]
         attachment: Vec<String>,
 
+        /// Priority level (must be one of the configured priorities)
+        #[arg(short, long, help = "Priority level (e.g., high)")]
+        priority: Option<String>,
+
+        /// Due date (absolute or relative)
+        #[arg(short, long, help = "Due date (YYYY-MM-DD, +3d, +2w, friday, ...)")]
+        due: Option<String>,
+
+        /// Custom fields
+        #[arg(
+            long = "set",
+            value_name = "KEY=VALUE",
+            num_args = 1..,
+            value_parser = parse_field_arg,
+            help = "Set custom frontmatter field(s), e.g. severity=major"
+        )]
+        set_fields: Vec<(String, String)>,
+
+        /// Body text
+        #[arg(
+            long,
+            value_name = "TEXT",
+            conflicts_with = "body_file",
+            allow_hyphen_values = true,
+            help = "Body text in Markdown ('-' reads from stdin)"
+        )]
+        body: Option<String>,
+
+        /// Read body from a file
+        #[arg(long, value_name = "PATH", help = "Read the body from a file")]
+        body_file: Option<std::path::PathBuf>,
+
         /// Force interactive mode (open editor)
         #[arg(
             short = 'i',
@@ -202,7 +272,13 @@ Templates:\n  \
 Shows all open items. Based on the 'interactive' config setting (default: true), \
 presents a selector to choose an item to open. Use -i to force interactive \
 selection, or --no-interactive to just print the list.\n\n\
-Use filters to narrow down results.\n\n\
+Use filters to narrow down results. --query accepts an expression combining \
+conditions with AND, OR, NOT, and parentheses, e.g. \
+'label:bug AND NOT label:wontfix AND (category:backend OR author:alice) AND created>2026-01-01'. \
+Keys: label, category, author, assignee, title, body, id, text, created, updated, and any \
+frontmatter field. Bare words match title, ID, or body.\n\n\
+--view starts from a saved view (see 'qs view list') and adds the other filters to it. \
+The view's sort order applies unless --sort is given.\n\n\
 Special modes:\n  \
 --labels        List unique labels across all items\n  \
 --categories    List unique categories across all items\n  \
@@ -216,52 +292,32 @@ Special modes:\n  \
             c!("qs list --closed"), "                   List archived/closed items\n  ",
             c!("qs list --label "), a!("bug"), "                Filter by label\n  ",
             c!("qs list --author "), a!("\"John\""), "            Filter by author\n  ",
+            c!("qs list --assignee "), a!("\"John\""), "          Filter by assignee\n  ",
+            c!("qs list --mine"), "                     Items assigned to you\n  ",
             c!("qs list --sort "), a!("date"), "                Sort by creation date\n  ",
+            c!("qs list --sort "), a!("priority"), "            Highest priority first\n  ",
+            c!("qs list --sort "), a!("updated"), "             Recently updated first\n  ",
+            c!("qs list --stale "), a!("30d"), "                Not updated for 30 days\n  ",
+            c!("qs list --priority "), a!("high critical"), "  Filter by priority\n  ",
+            c!("qs list --overdue"), "                  Open items past their due date\n  ",
+            c!("qs list --due-before "), a!("+7d"), "           Due within the next week\n  ",
+            c!("qs list --ready"), "                    Items with no open blockers\n  ",
+            c!("qs list --where "), a!("sprint=12"), "           Filter by a custom field\n  ",
+            c!("qs list --where "), a!("estimate>=3"), "         Compare numbers and dates\n  ",
+            c!("qs list -q "), a!("\"NOT label:wontfix\""), "    Filter with a query\n  ",
+            c!("qs list --view "), a!("triage"), "              Use a saved view\n  ",
             c!("qs list --labels"), "                   List all unique labels\n  ",
             c!("qs list --categories"), "               List all unique categories\n  ",
             c!("qs list --attachments --id "), a!("260109"), "      List attachments for item\n  ",
             c!("qs list --meta --id "), a!("260109"), "             Show item metadata\n  ",
-            c!("qs list --templates"), "                List all templates\n\n",
+            c!("qs list --templates"), "                List all templates\n  ",
+            c!("qs list --format "), a!("json"), "              Print items as a JSON array\n\n",
             h!("Interactive mode:"), " Use arrow keys to navigate, Enter to select, Esc to cancel."
         )
     )]
     List {
-        /// Show only open items
-        #[arg(
-            long,
-            conflicts_with = "closed",
-            help = "Show only open items (default if no status filter)"
-        )]
-        open: bool,
-
-        /// Show only closed items
-        #[arg(
-            long,
-            conflicts_with = "open",
-            help = "Show only closed/archived items"
-        )]
-        closed: bool,
-
-        /// Filter by label (can be specified multiple times for AND logic)
-        #[arg(long, num_args = 1.., help = "Filter items by label(s)")]
-        label: Vec<String>,
-
-        /// Filter by author
-        #[arg(long, help = "Filter items by author name (substring match)")]
-        author: Option<String>,
-
-        /// Filter by category
-        #[arg(long = "category", help = "Filter items by category")]
-        filter_category: Option<String>,
-
-        /// Sort order
-        #[arg(
-            long,
-            value_enum,
-            default_value = "id",
-            help = "Sort order: id, date, or title"
-        )]
-        sort: SortBy,
+        #[command(flatten)]
+        filter: ListFilterArgs,
 
         /// Force interactive mode (show selector)
         #[arg(
@@ -335,37 +391,108 @@ Special modes:\n  \
             help = "Item file path"
         )]
         file: Option<std::path::PathBuf>,
+
+        /// Include item bodies in JSON output
+        #[arg(long, help = "Include item bodies in --format json/jsonl output")]
+        with_body: bool,
+    },
+
+    /// Print an item with its metadata and rendered body
+    #[command(
+        long_about = "Print an item in the terminal.\n\n\
+Shows a header with the title, status, priority, due date, labels, category, author, \
+age, links, custom fields, and attachments, followed by the Markdown body with \
+terminal styling. Colors are disabled when output is not a terminal or NO_COLOR is set.\n\n\
+Use --raw to print the item file unchanged (for piping), or --format json to get the \
+item including its body as JSON.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs show --id "), a!("260109"), "                 Show an item\n  ",
+            c!("qs show --id "), a!("260109"), c!(" --raw"), "           Print the Markdown file\n  ",
+            c!("qs show --id "), a!("260109"), c!(" --format "), a!("json"), "   Print as JSON"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Show {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Print the item file unchanged
+        #[arg(long, help = "Print the item file unchanged")]
+        raw: bool,
     },
 
     /// Search for items and interactively select one to open
     #[command(
         long_about = "Search for items by title or ID.\n\n\
-Performs a case-insensitive substring search against item titles and IDs. Based on \
+Performs a case-insensitive substring search against item titles and IDs. The query \
+uses the same language as 'qs list --query': combine words and conditions like \
+label:bug or created>2026-01-01 with AND, OR, NOT, and parentheses. Based on \
 the 'interactive' config setting (default: true), presents a selector for matches. \
 Use -i to force interactive selection, or --no-interactive to just list results.\n\n\
 Search behavior:\n  \
 - Single match: opens the item directly (in interactive mode)\n  \
 - Multiple matches: shows interactive selector or lists results\n  \
-- No matches: returns an error\n\n\
-Use --full-text to also search within the markdown body content.",
+- No matches: returns an error (an empty array with --format json)\n\n\
+Use --full-text to also search within the markdown body content.\n\n\
+Use --regex to match a regular expression or --fuzzy to match the query characters in \
+order with gaps allowed. Results are ranked by relevance: title matches rank above ID \
+matches, which rank above body matches, and closer matches rank higher. Without \
+--interactive, body matches are printed below each item with the matching line and \
+its surrounding lines.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs search "), a!("\"login bug\""), "                 Search and select interactively\n  ",
             c!("qs search "), a!("\"260109-0A2B3C4\""), "            Search by ID\n  ",
             c!("qs search "), a!("\"auth\""), c!(" --full-text"), "          Include body content in search\n  ",
+            c!("qs search "), a!("\"login label:bug\""), "           Combine text and conditions\n  ",
+            c!("qs search "), a!("\"v[0-9]+\\.[0-9]+\""), c!(" --regex"), "   Search with a regular expression\n  ",
+            c!("qs search "), a!("\"lgnbtn\""), c!(" --fuzzy"), "            Fuzzy search (finds \"Login button\")\n  ",
             c!("qs search "), a!("\"bug\""), c!(" --no-interactive"), "      Just list matching items\n  ",
-            c!("qs search "), a!("\"old task\""), c!(" --closed"), "         Search in archived items\n\n",
+            c!("qs search "), a!("\"old task\""), c!(" --closed"), "         Search in archived items\n  ",
+            c!("qs search "), a!("\"bug\""), c!(" --format "), a!("jsonl"), "       One JSON object per match\n\n",
             h!("Interactive mode:"), " Use arrow keys to navigate, Enter to select, Esc to cancel."
         )
     )]
     Search {
-        /// Search query (matches against title and ID)
+        /// Search query (matches against title and ID, supports query syntax)
         query: String,
 
         /// Also search in item body content
         #[arg(long, help = "Include body content in search")]
         full_text: bool,
 
+        /// Match the query as a regular expression
+        #[arg(
+            long,
+            conflicts_with = "fuzzy",
+            help = "Treat the query as a regular expression (case-insensitive)"
+        )]
+        regex: bool,
+
+        /// Match the query as a fuzzy subsequence
+        #[arg(
+            long,
+            conflicts_with = "regex",
+            help = "Fuzzy match: query characters in order, gaps allowed"
+        )]
+        fuzzy: bool,
+
         /// Force interactive mode (show selector)
         #[arg(
             short = 'i',
@@ -382,23 +509,45 @@ Use --full-text to also search within the markdown body content.",
         /// Search in closed/archived items
         #[arg(long, help = "Search in closed/archived items instead of open")]
         closed: bool,
+
+        /// Include item bodies in JSON output
+        #[arg(long, help = "Include item bodies in --format json/jsonl output")]
+        with_body: bool,
     },
 
     /// Update an existing item
     #[command(
         long_about = "Update an existing item's metadata.\n\n\
-Modify the title, labels, or category of an item. If the title changes, the file \
+Modify the title, labels, assignees, category, priority, due date, or custom fields of an item. If the title changes, the file \
 is renamed to reflect the new slug. In Git repositories, uses 'git mv' to preserve \
 history.\n\n\
 Labels are additive - new labels are added without removing existing ones. \
-To modify labels directly, edit the Markdown file.",
+To modify labels directly, edit the Markdown file.\n\n\
+Custom fields are arbitrary frontmatter keys (e.g. 'sprint', 'estimate'). Values set with \
+--set are stored as YAML scalars, so numbers and booleans keep their type. Fields declared \
+in the project config ([[fields]]) are validated against their type and allowed values. \
+Unknown keys added by hand or by other tools are preserved on every update.\n\n\
+--body and --body-file replace the description, and --append-body adds a paragraph \
+to it; '-' reads the text from stdin. Comments added with 'qs comment' are kept.\n\n\
+Instead of one item, --query updates every open item matching a query and --stdin \
+the items whose full IDs or paths are piped in (one per line, e.g. from 'qs list'). Each \
+item's result is reported; --dry-run shows what would change without writing.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --title "), a!("\"New title\""), "\n  ",
             c!("qs update --id "), a!("2601"), c!(" --label "), a!("urgent p1"), "      Partial ID\n  ",
             c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --category "), a!("bugs"), "\n  ",
             c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --remove-category"), "  Move to queuestack root\n  ",
-            c!("qs update --id "), a!("26"), c!(" --title "), a!("\"Fix\""), c!(" --label "), a!("done"), "      Partial ID\n\n",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --priority "), a!("high"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --assign "), a!("\"Jane Doe\""), c!(" --unassign "), a!("Bob"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --due "), a!("+3d"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --set "), a!("sprint=12 estimate=3"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --unset "), a!("estimate"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --body-file "), a!("notes.md"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --append-body "), a!("\"Found the cause\""), "\n  ",
+            c!("qs update --id "), a!("26"), c!(" --title "), a!("\"Fix\""), c!(" --label "), a!("done"), "      Partial ID\n  ",
+            c!("qs update --query "), a!("'label:bug'"), c!(" --priority "), a!("high"), c!(" --dry-run"), "\n  ",
+            c!("qs list --label "), a!("ui"), c!(" --no-interactive | qs update --stdin --category "), a!("frontend"), "\n\n",
             h!("Note:"), " The --id flag supports partial matching for convenience."
         ),
         group = ArgGroup::new("item_ref").required(true)
@@ -422,6 +571,29 @@ To modify labels directly, edit the Markdown file.",
         )]
         file: Option<std::path::PathBuf>,
 
+        /// Select items with a query
+        #[arg(
+            short = 'q',
+            long,
+            visible_alias = "where",
+            value_name = "EXPR",
+            group = "item_ref",
+            help = "Update all open items matching a query (e.g. 'label:done')"
+        )]
+        query: Option<String>,
+
+        /// Read item IDs or paths from stdin
+        #[arg(
+            long,
+            group = "item_ref",
+            help = "Update the items whose full IDs or paths are read from stdin, one per line"
+        )]
+        stdin: bool,
+
+        /// Report changes without writing them
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
+
         /// New title
         #[arg(long, help = "New title (renames file if changed)")]
         title: Option<String>,
@@ -434,6 +606,19 @@ To modify labels directly, edit the Markdown file.",
         #[arg(long, num_args = 1.., help = "Remove label(s) from item")]
         remove_label: Vec<String>,
 
+        /// Assign people
+        #[arg(long, value_name = "NAME", num_args = 1.., help = "Assign people to the item")]
+        assign: Vec<String>,
+
+        /// Unassign people
+        #[arg(
+            long,
+            value_name = "NAME",
+            num_args = 1..,
+            help = "Remove people from the item's assignees"
+        )]
+        unassign: Vec<String>,
+
         /// Move to category
         #[arg(
             long,
@@ -449,6 +634,77 @@ To modify labels directly, edit the Markdown file.",
             help = "Remove from category (move to queuestack root)"
         )]
         remove_category: bool,
+
+        /// Set priority
+        #[arg(
+            long,
+            conflicts_with = "remove_priority",
+            help = "Set priority level (e.g., high)"
+        )]
+        priority: Option<String>,
+
+        /// Clear priority
+        #[arg(long, conflicts_with = "priority", help = "Clear the priority")]
+        remove_priority: bool,
+
+        /// Set due date
+        #[arg(
+            long,
+            conflicts_with = "remove_due",
+            help = "Set due date (YYYY-MM-DD, +3d, +2w, friday, ...)"
+        )]
+        due: Option<String>,
+
+        /// Clear due date
+        #[arg(long, conflicts_with = "due", help = "Clear the due date")]
+        remove_due: bool,
+
+        /// Set custom fields
+        #[arg(
+            long = "set",
+            value_name = "KEY=VALUE",
+            num_args = 1..,
+            value_parser = parse_field_arg,
+            help = "Set custom frontmatter field(s), e.g. sprint=12"
+        )]
+        set_fields: Vec<(String, String)>,
+
+        /// Remove custom fields
+        #[arg(
+            long = "unset",
+            value_name = "KEY",
+            num_args = 1..,
+            help = "Remove custom frontmatter field(s)"
+        )]
+        unset_fields: Vec<String>,
+
+        /// Replace the description
+        #[arg(
+            long,
+            value_name = "TEXT",
+            conflicts_with_all = ["body_file", "append_body"],
+            allow_hyphen_values = true,
+            help = "Replace the description ('-' reads from stdin)"
+        )]
+        body: Option<String>,
+
+        /// Replace the description from a file
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "append_body",
+            help = "Replace the description with a file's content"
+        )]
+        body_file: Option<std::path::PathBuf>,
+
+        /// Append to the description
+        #[arg(
+            long,
+            value_name = "TEXT",
+            allow_hyphen_values = true,
+            help = "Append a paragraph to the description ('-' reads from stdin)"
+        )]
+        append_body: Option<String>,
     },
 
     /// Close an item (move to archive)
@@ -456,11 +712,19 @@ To modify labels directly, edit the Markdown file.",
         long_about = "Close an item by moving it to the archive directory.\n\n\
 Sets the item's status to 'closed' and moves it from the queuestack directory to the \
 archive subdirectory. In Git repositories, uses 'git mv' to preserve history.\n\n\
-Closed items are excluded from 'qs list' by default (use --closed to see them).",
+Closed items are excluded from 'qs list' by default (use --closed to see them).\n\n\
+If other items are blocked by the closed item, reports which of them are now \
+unblocked and warns about those still waiting on other open items.\n\n\
+If the project defines a workflow, the item moves to the first archived state.\n\n\
+Instead of one item, --query closes every open item matching a query and --stdin \
+the items whose full IDs or paths are piped in (one per line, e.g. from 'qs list'). Each \
+item's result is reported; --dry-run shows what would change without writing.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs close --id "), a!("260109-0A2B3C4"), "          Close by full ID\n  ",
             c!("qs close --id "), a!("2601"), "                    Close by partial ID\n  ",
+            c!("qs close --query "), a!("'label:done'"), "         Close all matching items\n  ",
+            c!("qs list --label "), a!("done"), c!(" --no-interactive | qs close --stdin --dry-run"), "\n  ",
             c!("qs list --closed"), "                      View closed items\n  ",
             c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen if needed"
         ),
@@ -484,6 +748,29 @@ Closed items are excluded from 'qs list' by default (use --closed to see them)."
             help = "Item file path"
         )]
         file: Option<std::path::PathBuf>,
+
+        /// Select items with a query
+        #[arg(
+            short = 'q',
+            long,
+            visible_alias = "where",
+            value_name = "EXPR",
+            group = "item_ref",
+            help = "Close all open items matching a query (e.g. 'label:done')"
+        )]
+        query: Option<String>,
+
+        /// Read item IDs or paths from stdin
+        #[arg(
+            long,
+            group = "item_ref",
+            help = "Close the items whose full IDs or paths are read from stdin, one per line"
+        )]
+        stdin: bool,
+
+        /// Report changes without writing them
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
     },
 
     /// Reopen a closed item (move from archive)
@@ -491,11 +778,16 @@ Closed items are excluded from 'qs list' by default (use --closed to see them)."
         long_about = "Reopen a closed item by moving it back from the archive.\n\n\
 Sets the item's status to 'open' and moves it from the archive directory back \
 to queuestack (or its original category). In Git repositories, uses 'git mv' to \
-preserve history.",
+preserve history.\n\n\
+If the project defines a workflow, the item moves to the initial state.\n\n\
+Instead of one item, --query reopens every closed item matching a query and --stdin \
+the items whose full IDs or paths are piped in (one per line). Each item's result is \
+reported; --dry-run shows what would change without writing.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen by full ID\n  ",
             c!("qs reopen --id "), a!("2601"), "                   Reopen by partial ID\n  ",
+            c!("qs reopen --query "), a!("'label:regression'"), "  Reopen all matching items\n  ",
             c!("qs list"), "                               Verify item is back in open list"
         ),
         group = ArgGroup::new("item_ref").required(true)
@@ -518,6 +810,169 @@ preserve history.",
             help = "Item file path"
         )]
         file: Option<std::path::PathBuf>,
+
+        /// Select items with a query
+        #[arg(
+            short = 'q',
+            long,
+            visible_alias = "where",
+            value_name = "EXPR",
+            group = "item_ref",
+            help = "Reopen all closed items matching a query (e.g. 'label:done')"
+        )]
+        query: Option<String>,
+
+        /// Read item IDs or paths from stdin
+        #[arg(
+            long,
+            group = "item_ref",
+            help = "Reopen the items whose full IDs or paths are read from stdin, one per line"
+        )]
+        stdin: bool,
+
+        /// Report changes without writing them
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
+    },
+
+    /// Move an item to another workflow state
+    #[command(
+        long_about = "Move an item to another workflow state.\n\n\
+Workflow states, allowed transitions, and archived states are configured in the \
+[workflow] table of the project config (.queuestack). The state is stored in the \
+item's 'state' key. Moving into an archived state closes the item and moves it to \
+the archive; moving out of one reopens it.\n\n\
+Transitions not listed in [workflow.transitions] are rejected unless --force is given. \
+Without a workflow, the only states are 'open' and 'closed'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs status --id "), a!("260109"), " ", a!("in-progress"), "     Start working on an item\n  ",
+            c!("qs status --id "), a!("260109"), " ", a!("done"), "            Finish (archives the item)\n  ",
+            c!("qs status --id "), a!("260109"), " ", a!("todo"), c!(" --force"), "    Skip the transition check\n  ",
+            c!("qs list --state "), a!("review"), "                Items in a state"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Status {
+        /// Target state
+        #[arg(help = "Target workflow state")]
+        state: String,
+
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Allow transitions not listed in the workflow
+        #[arg(long, help = "Skip the transition check")]
+        force: bool,
+    },
+
+    /// Add a comment to an item
+    #[command(
+        long_about = "Add a timestamped comment to an item.\n\n\
+Comments are appended to a 'Comments' section at the end of the item's Markdown body, \
+one entry per comment with its time and author. The author is resolved like for new \
+items: user_name from the config, then git user.name.\n\n\
+Pass the text as an argument, or pass '-' (or nothing) to read it from stdin. \
+'qs show' lists comments separately from the description.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs comment --id "), a!("260109"), " ", a!("\"Fixed in 1a2b3c\""), "   Add a comment\n  ",
+            c!("git log -1 | qs comment --id "), a!("260109"), "      Read the comment from stdin"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Comment {
+        /// Comment text
+        #[arg(help = "Comment text (Markdown); '-' or omitted reads from stdin")]
+        text: Option<String>,
+
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+    },
+
+    /// Link items to each other (blocks, blocked by, related)
+    #[command(
+        long_about = "Link an item to other items.\n\n\
+Links are stored as item IDs in the frontmatter of both items: '--blocks B' adds B to \
+the item's 'blocks' list and the item to B's 'blocked_by' list. Related links are \
+recorded on both sides as well. Because links use IDs, they keep working when items \
+are renamed, moved between categories, or archived.\n\n\
+An item is blocked while any item in its 'blocked_by' list is still open.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs link --id "), a!("260109"), c!(" --blocks "), a!("260110"), "          260110 waits on 260109\n  ",
+            c!("qs link --id "), a!("260110"), c!(" --blocked-by "), a!("260109"), "      Same link, other side\n  ",
+            c!("qs link --id "), a!("260109"), c!(" --related "), a!("260111 260112"), "\n  ",
+            c!("qs link --id "), a!("260109"), c!(" --blocks "), a!("260110"), c!(" --remove"), " Remove a link\n  ",
+            c!("qs list --blocked"), "                          Items waiting on open blockers\n  ",
+            c!("qs list --ready"), "                            Items with no open blockers"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Link {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to link from (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Items blocked by this item
+        #[arg(long, num_args = 1.., help = "Item ID(s) this item blocks")]
+        blocks: Vec<String>,
+
+        /// Items blocking this item
+        #[arg(long, num_args = 1.., help = "Item ID(s) blocking this item")]
+        blocked_by: Vec<String>,
+
+        /// Related items
+        #[arg(long, num_args = 1.., help = "Related item ID(s)")]
+        related: Vec<String>,
+
+        /// Remove the links instead of adding them
+        #[arg(long, help = "Remove the given links")]
+        remove: bool,
     },
 
     /// Manage item attachments (add, remove)
@@ -539,6 +994,218 @@ To list attachments for an item, use: qs list --attachments --id <ID>",
         action: AttachmentsAction,
     },
 
+    /// Show items on a kanban board
+    #[command(
+        long_about = "Show items on a kanban board.\n\n\
+Columns are the workflow states (--by status, the default, including archived states), \
+labels (--by label), or categories (--by category). Label and category boards show open \
+items; an extra column holds items without any of the labels or without a category.\n\n\
+Moving a card to another column updates the item: --by status moves it to the state \
+(closing or reopening it as needed, respecting workflow transitions), --by label replaces \
+the board's labels with the column's label, and --by category moves the file to the \
+category's directory.\n\n\
+Without interactive mode, the board is printed column by column.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs board"), "                              Columns per workflow state\n  ",
+            c!("qs board --by "), a!("category"), "                Columns per category\n  ",
+            c!("qs board --by "), a!("label"), " --labels ", a!("todo doing done"), "\n  ",
+            c!("qs board --format "), a!("json"), "                Columns with their items as JSON\n\n",
+            h!("Interactive mode:"), " ←/→ switch columns, ↑/↓ select a card, Shift+←/→ (or H/L) move it, Enter opens it, Esc quits."
+        )
+    )]
+    Board {
+        /// What the columns are
+        #[arg(
+            long,
+            value_enum,
+            default_value = "status",
+            help = "Group columns by: status, label, or category"
+        )]
+        by: GroupBy,
+
+        /// Labels to use as columns
+        #[arg(
+            long,
+            num_args = 1..,
+            help = "Labels to use as columns with --by label (default: all labels in use)"
+        )]
+        labels: Vec<String>,
+
+        /// Force interactive mode
+        #[arg(
+            short = 'i',
+            long,
+            conflicts_with = "no_interactive",
+            help = "Force interactive board"
+        )]
+        interactive: bool,
+
+        /// Force non-interactive mode
+        #[arg(long, help = "Just print the board")]
+        no_interactive: bool,
+    },
+
+    /// Manage saved views (list)
+    #[command(
+        long_about = "Manage saved views.\n\n\
+A view is a named list preset declared as a [[views]] table in .queuestack. It bundles \
+filters (query, labels, category, author, assignees, priorities, states, where), a sort \
+order, and the columns shown in the interactive list. Views can also be saved from the \
+interactive list: open the filter with 'f' and press Ctrl+S.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs view list"), "                   List saved views\n  ",
+            c!("qs view list --format "), a!("json"), "     Print views as JSON\n\n",
+            h!("See also:"), " ", c!("qs list --view "), a!("<NAME>"), " to list items of a view"
+        )
+    )]
+    View {
+        #[command(subcommand)]
+        action: ViewAction,
+    },
+
+    /// Export items to CSV, JSON, or an HTML report
+    #[command(
+        long_about = "Export items to CSV, JSON, JSON Lines, or an HTML report.\n\n\
+Takes the same filters as qs list (open items by default) and writes every frontmatter \
+field plus the derived category:\n  \
+csv     One row per item, custom fields as extra columns, lists joined with commas\n  \
+json    The qs list --format json records, including item bodies (jsonl: one per line)\n  \
+html    A self-contained report with a section per category, rendered bodies and \
+comments, and links to local attachments (relative to the report)\n\n\
+Writes to stdout unless --output is given.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs export --format "), a!("csv"), "                        Print open items as CSV\n  ",
+            c!("qs export --format "), a!("json"), c!(" -o "), a!("items.json"), "         Write a JSON file\n  ",
+            c!("qs export --format "), a!("html"), c!(" -o "), a!("report.html"), "        Write an HTML report\n  ",
+            c!("qs export --format "), a!("csv"), c!(" --closed --label "), a!("bug"), "   Closed bugs as CSV\n  ",
+            c!("qs export --format "), a!("html"), c!(" --view "), a!("triage"), "         Export a saved view"
+        )
+    )]
+    Export {
+        #[command(flatten)]
+        filter: ListFilterArgs,
+
+        /// File to write
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "File to write (default: stdout)"
+        )]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Import issues from GitHub, GitLab, or Jira
+    #[command(
+        long_about = "Import issues from a GitHub, GitLab, or Jira export file.\n\n\
+Sources:\n  \
+github-json   gh issue list --json number,title,body,labels,author,assignees,state,\
+createdAt,updatedAt,closedAt,url (or the REST issues API; pull requests are skipped)\n  \
+gitlab-json   The REST issues API (GET /projects/:id/issues)\n  \
+gitlab-csv    The issue list's \"Export as CSV\"\n  \
+jira-json     The REST search API (GET /rest/api/2/search)\n  \
+jira-csv      \"Export CSV (all fields)\" (has no issue URLs)\n\n\
+Titles, bodies, labels, authors, assignees, due dates, and timestamps are kept. IDs are \
+generated for the original creation time. Closed issues are created in the archive. The \
+issue URL is attached to the item, and issues whose URL is already attached to an item \
+are skipped, so importing the same export again only adds new issues.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs import --from "), a!("github-json"), c!(" "), a!("issues.json"), "               Import GitHub issues\n  ",
+            c!("qs import --from "), a!("gitlab-csv"), c!(" "), a!("export.csv"), c!(" --label "), a!("gitlab"), "  Label all imported items\n  ",
+            c!("qs import --from "), a!("jira-json"), c!(" "), a!("search.json"), c!(" --dry-run"), "       Preview without creating items"
+        )
+    )]
+    Import {
+        /// Export format
+        #[arg(
+            long = "from",
+            value_enum,
+            value_name = "SOURCE",
+            help = "Export format"
+        )]
+        source: commands::ImportSource,
+
+        /// Export file
+        #[arg(value_name = "FILE", help = "Export file (- reads from stdin)")]
+        file: std::path::PathBuf,
+
+        /// Category for all imported items
+        #[arg(long, help = "Category for all imported items")]
+        category: Option<String>,
+
+        /// Labels added to all imported items
+        #[arg(long, num_args = 1.., help = "Add label(s) to all imported items")]
+        label: Vec<String>,
+
+        /// Custom fields set on all imported items
+        #[arg(
+            long = "set",
+            value_name = "KEY=VALUE",
+            num_args = 1..,
+            value_parser = parse_field_arg,
+            help = "Set custom frontmatter field(s) on all imported items"
+        )]
+        set_fields: Vec<(String, String)>,
+
+        /// Preview without creating items
+        #[arg(long, help = "Show what would be imported without creating anything")]
+        dry_run: bool,
+    },
+
+    /// Run an MCP server for agents
+    #[command(
+        long_about = "Run a JSON-RPC server speaking the Model Context Protocol (MCP).\n\n\
+The server reads newline-delimited JSON-RPC 2.0 messages from stdin and writes responses \
+to stdout, so MCP clients can start it as a subprocess. Warnings go to stderr.\n\n\
+Tools: list, search, show, new, update, close, comment, attach. They take the same \
+options as the commands of the same name and return items in the format of \
+'--format json'. Each tool can also be called directly as a JSON-RPC method.\n\n\
+Run the server from the project directory. New items and comments are attributed to \
+the configured user name.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs serve --stdio"), "                      Serve the current project\n\n",
+            h!("Client config:"), " {\"command\": \"qs\", \"args\": [\"serve\", \"--stdio\"]}"
+        )
+    )]
+    Serve {
+        /// Use stdin and stdout as the transport
+        #[arg(long, required = true, help = "Use stdin/stdout as the transport")]
+        stdio: bool,
+    },
+
+    /// Check the queuestack directory for problems
+    #[command(
+        long_about = "Check the queuestack directory for problems.\n\n\
+Other commands skip files they cannot read, so problems can go unnoticed. The doctor \
+reports:\n  \
+- Item files that fail to parse\n  \
+- Duplicate IDs\n  \
+- Filenames that no longer match the item's ID and title\n  \
+- Closed items outside the archive, open items inside it, and templates outside the \
+template directory (or items inside it)\n  \
+- Attachments listed in the frontmatter but missing on disk\n  \
+- Attachment files not listed by an item in their directory\n\n\
+--fix applies the safe repairs: renaming files to match their title, moving items to or \
+from the archive, moving attachments next to their item, and listing unlisted attachments \
+in their item's frontmatter. Everything else is only reported.\n\n\
+Exits with an error while problems remain.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs doctor"), "                             Report problems\n  ",
+            c!("qs doctor --fix"), "                       Repair what can be repaired safely"
+        )
+    )]
+    Doctor {
+        /// Apply safe repairs
+        #[arg(long, help = "Apply safe repairs (renames and moves)")]
+        fix: bool,
+    },
+
     /// One-time setup: create global config and install shell completions
     #[command(
         long_about = "One-time setup for queuestack.\n\n\
@@ -590,6 +1257,202 @@ For automatic installation, use 'qs setup' instead.",
     },
 }
 
+impl Commands {
+    /// Returns true if the command prints items in the `--format` format.
+    const fn supports_format(&self) -> bool {
+        matches!(
+            self,
+            Self::List { .. }
+                | Self::Search { .. }
+                | Self::Show { .. }
+                | Self::Board { .. }
+                | Self::View { .. }
+                | Self::Export { .. }
+        )
+    }
+}
+
+/// Item filters shared by `list` and `export`
+#[derive(clap::Args)]
+// One field per command-line flag
+#[allow(clippy::struct_excessive_bools)]
+struct ListFilterArgs {
+    /// Show only open items
+    #[arg(
+        long,
+        conflicts_with = "closed",
+        help = "Show only open items (default if no status filter)"
+    )]
+    open: bool,
+
+    /// Show only closed items
+    #[arg(
+        long,
+        conflicts_with = "open",
+        help = "Show only closed/archived items"
+    )]
+    closed: bool,
+
+    /// Filter by label (can be specified multiple times for AND logic)
+    #[arg(long, num_args = 1.., help = "Filter items by label(s)")]
+    label: Vec<String>,
+
+    /// Filter by author
+    #[arg(long, help = "Filter items by author name (substring match)")]
+    author: Option<String>,
+
+    /// Filter by assignee (multiple values use OR logic)
+    #[arg(
+        long,
+        value_name = "NAME",
+        num_args = 1..,
+        help = "Filter items by assignee (use 'none' for unassigned)"
+    )]
+    assignee: Vec<String>,
+
+    /// Show only items assigned to the current user
+    #[arg(long, help = "Show only items assigned to you (your user_name)")]
+    mine: bool,
+
+    /// Filter by category
+    #[arg(long = "category", help = "Filter items by category")]
+    filter_category: Option<String>,
+
+    /// Filter by priority (multiple values use OR logic)
+    #[arg(
+        long,
+        num_args = 1..,
+        help = "Filter items by priority (use 'none' for unprioritized)"
+    )]
+    priority: Vec<String>,
+
+    /// Show only open items past their due date
+    #[arg(long, help = "Show only overdue items")]
+    overdue: bool,
+
+    /// Show only items due before a date
+    #[arg(
+        long,
+        value_parser = parse_due_arg,
+        help = "Show items due before a date (YYYY-MM-DD, +3d, friday, ...)"
+    )]
+    due_before: Option<chrono::NaiveDate>,
+
+    /// Show only items due after a date
+    #[arg(
+        long,
+        value_parser = parse_due_arg,
+        help = "Show items due after a date (YYYY-MM-DD, -3d, monday, ...)"
+    )]
+    due_after: Option<chrono::NaiveDate>,
+
+    /// Show only items not updated recently
+    #[arg(
+        long,
+        value_name = "AGE",
+        value_parser = parse_age_arg,
+        help = "Show items not updated for at least AGE (e.g. 30d, 2w, 1m)"
+    )]
+    stale: Option<chrono::NaiveDate>,
+
+    /// Filter by frontmatter field
+    #[arg(
+        long = "where",
+        value_name = "KEY=VALUE",
+        value_parser = parse_where_arg,
+        help = "Filter by frontmatter field, e.g. sprint=12 or estimate>=3 (repeatable, AND logic)"
+    )]
+    where_fields: Vec<FieldFilter>,
+
+    /// Filter with a query expression
+    #[arg(
+        short = 'q',
+        long,
+        value_name = "EXPR",
+        value_parser = parse_query_arg,
+        help = "Filter with a query, e.g. 'label:bug AND NOT label:wontfix'"
+    )]
+    query: Option<Query>,
+
+    /// Filter by workflow state
+    #[arg(
+        long,
+        num_args = 1..,
+        help = "Filter by workflow state (OR logic, includes archived items)"
+    )]
+    state: Vec<String>,
+
+    /// Start from a saved view
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Start from a saved view (other filters are added to it)"
+    )]
+    view: Option<String>,
+
+    /// Show only items with open blockers
+    #[arg(
+        long,
+        conflicts_with = "ready",
+        help = "Show only items blocked by open items"
+    )]
+    blocked: bool,
+
+    /// Show only items without open blockers
+    #[arg(
+        long,
+        conflicts_with = "blocked",
+        help = "Show only items with no open blockers"
+    )]
+    ready: bool,
+
+    /// Sort order
+    #[arg(
+        long,
+        value_enum,
+        help = "Sort order: id (default), date, updated, title, priority, or due"
+    )]
+    sort: Option<SortBy>,
+}
+
+impl ListFilterArgs {
+    /// Converts the filters into list options (other options at defaults).
+    fn into_options(self) -> ListOptions {
+        let status = if self.closed {
+            StatusFilter::Closed
+        } else {
+            StatusFilter::Open // Default to open
+        };
+        let dependencies = if self.blocked {
+            Some(DependencyFilter::Blocked)
+        } else if self.ready {
+            Some(DependencyFilter::Ready)
+        } else {
+            None
+        };
+        ListOptions {
+            status,
+            labels: self.label,
+            author: self.author,
+            assignees: self.assignee,
+            mine: self.mine,
+            category: self.filter_category,
+            priorities: self.priority,
+            overdue: self.overdue,
+            due_before: self.due_before,
+            due_after: self.due_after,
+            stale_since: self.stale,
+            dependencies,
+            fields: self.where_fields,
+            query: self.query,
+            states: self.state,
+            sort: self.sort,
+            view: self.view,
+            ..ListOptions::default()
+        }
+    }
+}
+
 /// Subcommands for the attachments command
 #[derive(Subcommand)]
 enum AttachmentsAction {
@@ -663,6 +1526,38 @@ enum AttachmentsAction {
     },
 }
 
+/// Subcommands for the view command
+#[derive(Subcommand)]
+enum ViewAction {
+    /// List saved views
+    List,
+}
+
+/// Parses a due date expression for clap (relative dates resolve against today).
+fn parse_due_arg(value: &str) -> Result<chrono::NaiveDate, String> {
+    due::parse_due(value).map_err(|e| e.to_string())
+}
+
+/// Parses a `--stale` age for clap into the cutoff date.
+fn parse_age_arg(value: &str) -> Result<chrono::NaiveDate, String> {
+    due::parse_age_from(value, due::today()).map_err(|e| e.to_string())
+}
+
+/// Parses a `--where` condition for clap.
+fn parse_where_arg(value: &str) -> Result<FieldFilter, String> {
+    FieldFilter::parse(value).map_err(|e| e.to_string())
+}
+
+/// Parses a `--query` expression for clap.
+fn parse_query_arg(value: &str) -> Result<Query, String> {
+    Query::parse(value).map_err(|e| e.to_string())
+}
+
+/// Parses a `key=value` field argument for clap.
+fn parse_field_arg(value: &str) -> Result<(String, String), String> {
+    fields::parse_assignment(value).map_err(|e| e.to_string())
+}
+
 fn main() {
     if let Err(err) = run() {
         eprintln!("{} {err:#}", "error:".red().bold());
@@ -673,6 +1568,17 @@ fn main() {
 #[allow(clippy::too_many_lines)]
 fn run() -> Result<()> {
     let cli = Cli::parse();
+    let format = cli.format;
+    let strict = cli.strict;
+
+    if format.is_export_only() && !matches!(cli.command, Commands::Export { .. }) {
+        anyhow::bail!("--format csv and --format html are only supported by qs export");
+    }
+    if format != OutputFormat::Text && !cli.command.supports_format() {
+        anyhow::bail!(
+            "--format is only supported by list, search, show, board, view list, and export"
+        );
+    }
 
     match cli.command {
         Commands::Init => commands::init(),
@@ -680,8 +1586,14 @@ fn run() -> Result<()> {
         Commands::New {
             title,
             label,
+            assign,
             category,
             attachment,
+            priority,
+            due,
+            set_fields,
+            body,
+            body_file,
             interactive,
             no_interactive,
             as_template,
@@ -689,8 +1601,14 @@ fn run() -> Result<()> {
         } => commands::new(NewArgs {
             title,
             labels: label,
+            assignees: assign,
             category,
             attachments: attachment,
+            priority,
+            due,
+            fields: set_fields,
+            body,
+            body_file,
             interactive: InteractiveArgs {
                 interactive,
                 no_interactive,
@@ -700,12 +1618,7 @@ fn run() -> Result<()> {
         }),
 
         Commands::List {
-            open: _,
-            closed,
-            label,
-            author,
-            filter_category,
-            sort,
+            filter,
             interactive,
             no_interactive,
             labels,
@@ -715,6 +1628,7 @@ fn run() -> Result<()> {
             templates,
             id,
             file,
+            with_body,
         } => {
             let mode = if labels {
                 ListMode::Labels
@@ -729,64 +1643,172 @@ fn run() -> Result<()> {
             } else {
                 ListMode::Items
             };
-            let status = if closed {
-                StatusFilter::Closed
-            } else {
-                StatusFilter::Open // Default to open
-            };
             commands::list(&ListOptions {
                 mode,
-                status,
-                labels: label,
-                author,
-                category: filter_category,
-                sort,
                 interactive: InteractiveArgs {
                     interactive,
                     no_interactive,
                 },
                 id,
                 file,
+                format,
+                with_body,
+                strict,
+                ..filter.into_options()
             })
         }
 
         Commands::Search {
             query,
             full_text,
+            regex,
+            fuzzy,
             interactive,
             no_interactive,
             closed,
+            with_body,
         } => commands::search(&SearchArgs {
             query,
             full_text,
+            mode: if regex {
+                SearchMode::Regex
+            } else if fuzzy {
+                SearchMode::Fuzzy
+            } else {
+                SearchMode::Plain
+            },
             interactive: InteractiveArgs {
                 interactive,
                 no_interactive,
             },
             closed,
+            format,
+            with_body,
+            strict,
         }),
 
         Commands::Update {
             id,
             file,
+            query,
+            stdin,
+            dry_run,
             title,
             label,
             remove_label,
+            assign,
+            unassign,
             category,
             remove_category,
+            priority,
+            remove_priority,
+            due,
+            remove_due,
+            set_fields,
+            unset_fields,
+            body,
+            body_file,
+            append_body,
         } => commands::update(UpdateArgs {
             id,
             file,
             title,
             labels: label,
             remove_labels: remove_label,
+            assign,
+            unassign,
             category,
             remove_category,
+            priority,
+            remove_priority,
+            due,
+            remove_due,
+            set_fields,
+            unset_fields,
+            body,
+            body_file,
+            append_body,
+            targets: BulkTargets {
+                query,
+                stdin,
+                dry_run,
+            },
+        }),
+
+        Commands::Close {
+            id,
+            file,
+            query,
+            stdin,
+            dry_run,
+        } => {
+            let targets = BulkTargets {
+                query,
+                stdin,
+                dry_run,
+            };
+            if targets.is_bulk() {
+                commands::bulk(&BulkOp::Close, id, file, &targets)
+            } else {
+                commands::execute_close(id, file)
+            }
+        }
+
+        Commands::Reopen {
+            id,
+            file,
+            query,
+            stdin,
+            dry_run,
+        } => {
+            let targets = BulkTargets {
+                query,
+                stdin,
+                dry_run,
+            };
+            if targets.is_bulk() {
+                commands::bulk(&BulkOp::Reopen, id, file, &targets)
+            } else {
+                commands::execute_reopen(id, file)
+            }
+        }
+
+        Commands::Comment { text, id, file } => commands::comment(&CommentArgs { id, file, text }),
+
+        Commands::Show { id, file, raw } => commands::show(&ShowArgs {
+            id,
+            file,
+            raw,
+            format,
         }),
 
-        Commands::Close { id, file } => commands::execute_close(id, file),
+        Commands::Status {
+            state,
+            id,
+            file,
+            force,
+        } => commands::status(&StatusArgs {
+            id,
+            file,
+            state,
+            force,
+        }),
 
-        Commands::Reopen { id, file } => commands::execute_reopen(id, file),
+        Commands::Link {
+            id,
+            file,
+            blocks,
+            blocked_by,
+            related,
+            remove,
+        } => commands::link(&LinkArgs {
+            id,
+            file,
+            blocks,
+            blocked_by,
+            related,
+            remove,
+        }),
 
         Commands::Attachments { action } => match action {
             AttachmentsAction::Add { id, file, sources } => {
@@ -797,6 +1819,55 @@ fn run() -> Result<()> {
             }
         },
 
+        Commands::Board {
+            by,
+            labels,
+            interactive,
+            no_interactive,
+        } => commands::board(&BoardArgs {
+            by,
+            labels,
+            interactive: InteractiveArgs {
+                interactive,
+                no_interactive,
+            },
+            format,
+            strict,
+        }),
+
+        Commands::View { action } => match action {
+            ViewAction::List => commands::view_list(format),
+        },
+
+        Commands::Export { filter, output } => commands::export(&ExportArgs {
+            filter: ListOptions {
+                format,
+                strict,
+                ..filter.into_options()
+            },
+            output,
+        }),
+
+        Commands::Import {
+            source,
+            file,
+            category,
+            label,
+            set_fields,
+            dry_run,
+        } => commands::import(&ImportArgs {
+            source,
+            file,
+            category,
+            labels: label,
+            fields: set_fields,
+            dry_run,
+        }),
+
+        Commands::Serve { stdio: _ } => commands::serve(),
+
+        Commands::Doctor { fix } => commands::doctor(&DoctorArgs { fix }),
+
         Commands::Setup { shell } => {
             let mut cmd = Cli::command();
             commands::setup(&mut cmd, shell)
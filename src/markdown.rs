@@ -0,0 +1,508 @@
+//! # Markdown Rendering
+//!
+//! Renders item bodies for display outside the editor. Markdown is parsed with
+//! `pulldown-cmark` into lines of styled spans, which can then be written as
+//! ANSI-styled text (`qs show`) or converted by other front ends. HTML
+//! (`qs export`) is rendered by `pulldown-cmark` directly.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt::Write as _;
+
+use owo_colors::OwoColorize;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Visual role of a piece of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    /// Regular text
+    Plain,
+    /// Headings and titles
+    Heading,
+    /// `**strong**` text and table headers
+    Strong,
+    /// `*emphasized*` text
+    Emphasis,
+    /// `~~struck~~` text
+    Strikethrough,
+    /// Inline code and code blocks
+    Code,
+    /// Link and image text
+    Link,
+    /// Decorations: list bullets, quote bars, rules, link targets, labels
+    Marker,
+    /// Text that needs attention (e.g. an overdue date)
+    Alert,
+    /// Search matches
+    Highlight,
+}
+
+/// A run of text with a single tone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub tone: Tone,
+}
+
+impl Span {
+    /// Creates a span with the given tone.
+    pub fn new(text: impl Into<String>, tone: Tone) -> Self {
+        Self {
+            text: text.into(),
+            tone,
+        }
+    }
+
+    /// Creates an unstyled span.
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::new(text, Tone::Plain)
+    }
+}
+
+/// A rendered line (no trailing newline).
+pub type Line = Vec<Span>;
+
+/// Markdown extensions enabled for item bodies.
+const OPTIONS: Options = Options::ENABLE_TABLES
+    .union(Options::ENABLE_STRIKETHROUGH)
+    .union(Options::ENABLE_TASKLISTS)
+    .union(Options::ENABLE_FOOTNOTES);
+
+/// Renders Markdown into styled lines.
+///
+/// Lines are not wrapped; `width` only sizes horizontal rules.
+pub fn render(markdown: &str, width: usize) -> Vec<Line> {
+    let mut renderer = Renderer::new(width);
+    for event in Parser::new_ext(markdown, OPTIONS) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+/// Writes lines as text, with ANSI styles when `color` is true.
+pub fn to_ansi(lines: &[Line], color: bool) -> String {
+    let mut out = String::new();
+    for line in lines {
+        for span in line {
+            if color {
+                let _ = write!(out, "{}", Styled(span));
+            } else {
+                out.push_str(&span.text);
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders Markdown as an HTML fragment.
+///
+/// Raw HTML in the Markdown is escaped and shown as text, so a body can never
+/// inject markup or scripts into the page.
+pub fn to_html(markdown: &str) -> String {
+    let events = Parser::new_ext(markdown, OPTIONS).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        event => event,
+    });
+    let mut out = String::new();
+    pulldown_cmark::html::push_html(&mut out, events);
+    out
+}
+
+/// Escapes text for use in HTML content and attribute values.
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Display adapter that applies a span's tone as ANSI styles.
+struct Styled<'a>(&'a Span);
+
+impl std::fmt::Display for Styled<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = &self.0.text;
+        match self.0.tone {
+            Tone::Plain => write!(f, "{text}"),
+            Tone::Heading => write!(f, "{}", text.bold().cyan()),
+            Tone::Strong => write!(f, "{}", text.bold()),
+            Tone::Emphasis => write!(f, "{}", text.italic()),
+            Tone::Strikethrough => write!(f, "{}", text.strikethrough()),
+            Tone::Code => write!(f, "{}", text.yellow()),
+            Tone::Link => write!(f, "{}", text.blue().underline()),
+            Tone::Marker => write!(f, "{}", text.dimmed()),
+            Tone::Alert => write!(f, "{}", text.red().bold()),
+            Tone::Highlight => write!(f, "{}", text.black().on_yellow()),
+        }
+    }
+}
+
+// =============================================================================
+// Renderer
+// =============================================================================
+
+/// Indentation per list level and for code blocks.
+const LIST_INDENT: &str = "  ";
+const CODE_INDENT: &str = "    ";
+
+/// Event-driven renderer state.
+struct Renderer {
+    width: usize,
+    lines: Vec<Line>,
+    current: Line,
+    /// Whether `current` has been started (prefix written)
+    line_open: bool,
+    /// Whether `current` holds only its prefix and list marker
+    line_fresh: bool,
+    tones: Vec<Tone>,
+    quote_depth: usize,
+    /// Open lists with their next number (`None` for bullet lists)
+    lists: Vec<Option<u64>>,
+    /// Open links: destination and the text seen so far
+    links: Vec<(String, String)>,
+    in_code_block: bool,
+    table_cell: usize,
+}
+
+impl Renderer {
+    const fn new(width: usize) -> Self {
+        Self {
+            width,
+            lines: Vec::new(),
+            current: Vec::new(),
+            line_open: false,
+            line_fresh: false,
+            tones: Vec::new(),
+            quote_depth: 0,
+            lists: Vec::new(),
+            links: Vec::new(),
+            in_code_block: false,
+            table_cell: 0,
+        }
+    }
+
+    fn finish(mut self) -> Vec<Line> {
+        self.finish_line();
+        while self.lines.last().is_some_and(Vec::is_empty) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => self.push(&code, Tone::Code),
+            Event::InlineMath(math) | Event::DisplayMath(math) => self.push(&math, Tone::Code),
+            Event::Html(html) | Event::InlineHtml(html) => self.text(&html),
+            Event::FootnoteReference(name) => self.push(&format!("[^{name}]"), Tone::Marker),
+            Event::SoftBreak | Event::HardBreak => self.finish_line(),
+            Event::Rule => {
+                self.block_start();
+                self.push(&"─".repeat(self.width), Tone::Marker);
+                self.finish_line();
+                self.blank_line();
+            }
+            Event::TaskListMarker(checked) => {
+                self.push(if checked { "[x] " } else { "[ ] " }, Tone::Marker);
+                self.line_fresh = true;
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock | Tag::DefinitionList | Tag::MetadataBlock(_) => {
+                self.block_start();
+            }
+            Tag::Heading { level, .. } => {
+                self.block_start();
+                self.push(&format!("{} ", "#".repeat(level as usize)), Tone::Heading);
+                self.tones.push(Tone::Heading);
+            }
+            Tag::BlockQuote(_) => {
+                self.block_start();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(_) => {
+                self.block_start();
+                self.in_code_block = true;
+            }
+            Tag::List(first) => {
+                self.block_start();
+                self.lists.push(first);
+            }
+            Tag::Item => self.start_item(),
+            Tag::DefinitionListTitle => {
+                self.block_start();
+                self.tones.push(Tone::Strong);
+            }
+            Tag::DefinitionListDefinition => {
+                self.block_start();
+                self.push(LIST_INDENT, Tone::Plain);
+                self.line_fresh = true;
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.block_start();
+                self.push(&format!("[^{name}]: "), Tone::Marker);
+                self.line_fresh = true;
+            }
+            Tag::Table(_) | Tag::TableRow => {
+                self.block_start();
+                self.table_cell = 0;
+            }
+            Tag::TableHead => {
+                self.block_start();
+                self.table_cell = 0;
+                self.tones.push(Tone::Strong);
+            }
+            Tag::TableCell => {
+                if self.table_cell > 0 {
+                    self.push(" │ ", Tone::Marker);
+                }
+                self.table_cell += 1;
+            }
+            Tag::Emphasis => self.tones.push(Tone::Emphasis),
+            Tag::Strong => self.tones.push(Tone::Strong),
+            Tag::Strikethrough => self.tones.push(Tone::Strikethrough),
+            Tag::Superscript | Tag::Subscript => self.tones.push(self.tone()),
+            Tag::Link { dest_url, .. } => {
+                self.tones.push(Tone::Link);
+                self.links.push((dest_url.to_string(), String::new()));
+            }
+            Tag::Image { dest_url, .. } => {
+                self.push("[image: ", Tone::Marker);
+                self.tones.push(Tone::Link);
+                self.links.push((dest_url.to_string(), String::new()));
+            }
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.finish_line();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.tones.pop();
+                self.finish_line();
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.finish_line();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.blank_line();
+            }
+            TagEnd::CodeBlock => {
+                self.finish_line();
+                self.in_code_block = false;
+                self.blank_line();
+            }
+            TagEnd::List(_) => {
+                self.finish_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Table => {
+                self.finish_line();
+                self.blank_line();
+            }
+            TagEnd::TableHead | TagEnd::DefinitionListTitle => {
+                self.tones.pop();
+                self.finish_line();
+            }
+            TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Superscript
+            | TagEnd::Subscript => {
+                self.tones.pop();
+            }
+            TagEnd::Link => self.end_link(""),
+            TagEnd::Image => self.end_link("]"),
+            TagEnd::TableCell => {}
+            TagEnd::HtmlBlock
+            | TagEnd::Item
+            | TagEnd::TableRow
+            | TagEnd::FootnoteDefinition
+            | TagEnd::DefinitionList
+            | TagEnd::DefinitionListDefinition
+            | TagEnd::MetadataBlock(_) => self.finish_line(),
+        }
+    }
+
+    fn start_item(&mut self) {
+        self.block_start();
+        let depth = self.lists.len().saturating_sub(1);
+        let marker = match self.lists.last_mut() {
+            Some(Some(number)) => {
+                let marker = format!("{number}. ");
+                *number += 1;
+                marker
+            }
+            _ => "• ".to_string(),
+        };
+        self.open_line(depth);
+        self.current.push(Span::new(marker, Tone::Marker));
+    }
+
+    /// Closes a link, appending its destination unless the text already shows it.
+    fn end_link(&mut self, suffix: &str) {
+        self.tones.pop();
+        let Some((url, text)) = self.links.pop() else {
+            return;
+        };
+        self.push(suffix, Tone::Marker);
+        if !url.is_empty() && text != url {
+            self.push(&format!(" ({url})"), Tone::Marker);
+        }
+    }
+
+    /// Current inline tone.
+    fn tone(&self) -> Tone {
+        self.tones.last().copied().unwrap_or(Tone::Plain)
+    }
+
+    /// Writes text in the current tone, starting new lines at newlines.
+    fn text(&mut self, text: &str) {
+        let tone = if self.in_code_block {
+            Tone::Code
+        } else {
+            self.tone()
+        };
+        for (i, piece) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.finish_line();
+            }
+            self.push(piece, tone);
+        }
+    }
+
+    /// Appends a span to the current line.
+    fn push(&mut self, text: &str, tone: Tone) {
+        if text.is_empty() {
+            return;
+        }
+        if !self.line_open {
+            self.open_line(self.lists.len());
+        }
+        self.line_fresh = false;
+        for (_, link_text) in &mut self.links {
+            link_text.push_str(text);
+        }
+        self.current.push(Span::new(text, tone));
+    }
+
+    /// Starts a line with quote bars and `indent` list levels.
+    fn open_line(&mut self, indent: usize) {
+        for _ in 0..self.quote_depth {
+            self.current.push(Span::new("│ ", Tone::Marker));
+        }
+        if indent > 0 {
+            self.current.push(Span::plain(LIST_INDENT.repeat(indent)));
+        }
+        if self.in_code_block {
+            self.current.push(Span::plain(CODE_INDENT));
+        }
+        self.line_open = true;
+        self.line_fresh = true;
+    }
+
+    /// Ends the current line, if one was started.
+    fn finish_line(&mut self) {
+        if self.line_open {
+            self.lines.push(std::mem::take(&mut self.current));
+            self.line_open = false;
+            self.line_fresh = false;
+        }
+    }
+
+    /// Moves to a new line before a block, unless the line only holds a marker.
+    fn block_start(&mut self) {
+        if self.line_open && !self.line_fresh {
+            self.finish_line();
+        }
+    }
+
+    /// Adds a single blank line between blocks.
+    fn blank_line(&mut self) {
+        self.finish_line();
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.lines.push(Vec::new());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(markdown: &str) -> String {
+        to_ansi(&render(markdown, 10), false)
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        let text = plain("# Title\n\nSome **bold** and *italic* text.\n\nSecond paragraph.");
+        assert_eq!(
+            text,
+            "# Title\n\nSome bold and italic text.\n\nSecond paragraph.\n"
+        );
+    }
+
+    #[test]
+    fn test_tones() {
+        let lines = render("Run `qs list` or see [docs](https://example.com).", 10);
+        let tones: Vec<_> = lines[0].iter().map(|s| (s.text.as_str(), s.tone)).collect();
+        assert!(tones.contains(&("qs list", Tone::Code)));
+        assert!(tones.contains(&("docs", Tone::Link)));
+        assert!(tones.contains(&(" (https://example.com)", Tone::Marker)));
+    }
+
+    #[test]
+    fn test_lists() {
+        let text = plain("- one\n- two\n  - nested\n\n1. first\n2. second\n\n- [x] done");
+        assert_eq!(
+            text,
+            "• one\n• two\n  • nested\n\n1. first\n2. second\n\n• [x] done\n"
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_and_quotes() {
+        let text = plain("```\nfn main() {}\n```\n\n> quoted\n> text\n\n---");
+        assert_eq!(text, "    fn main() {}\n\n│ quoted\n│ text\n\n──────────\n");
+    }
+
+    #[test]
+    fn test_autolinks_are_not_repeated() {
+        assert_eq!(plain("<https://example.com>"), "https://example.com\n");
+    }
+
+    #[test]
+    fn test_html_escapes_raw_html() {
+        let html = to_html("**bold** <script>alert(1)</script>\n\n<div>x</div>");
+        assert!(html.contains("<strong>bold</strong>"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("&lt;div&gt;"));
+        assert!(!html.contains("<script>"));
+        assert_eq!(escape_html(r#"a<b & "c"#), "a&lt;b &amp; &quot;c");
+    }
+}
@@ -0,0 +1,228 @@
+//! # MCP Server
+//!
+//! JSON-RPC 2.0 server behind `qs serve --stdio`, speaking the Model Context
+//! Protocol (MCP) so agents can work with items without shelling out.
+//!
+//! Messages are newline-delimited JSON on stdin and stdout; anything meant for
+//! humans (warnings, unparseable item files) goes to stderr. Besides the MCP
+//! methods (`initialize`, `ping`, `tools/list`, `tools/call`), every tool can
+//! be called directly as a JSON-RPC method of the same name, returning the
+//! tool's structured result.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+pub mod tools;
+
+use std::io::{BufRead, Write};
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+/// Protocol versions the server understands, newest first.
+pub const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Invalid JSON was received.
+const PARSE_ERROR: i64 = -32700;
+/// The message is not a valid request object.
+const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameters (including unknown tools).
+const INVALID_PARAMS: i64 = -32602;
+/// A tool called as a JSON-RPC method failed.
+const TOOL_ERROR: i64 = -32000;
+
+/// A JSON-RPC error response.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Reads requests line by line from `input` and writes responses to `output`
+/// until the input ends.
+pub fn serve(input: impl BufRead, mut output: impl Write) -> Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle(&line) {
+            writeln!(output, "{response}")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Handles one JSON-RPC message, returning the response line.
+///
+/// Notifications (messages without an `id`) get no response.
+pub fn handle(line: &str) -> Option<String> {
+    let message: Value = match serde_json::from_str(line) {
+        Ok(message) => message,
+        Err(e) => {
+            let error = RpcError::new(PARSE_ERROR, format!("Parse error: {e}"));
+            return Some(response(&Value::Null, Err(error)));
+        }
+    };
+
+    let id = message.get("id").cloned();
+    let method = message.get("method").and_then(Value::as_str);
+    let Some(method) = method.filter(|_| message.get("jsonrpc") == Some(&json!("2.0"))) else {
+        let error = RpcError::new(INVALID_REQUEST, "Invalid request");
+        return Some(response(&id.unwrap_or(Value::Null), Err(error)));
+    };
+
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+    let result = dispatch(method, params);
+    id.map(|id| response(&id, result))
+}
+
+/// Runs a method and returns its result.
+fn dispatch(method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "initialize" => Ok(initialize(&params)),
+        "ping" | "notifications/initialized" | "notifications/cancelled" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+        "tools/call" => call_tool(&params),
+        name => match tools::call(name, params) {
+            Some(Ok(result)) => Ok(result),
+            Some(Err(e)) => Err(RpcError::new(TOOL_ERROR, format!("{e:#}"))),
+            None => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {name}"),
+            )),
+        },
+    }
+}
+
+/// Answers `initialize` with the server's capabilities.
+///
+/// The client's protocol version is accepted if supported, otherwise the
+/// newest supported version is offered.
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = requested
+        .filter(|v| PROTOCOL_VERSIONS.contains(v))
+        .unwrap_or(PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": {
+            "name": "queuestack",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+/// Answers `tools/call`.
+///
+/// Tool failures are results with `isError` set, so the model sees the
+/// message; only unknown tools are protocol errors.
+fn call_tool(params: &Value) -> Result<Value, RpcError> {
+    let Some(name) = params.get("name").and_then(Value::as_str) else {
+        return Err(RpcError::new(INVALID_PARAMS, "Missing tool name"));
+    };
+    let arguments = params.get("arguments").cloned().unwrap_or(Value::Null);
+
+    match tools::call(name, arguments) {
+        Some(Ok(result)) => Ok(json!({
+            "content": [{ "type": "text", "text": result.to_string() }],
+            "structuredContent": result,
+            "isError": false,
+        })),
+        Some(Err(e)) => Ok(json!({
+            "content": [{ "type": "text", "text": format!("{e:#}") }],
+            "isError": true,
+        })),
+        None => Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("Unknown tool: {name}"),
+        )),
+    }
+}
+
+/// Builds a response line for a request.
+fn response(id: &Value, result: Result<Value, RpcError>) -> String {
+    let message = match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": error.code, "message": error.message },
+        }),
+    };
+    message.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(line: &str) -> Value {
+        serde_json::from_str(&handle(line).expect("should respond")).unwrap()
+    }
+
+    #[test]
+    fn test_initialize_negotiates_version() {
+        let response = reply(
+            r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2024-11-05"}}"#,
+        );
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+        assert_eq!(response["result"]["serverInfo"]["name"], "queuestack");
+
+        let response = reply(
+            r#"{"jsonrpc":"2.0","id":2,"method":"initialize","params":{"protocolVersion":"1999-01-01"}}"#,
+        );
+        assert_eq!(response["result"]["protocolVersion"], PROTOCOL_VERSIONS[0]);
+    }
+
+    #[test]
+    fn test_notifications_get_no_response() {
+        assert!(handle(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#).is_none());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(reply("{not json")["error"]["code"], PARSE_ERROR);
+        assert_eq!(
+            reply(r#"{"id":1,"method":"ping"}"#)["error"]["code"],
+            INVALID_REQUEST
+        );
+        assert_eq!(
+            reply(r#"{"jsonrpc":"2.0","id":"a","method":"nope"}"#)["error"]["code"],
+            METHOD_NOT_FOUND
+        );
+        let response =
+            reply(r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"nope"}}"#);
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_tools_list() {
+        let response = reply(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#);
+        let names: Vec<_> = response["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            ["list", "search", "show", "new", "update", "close", "comment", "attach"]
+        );
+    }
+}
@@ -0,0 +1,674 @@
+//! # MCP Tools
+//!
+//! The tools offered by `qs serve`: typed inputs, JSON schemas, and handlers
+//! built on the same command and storage functions as the CLI.
+//!
+//! Every tool loads the project config per call, so edits made with `qs` or
+//! an editor while the server runs are picked up. Item results use the
+//! records of `--format json`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    commands::{close, comment, list, new, search, update, ListOptions, SortBy, StatusFilter},
+    config::Config,
+    item::{rank::SearchMode, Item, Query, Status},
+    output::ItemRecord,
+    storage::{self, AttachmentResult, ItemRef},
+    ui,
+};
+
+/// Runs the tool `name` with JSON `arguments`.
+///
+/// Returns `None` if there is no such tool.
+pub fn call(name: &str, arguments: Value) -> Option<Result<Value>> {
+    let result = match name {
+        "list" => run(arguments, list_items),
+        "search" => run(arguments, search_items),
+        "show" => run(arguments, show_item),
+        "new" => run(arguments, new_item),
+        "update" => run(arguments, update_item),
+        "close" => run(arguments, close_item),
+        "comment" => run(arguments, comment_item),
+        "attach" => run(arguments, attach_item),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Parses the arguments, loads the config, and runs a tool.
+///
+/// Tools listing items report unparseable item files on stderr, as the CLI
+/// does.
+fn run<T: DeserializeOwned>(
+    arguments: Value,
+    tool: fn(&Config, T) -> Result<Value>,
+) -> Result<Value> {
+    // Tools without required inputs may be called without arguments
+    let arguments = if arguments.is_null() {
+        json!({})
+    } else {
+        arguments
+    };
+    let input =
+        serde_json::from_value(arguments).map_err(|e| anyhow::anyhow!("Invalid arguments: {e}"))?;
+
+    let config = Config::load()?;
+    tool(&config, input)
+}
+
+// =============================================================================
+// Inputs
+// =============================================================================
+
+/// Which items `list` returns.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum StatusInput {
+    #[default]
+    Open,
+    Closed,
+    All,
+}
+
+/// How `search` matches its query.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ModeInput {
+    #[default]
+    Plain,
+    Regex,
+    Fuzzy,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ListInput {
+    status: StatusInput,
+    labels: Vec<String>,
+    author: Option<String>,
+    assignees: Vec<String>,
+    category: Option<String>,
+    priorities: Vec<String>,
+    states: Vec<String>,
+    query: Option<String>,
+    view: Option<String>,
+    sort: Option<String>,
+    with_body: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SearchInput {
+    query: String,
+    #[serde(default)]
+    mode: ModeInput,
+    #[serde(default)]
+    full_text: bool,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    with_body: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IdInput {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NewInput {
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    assignees: Vec<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    priority: Option<String>,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    fields: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+// One field per `qs update` option
+#[allow(clippy::struct_excessive_bools)]
+struct UpdateInput {
+    id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    append_body: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    remove_labels: Vec<String>,
+    #[serde(default)]
+    assign: Vec<String>,
+    #[serde(default)]
+    unassign: Vec<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    remove_category: bool,
+    #[serde(default)]
+    priority: Option<String>,
+    #[serde(default)]
+    remove_priority: bool,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    remove_due: bool,
+    #[serde(default)]
+    fields: Map<String, Value>,
+    #[serde(default)]
+    unset_fields: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentInput {
+    id: String,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AttachInput {
+    id: String,
+    sources: Vec<String>,
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+fn list_items(config: &Config, input: ListInput) -> Result<Value> {
+    let sort = input
+        .sort
+        .as_deref()
+        .map(|sort| {
+            SortBy::from_str(sort, true).map_err(|_| anyhow::anyhow!("Unknown sort order '{sort}'"))
+        })
+        .transpose()?;
+    let filter = ListOptions {
+        status: match input.status {
+            StatusInput::Open => StatusFilter::Open,
+            StatusInput::Closed => StatusFilter::Closed,
+            StatusInput::All => StatusFilter::All,
+        },
+        labels: input.labels,
+        author: input.author,
+        assignees: input.assignees,
+        category: input.category,
+        priorities: input.priorities,
+        states: input.states,
+        query: input.query.as_deref().map(Query::parse).transpose()?,
+        view: input.view,
+        sort,
+        ..ListOptions::default()
+    };
+
+    let (items, failures) = list::filter_items(&filter, filter.view.as_deref(), config)?;
+    ui::print_parse_failures(config, &failures);
+    Ok(items_result(&items, config, input.with_body))
+}
+
+fn search_items(config: &Config, input: SearchInput) -> Result<Value> {
+    let args = search::SearchArgs {
+        query: input.query,
+        full_text: input.full_text,
+        mode: match input.mode {
+            ModeInput::Plain => SearchMode::Plain,
+            ModeInput::Regex => SearchMode::Regex,
+            ModeInput::Fuzzy => SearchMode::Fuzzy,
+        },
+        closed: input.closed,
+        ..search::SearchArgs::default()
+    };
+    let items = search::find_items(&args, config)?;
+    Ok(items_result(&items, config, input.with_body))
+}
+
+fn show_item(config: &Config, input: IdInput) -> Result<Value> {
+    let path = ItemRef::Id(input.id).resolve(config)?.path;
+    item_result(config, &path)
+}
+
+fn new_item(config: &Config, input: NewInput) -> Result<Value> {
+    let author = author(config)?;
+    let args = new::NewArgs {
+        title: Some(input.title),
+        labels: input.labels,
+        assignees: input.assignees,
+        category: input.category,
+        priority: input.priority,
+        due: input.due,
+        fields: field_pairs(input.fields)?,
+        ..new::NewArgs::default()
+    };
+    let (path, _) = new::create(config, &args, author, input.body)?;
+    item_result(config, &path)
+}
+
+fn update_item(config: &Config, input: UpdateInput) -> Result<Value> {
+    let path = ItemRef::Id(input.id).resolve(config)?.path;
+    let args = update::UpdateArgs {
+        title: input.title,
+        labels: input.labels,
+        remove_labels: input.remove_labels,
+        assign: input.assign,
+        unassign: input.unassign,
+        category: input.category,
+        remove_category: input.remove_category,
+        priority: input.priority,
+        remove_priority: input.remove_priority,
+        due: input.due,
+        remove_due: input.remove_due,
+        set_fields: field_pairs(input.fields)?,
+        unset_fields: input.unset_fields,
+        body: input.body,
+        append_body: input.append_body,
+        ..update::UpdateArgs::default()
+    };
+
+    let new_path = update::update(config, args, &path)?;
+    let mut result = item_result(config, new_path.as_deref().unwrap_or(&path))?;
+    result["changed"] = json!(new_path.is_some());
+    Ok(result)
+}
+
+fn close_item(config: &Config, input: IdInput) -> Result<Value> {
+    let storage::LoadedItem { path, mut item } = ItemRef::Id(input.id).resolve(config)?;
+    let new_path = close::close(config, &path, &mut item)?;
+
+    let dependents = close::find_dependents(config, &item);
+    let still_blocked: Vec<_> = dependents
+        .still_blocked
+        .iter()
+        .map(|(dependent, remaining)| json!({ "id": dependent.id(), "blocked_by": remaining }))
+        .collect();
+    let mut result = item_result(config, &new_path)?;
+    result["open_blockers"] = json!(dependents.open_blockers);
+    result["unblocked"] = json!(dependents
+        .unblocked
+        .iter()
+        .map(Item::id)
+        .collect::<Vec<_>>());
+    result["still_blocked"] = json!(still_blocked);
+    Ok(result)
+}
+
+fn comment_item(config: &Config, input: CommentInput) -> Result<Value> {
+    let loaded = ItemRef::Id(input.id).resolve(config)?;
+    let author = author(config)?;
+    let path = comment::add_comment(loaded, author, &input.text)?;
+    item_result(config, &path)
+}
+
+fn attach_item(config: &Config, input: AttachInput) -> Result<Value> {
+    if input.sources.is_empty() {
+        bail!("No files or URLs specified");
+    }
+    let storage::LoadedItem { path, mut item } = ItemRef::Id(input.id).resolve(config)?;
+    if item.status() == Status::Closed {
+        bail!("Cannot attach to a closed item. Use 'qs reopen' first.");
+    }
+
+    item.touch();
+    let mut added = Vec::new();
+    let mut not_found = Vec::new();
+    for result in storage::add_attachments(&mut item, &path, &input.sources)? {
+        match result {
+            AttachmentResult::UrlAdded(name)
+            | AttachmentResult::FileCopied { new_name: name, .. } => {
+                added.push(name);
+            }
+            AttachmentResult::FileNotFound(source) => not_found.push(source),
+        }
+    }
+    if added.is_empty() {
+        bail!("No attachments were added (all files not found)");
+    }
+
+    let mut result = item_result(config, &path)?;
+    result["added"] = json!(added);
+    result["not_found"] = json!(not_found);
+    Ok(result)
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Returns the configured user name; the server cannot prompt for one.
+fn author(config: &Config) -> Result<String> {
+    config.user_name().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No user name available. Set user_name in ~/.queuestack or configure git user.name"
+        )
+    })
+}
+
+/// Converts a JSON object of custom fields into `key=value` pairs.
+///
+/// Values are given as text, so they are validated like CLI values.
+fn field_pairs(fields: Map<String, Value>) -> Result<Vec<(String, String)>> {
+    fields
+        .into_iter()
+        .map(|(key, value)| {
+            let text = match value {
+                Value::String(text) => text,
+                Value::Number(_) | Value::Bool(_) => value.to_string(),
+                _ => bail!("Field '{key}' must be a string, number, or boolean"),
+            };
+            Ok((key, text))
+        })
+        .collect()
+}
+
+/// Builds `{"items": [...]}` from loaded items.
+fn items_result(items: &[Item], config: &Config, with_body: bool) -> Value {
+    let records: Vec<_> = items
+        .iter()
+        .map(|item| ItemRecord::new(item, config, with_body))
+        .collect();
+    json!({ "items": records })
+}
+
+/// Builds `{"item": {...}}` from the item file at `path`, with its body.
+fn item_result(config: &Config, path: &Path) -> Result<Value> {
+    let item = storage::load_from_file(path)?.item;
+    Ok(json!({ "item": ItemRecord::new(&item, config, true) }))
+}
+
+// =============================================================================
+// Definitions
+// =============================================================================
+
+/// Returns the `tools/list` entries: name, description, and schemas.
+// One entry per tool
+#[allow(clippy::too_many_lines)]
+pub fn definitions() -> Vec<Value> {
+    let id = json!({ "type": "string", "description": "Item ID or unique ID prefix" });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    let fields = json!({
+        "type": "object",
+        "description": "Custom frontmatter fields, validated against the project schema",
+        "additionalProperties": { "type": ["string", "number", "boolean"] },
+    });
+
+    vec![
+        tool(
+            "list",
+            "List items with filters. Returns open items unless status is given.",
+            &json!({
+                "status": { "type": "string", "enum": ["open", "closed", "all"] },
+                "labels": { "type": "array", "items": { "type": "string" }, "description": "Labels (AND logic)" },
+                "author": { "type": "string", "description": "Author name (substring match)" },
+                "assignees": { "type": "array", "items": { "type": "string" }, "description": "Assignees (OR logic, 'none' for unassigned)" },
+                "category": { "type": "string" },
+                "priorities": { "type": "array", "items": { "type": "string" }, "description": "Priorities (OR logic, 'none' for unprioritized)" },
+                "states": { "type": "array", "items": { "type": "string" }, "description": "Workflow states (OR logic)" },
+                "query": { "type": "string", "description": "Query expression, e.g. 'label:bug AND NOT label:wontfix'" },
+                "view": { "type": "string", "description": "Saved view to start from" },
+                "sort": { "type": "string", "enum": ["id", "date", "updated", "title", "priority", "due"] },
+                "with_body": { "type": "boolean", "description": "Include item bodies" },
+            }),
+            &[],
+            &items_schema(),
+        ),
+        tool(
+            "search",
+            "Search items by title and ID (or full text), best match first.",
+            &json!({
+                "query": { "type": "string", "description": "Query expression, regex, or fuzzy pattern" },
+                "mode": { "type": "string", "enum": ["plain", "regex", "fuzzy"] },
+                "full_text": { "type": "boolean", "description": "Also search item bodies" },
+                "closed": { "type": "boolean", "description": "Search closed items instead of open ones" },
+                "with_body": { "type": "boolean", "description": "Include item bodies" },
+            }),
+            &["query"],
+            &items_schema(),
+        ),
+        tool(
+            "show",
+            "Show one item with its body and comments.",
+            &json!({ "id": id }),
+            &["id"],
+            &item_schema(&json!({})),
+        ),
+        tool(
+            "new",
+            "Create an item. The author is the configured user name.",
+            &json!({
+                "title": { "type": "string" },
+                "body": { "type": "string", "description": "Markdown description" },
+                "labels": strings,
+                "assignees": strings,
+                "category": { "type": "string" },
+                "priority": { "type": "string" },
+                "due": { "type": "string", "description": "Due date (YYYY-MM-DD, +3d, friday, ...)" },
+                "fields": fields,
+            }),
+            &["title"],
+            &item_schema(&json!({})),
+        ),
+        tool(
+            "update",
+            "Update an item. Only the given changes are applied.",
+            &json!({
+                "id": id,
+                "title": { "type": "string" },
+                "body": { "type": "string", "description": "New description (comments are kept)" },
+                "append_body": { "type": "string", "description": "Text to append to the description" },
+                "labels": { "type": "array", "items": { "type": "string" }, "description": "Labels to add" },
+                "remove_labels": strings,
+                "assign": strings,
+                "unassign": strings,
+                "category": { "type": "string", "description": "Category to move the item to" },
+                "remove_category": { "type": "boolean" },
+                "priority": { "type": "string" },
+                "remove_priority": { "type": "boolean" },
+                "due": { "type": "string", "description": "Due date (YYYY-MM-DD, +3d, friday, ...)" },
+                "remove_due": { "type": "boolean" },
+                "fields": fields,
+                "unset_fields": strings,
+            }),
+            &["id"],
+            &item_schema(&json!({
+                "changed": { "type": "boolean", "description": "False if there was nothing to change" },
+            })),
+        ),
+        tool(
+            "close",
+            "Close an item and move it to the archive. Reports open items it unblocked or that are still blocked.",
+            &json!({ "id": id }),
+            &["id"],
+            &item_schema(&json!({
+                "open_blockers": { "type": "array", "items": { "type": "string" }, "description": "Open items the closed item was still blocked by" },
+                "unblocked": { "type": "array", "items": { "type": "string" }, "description": "Open items no longer blocked by anything" },
+                "still_blocked": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "id": { "type": "string" }, "blocked_by": strings },
+                    },
+                    "description": "Open dependents with the open items they are still blocked by",
+                },
+            })),
+        ),
+        tool(
+            "comment",
+            "Add a comment by the configured user name to an item.",
+            &json!({
+                "id": id,
+                "text": { "type": "string", "description": "Markdown comment text" },
+            }),
+            &["id", "text"],
+            &item_schema(&json!({})),
+        ),
+        tool(
+            "attach",
+            "Attach files (copied next to the item) or URLs to an open item.",
+            &json!({
+                "id": id,
+                "sources": { "type": "array", "items": { "type": "string" }, "description": "File paths or URLs" },
+            }),
+            &["id", "sources"],
+            &item_schema(&json!({
+                "added": { "type": "array", "items": { "type": "string" }, "description": "Added attachment names and URLs" },
+                "not_found": { "type": "array", "items": { "type": "string" }, "description": "Files that do not exist" },
+            })),
+        ),
+    ]
+}
+
+/// Builds one tool definition.
+fn tool(
+    name: &str,
+    description: &str,
+    properties: &Value,
+    required: &[&str],
+    output_schema: &Value,
+) -> Value {
+    json!({
+        "name": name,
+        "description": description,
+        "inputSchema": {
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        },
+        "outputSchema": output_schema,
+    })
+}
+
+/// Schema of an item record (see `ItemRecord`).
+fn record_schema() -> Value {
+    let string = json!({ "type": "string" });
+    let nullable = json!({ "type": ["string", "null"] });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    json!({
+        "type": "object",
+        "properties": {
+            "id": string,
+            "title": string,
+            "author": string,
+            "created_at": string,
+            "updated_at": nullable,
+            "closed_at": nullable,
+            "status": { "type": "string", "enum": ["open", "closed"] },
+            "state": nullable,
+            "labels": strings,
+            "assignees": strings,
+            "attachments": strings,
+            "priority": nullable,
+            "due": nullable,
+            "blocks": strings,
+            "blocked_by": strings,
+            "related": strings,
+            "fields": { "type": "object" },
+            "comments": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": { "author": string, "created_at": string, "text": string },
+                },
+            },
+            "category": nullable,
+            "path": string,
+            "body": string,
+        },
+        "required": ["id", "title", "status", "path"],
+    })
+}
+
+/// Output schema of tools returning a list of items.
+fn items_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": { "items": { "type": "array", "items": record_schema() } },
+        "required": ["items"],
+    })
+}
+
+/// Output schema of tools returning one item, plus `extra` properties.
+fn item_schema(extra: &Value) -> Value {
+    let mut properties = json!({ "item": record_schema() });
+    if let (Some(properties), Some(extra)) = (properties.as_object_mut(), extra.as_object()) {
+        properties.extend(extra.clone());
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": ["item"],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_pairs() {
+        let fields = json!({ "sprint": 12, "team": "core", "billable": true });
+        let Value::Object(fields) = fields else {
+            unreachable!()
+        };
+        assert_eq!(
+            field_pairs(fields).unwrap(),
+            [
+                ("billable".to_string(), "true".to_string()),
+                ("sprint".to_string(), "12".to_string()),
+                ("team".to_string(), "core".to_string()),
+            ]
+        );
+
+        let Value::Object(fields) = json!({ "tags": ["a"] }) else {
+            unreachable!()
+        };
+        assert!(field_pairs(fields).is_err());
+    }
+
+    #[test]
+    fn test_unknown_arguments_are_rejected() {
+        let result = call("show", json!({ "id": "1", "verbose": true })).unwrap();
+        assert!(result.unwrap_err().to_string().contains("unknown field"));
+        assert!(call("nope", Value::Null).is_none());
+    }
+
+    #[test]
+    fn test_definitions_are_consistent() {
+        for definition in definitions() {
+            let schema = &definition["inputSchema"];
+            for name in schema["required"].as_array().unwrap() {
+                let name = name.as_str().unwrap();
+                assert!(
+                    schema["properties"].get(name).is_some(),
+                    "{}: required '{name}' is not a property",
+                    definition["name"]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! # Machine-Readable Output
+//!
+//! JSON and JSON Lines output for listing commands. The record types in this
+//! module define the documented output schema (see README): fields may be
+//! added, but existing fields must not be renamed or removed.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::{
+    config::Config,
+    item::{is_url, Comment, Item, Status},
+    storage,
+};
+
+/// Output format for listing commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// A single pretty-printed JSON document
+    Json,
+    /// One compact JSON object per line
+    Jsonl,
+    /// Comma-separated values (`qs export` only)
+    Csv,
+    /// A self-contained HTML report (`qs export` only)
+    Html,
+}
+
+impl OutputFormat {
+    /// Returns true for JSON-based formats.
+    ///
+    /// Machine-readable formats never launch the TUI.
+    pub const fn is_machine(self) -> bool {
+        matches!(self, Self::Json | Self::Jsonl)
+    }
+
+    /// Returns true for formats only `qs export` can write.
+    pub const fn is_export_only(self) -> bool {
+        matches!(self, Self::Csv | Self::Html)
+    }
+}
+
+// =============================================================================
+// Records
+// =============================================================================
+
+/// A single item as emitted by `--format json|jsonl`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemRecord {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    /// Last modification time (`null` if never changed)
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Closing time (`null` unless closed)
+    pub closed_at: Option<DateTime<Utc>>,
+    pub status: Status,
+    /// Workflow state (`null` when the project has no workflow)
+    pub state: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub attachments: Vec<String>,
+    /// Priority level (`null` when unset)
+    pub priority: Option<String>,
+    /// Due date as `YYYY-MM-DD` (`null` when unset)
+    pub due: Option<NaiveDate>,
+    /// IDs of items this item blocks
+    pub blocks: Vec<String>,
+    /// IDs of items blocking this item
+    pub blocked_by: Vec<String>,
+    /// IDs of related items
+    pub related: Vec<String>,
+    /// Custom frontmatter fields, in file order
+    pub fields: serde_yml::Mapping,
+    /// Comments from the body's comments section, oldest first
+    pub comments: Vec<CommentRecord>,
+    /// Category derived from the item's directory (`null` when uncategorized)
+    pub category: Option<String>,
+    /// Path relative to the project root
+    pub path: String,
+    /// Markdown body (only present when requested)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl ItemRecord {
+    /// Builds a record from an item, deriving category and relative path.
+    pub fn new(item: &Item, config: &Config, include_body: bool) -> Self {
+        let fm = &item.frontmatter;
+        let category = item
+            .path
+            .as_ref()
+            .and_then(|p| storage::derive_category(config, p));
+        let path = item
+            .path
+            .as_ref()
+            .map(|p| config.relative_path(p).display().to_string())
+            .unwrap_or_default();
+
+        Self {
+            id: fm.id.clone(),
+            title: fm.title.clone(),
+            author: fm.author.clone(),
+            created_at: fm.created_at,
+            updated_at: fm.updated_at,
+            closed_at: fm.closed_at,
+            status: fm.status,
+            state: (config.has_workflow() && fm.status != Status::Template)
+                .then(|| config.display_state(item)),
+            labels: fm.labels.clone(),
+            assignees: fm.assignees.clone(),
+            attachments: fm.attachments.clone(),
+            priority: fm.priority.clone(),
+            due: fm.due,
+            blocks: fm.blocks.clone(),
+            blocked_by: fm.blocked_by.clone(),
+            related: fm.related.clone(),
+            fields: fm.extra.clone(),
+            comments: item
+                .comments()
+                .into_iter()
+                .map(CommentRecord::from)
+                .collect(),
+            category,
+            path,
+            body: include_body.then(|| item.body.clone()),
+        }
+    }
+}
+
+/// A comment on an item.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentRecord {
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    /// Comment text (Markdown)
+    pub text: String,
+}
+
+impl From<Comment> for CommentRecord {
+    fn from(comment: Comment) -> Self {
+        Self {
+            author: comment.author,
+            created_at: comment.created_at,
+            text: comment.text,
+        }
+    }
+}
+
+/// A label or category with its number of open items.
+#[derive(Debug, Clone, Serialize)]
+pub struct CountRecord {
+    /// Label or category name (`null` for uncategorized items)
+    pub name: Option<String>,
+    /// Number of open items
+    pub count: usize,
+}
+
+/// A board column with its items, as emitted by `qs board`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnRecord {
+    /// Workflow state, label, or category of the column
+    pub name: String,
+    /// Items in the column, ordered by ID
+    pub items: Vec<ItemRecord>,
+}
+
+/// A single attachment of an item.
+#[derive(Debug, Clone, Serialize)]
+pub struct AttachmentRecord {
+    /// 1-based index (as used by `qs attachments remove`)
+    pub index: usize,
+    /// Attachment filename or URL
+    pub attachment: String,
+    /// Either `"file"` or `"url"`
+    pub kind: &'static str,
+}
+
+impl AttachmentRecord {
+    /// Builds attachment records for all attachments of an item.
+    pub fn for_item(item: &Item) -> Vec<Self> {
+        item.attachments()
+            .iter()
+            .enumerate()
+            .map(|(i, attachment)| Self {
+                index: i + 1,
+                attachment: attachment.clone(),
+                kind: if is_url(attachment) { "url" } else { "file" },
+            })
+            .collect()
+    }
+}
+
+// =============================================================================
+// Printing
+// =============================================================================
+
+/// Prints a list of records to stdout.
+///
+/// `json` emits a single array, `jsonl` emits one object per line.
+pub fn print_records<T: Serialize>(format: OutputFormat, records: &[T]) -> Result<()> {
+    let mut out = io::stdout().lock();
+    write_records(&mut out, format, records)
+}
+
+/// Prints a single record to stdout.
+///
+/// Both `json` and `jsonl` emit a single object.
+pub fn print_record<T: Serialize>(format: OutputFormat, record: &T) -> Result<()> {
+    let mut out = io::stdout().lock();
+    match format {
+        OutputFormat::Json => serde_json::to_writer_pretty(&mut out, record)?,
+        _ => serde_json::to_writer(&mut out, record)?,
+    }
+    writeln!(out).context("Failed to write output")
+}
+
+/// Writes a list of records to the given writer.
+pub fn write_records<W: Write, T: Serialize>(
+    out: &mut W,
+    format: OutputFormat,
+    records: &[T],
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)?;
+        }
+        _ => {
+            for record in records {
+                serde_json::to_writer(&mut *out, record)?;
+                writeln!(out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        n: u32,
+    }
+
+    #[test]
+    fn test_json_is_single_array() {
+        let mut buf = Vec::new();
+        write_records(
+            &mut buf,
+            OutputFormat::Json,
+            &[Sample { n: 1 }, Sample { n: 2 }],
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(value.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_jsonl_is_one_object_per_line() {
+        let mut buf = Vec::new();
+        write_records(
+            &mut buf,
+            OutputFormat::Jsonl,
+            &[Sample { n: 1 }, Sample { n: 2 }],
+        )
+        .unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines, vec![r#"{"n":1}"#, r#"{"n":2}"#]);
+    }
+
+    #[test]
+    fn test_empty_json_is_empty_array() {
+        let mut buf = Vec::new();
+        write_records::<_, Sample>(&mut buf, OutputFormat::Json, &[]).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap().trim(), "[]");
+    }
+
+    #[test]
+    fn test_is_machine() {
+        assert!(!OutputFormat::Text.is_machine());
+        assert!(OutputFormat::Json.is_machine());
+        assert!(OutputFormat::Jsonl.is_machine());
+        assert!(!OutputFormat::Csv.is_machine());
+        assert!(OutputFormat::Html.is_export_only());
+        assert!(!OutputFormat::Json.is_export_only());
+    }
+}
@@ -0,0 +1,465 @@
+//! # Item Index
+//!
+//! Optional on-disk cache of parsed items, enabled with `index = true` in the
+//! project config and stored as `.index.json` in the stack directory.
+//!
+//! Entries are keyed by the file path relative to the stack directory and
+//! remember the file's modification time and size; a file whose metadata
+//! changed is parsed again and its entry replaced. Besides frontmatter and
+//! body, the index keeps a token index (lowercase words of titles, IDs, and
+//! bodies) used to skip files that cannot match a full-text search.
+//!
+//! The index is only a cache: it is rewritten when something changed, unreadable
+//! or outdated files are ignored, and deleting it is always safe. Creating it
+//! also adds it to a `.gitignore` in the stack directory.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_yml::Value;
+
+use super::ParseFailure;
+use crate::{
+    config::Config,
+    item::{Frontmatter, Item},
+};
+
+/// Format version; files with another version are rebuilt from scratch.
+const INDEX_VERSION: u32 = 1;
+
+/// The item index of a project.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Index {
+    version: u32,
+    /// Cached items by path relative to the stack directory
+    entries: BTreeMap<String, Entry>,
+    /// Lowercase word to the keys of entries containing it
+    tokens: BTreeMap<String, BTreeSet<String>>,
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(skip)]
+    stack_path: PathBuf,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+/// A cached item with the file metadata it was parsed from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// Modification time (seconds and nanoseconds since the Unix epoch)
+    modified: (u64, u32),
+    size: u64,
+    frontmatter: Frontmatter,
+    key_order: Vec<String>,
+    body: String,
+}
+
+impl Index {
+    /// Opens the index of a project, or returns `None` when it is disabled.
+    ///
+    /// A missing, unreadable, or outdated index file yields an empty index.
+    pub fn open(config: &Config) -> Option<Self> {
+        if !config.use_index() {
+            return None;
+        }
+
+        let path = config.index_path();
+        let mut index = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|index| index.version == INDEX_VERSION)
+            .unwrap_or_else(|| Self {
+                version: INDEX_VERSION,
+                ..Self::default()
+            });
+        index.path = path;
+        index.stack_path = config.stack_path();
+        Some(index)
+    }
+
+    /// Loads items, reusing cached entries for unchanged files.
+    ///
+    /// Files that fail to parse are skipped (and not cached) and returned
+    /// as failures.
+    pub fn load(
+        &mut self,
+        paths: impl IntoIterator<Item = PathBuf>,
+    ) -> (Vec<Item>, Vec<ParseFailure>) {
+        let mut items = Vec::new();
+        let mut failures = Vec::new();
+        for path in paths {
+            match self.load_one(path) {
+                Some(Ok(item)) => items.push(item),
+                Some(Err(failure)) => failures.push(failure),
+                None => {}
+            }
+        }
+        (items, failures)
+    }
+
+    /// Loads a single item through the cache.
+    ///
+    /// Returns `None` if the file no longer exists.
+    fn load_one(&mut self, path: PathBuf) -> Option<Result<Item, ParseFailure>> {
+        let key = self.key(&path);
+        let Some((modified, size)) = file_stamp(&path) else {
+            self.remove(&key);
+            return None;
+        };
+
+        if let Some(entry) = self.entries.get(&key) {
+            if entry.modified == modified && entry.size == size {
+                let mut frontmatter = entry.frontmatter.clone();
+                frontmatter.key_order.clone_from(&entry.key_order);
+                return Some(Ok(Item {
+                    frontmatter,
+                    body: entry.body.clone(),
+                    path: Some(path),
+                }));
+            }
+        }
+
+        self.remove(&key);
+        let item = match Item::load(&path) {
+            Ok(item) => item,
+            Err(err) => return Some(Err(ParseFailure::new(&path, &err))),
+        };
+        if is_cacheable(&item.frontmatter) {
+            let entry = Entry {
+                modified,
+                size,
+                frontmatter: item.frontmatter.clone(),
+                key_order: item.frontmatter.key_order.clone(),
+                body: item.body.clone(),
+            };
+            for token in entry_tokens(&entry) {
+                self.tokens.entry(token).or_default().insert(key.clone());
+            }
+            self.entries.insert(key, entry);
+            self.dirty = true;
+        }
+        Some(Ok(item))
+    }
+
+    /// Drops an entry and its tokens.
+    fn remove(&mut self, key: &str) {
+        let Some(entry) = self.entries.remove(key) else {
+            return;
+        };
+        for token in entry_tokens(&entry) {
+            if let Some(keys) = self.tokens.get_mut(&token) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.tokens.remove(&token);
+                }
+            }
+        }
+        self.dirty = true;
+    }
+
+    /// Keeps only the paths whose cached text can contain all `words`.
+    ///
+    /// A word can only occur in a file if its longest alphanumeric run is part
+    /// of one of the file's tokens. Paths without an up-to-date entry are kept,
+    /// so the result is never missing a match.
+    pub fn narrow(&self, paths: Vec<PathBuf>, words: &[&str]) -> Vec<PathBuf> {
+        let required: Vec<HashSet<&str>> = words
+            .iter()
+            .filter_map(|word| {
+                let longest = tokenize(word).max_by_key(String::len)?;
+                Some(
+                    self.tokens
+                        .iter()
+                        .filter(|(token, _)| token.contains(&longest))
+                        .flat_map(|(_, keys)| keys.iter().map(String::as_str))
+                        .collect(),
+                )
+            })
+            .collect();
+        if required.is_empty() {
+            return paths;
+        }
+
+        paths
+            .into_iter()
+            .filter(|path| {
+                let key = self.key(path);
+                let fresh = file_stamp(path).is_some_and(|(modified, size)| {
+                    self.entries
+                        .get(&key)
+                        .is_some_and(|e| e.modified == modified && e.size == size)
+                });
+                !fresh || required.iter().all(|keys| keys.contains(key.as_str()))
+            })
+            .collect()
+    }
+
+    /// Writes the index back to disk if anything changed.
+    ///
+    /// Entries of deleted files are dropped first. Write errors are ignored:
+    /// the index is only a cache.
+    pub fn save(&mut self) {
+        let deleted: Vec<String> = self
+            .entries
+            .keys()
+            .filter(|key| !self.stack_path.join(key).is_file())
+            .cloned()
+            .collect();
+        for key in &deleted {
+            self.remove(key);
+        }
+        if !self.dirty {
+            return;
+        }
+
+        let Ok(content) = serde_json::to_string(self) else {
+            return;
+        };
+        if !self.path.exists() {
+            add_to_gitignore(&self.path);
+        }
+        // Write to a temporary file first so readers never see a partial index
+        let temp = self.path.with_extension("json.tmp");
+        if fs::write(&temp, content).is_ok() && fs::rename(&temp, &self.path).is_ok() {
+            self.dirty = false;
+        } else {
+            let _ = fs::remove_file(&temp);
+        }
+    }
+
+    /// Returns the entry key of a path (relative to the stack directory).
+    fn key(&self, path: &Path) -> String {
+        path.strip_prefix(&self.stack_path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Adds a file to the `.gitignore` in its directory, keeping existing entries.
+///
+/// Write errors are ignored, like those of the index itself.
+fn add_to_gitignore(path: &Path) {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name().and_then(|n| n.to_str())) else {
+        return;
+    };
+    let gitignore = dir.join(".gitignore");
+    let content = fs::read_to_string(&gitignore).unwrap_or_default();
+    if content.lines().any(|line| line.trim() == name) {
+        return;
+    }
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    let _ = fs::write(&gitignore, format!("{content}{separator}{name}\n"));
+}
+
+/// Returns the modification time and size of a file.
+fn file_stamp(path: &Path) -> Option<((u64, u32), u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((
+        (modified.as_secs(), modified.subsec_nanos()),
+        metadata.len(),
+    ))
+}
+
+/// Splits text into lowercase alphanumeric words.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Returns the distinct tokens of an entry's title, ID, and body.
+fn entry_tokens(entry: &Entry) -> BTreeSet<String> {
+    tokenize(&entry.frontmatter.title)
+        .chain(tokenize(&entry.frontmatter.id))
+        .chain(tokenize(&entry.body))
+        .collect()
+}
+
+/// Returns true if the frontmatter survives a round-trip through JSON.
+///
+/// Custom fields with YAML tags or non-finite numbers are not cached; such
+/// items are simply parsed every time.
+fn is_cacheable(frontmatter: &Frontmatter) -> bool {
+    fn json_safe(value: &Value) -> bool {
+        match value {
+            Value::Null | Value::Bool(_) | Value::String(_) => true,
+            Value::Number(n) => n.as_f64().is_finite(),
+            Value::Sequence(items) => items.iter().all(json_safe),
+            Value::Mapping(map) => map.values().all(json_safe),
+            Value::Tagged(_) => false,
+        }
+    }
+
+    frontmatter.extra.values().all(json_safe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::parser;
+
+    const CONTENT: &str = "---
+id: 260101-AAA
+title: Login crash
+author: Test
+created_at: 2026-01-09T12:00:00Z
+status: open
+estimate: 3
+labels:
+- bug
+sprint: Q1
+---
+
+The app crashes on submit.
+";
+
+    fn index_in(dir: &Path) -> Index {
+        Index {
+            version: INDEX_VERSION,
+            path: dir.join(".index.json"),
+            stack_path: dir.to_path_buf(),
+            ..Index::default()
+        }
+    }
+
+    fn reopen(dir: &Path) -> Index {
+        let content = fs::read_to_string(dir.join(".index.json")).unwrap();
+        let mut index: Index = serde_json::from_str(&content).unwrap();
+        index.path = dir.join(".index.json");
+        index.stack_path = dir.to_path_buf();
+        index
+    }
+
+    #[test]
+    fn test_cached_items_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("260101-AAA-login-crash.md");
+        fs::write(&path, CONTENT).unwrap();
+
+        let mut index = index_in(dir.path());
+        let loaded = index.load([path.clone()]).0.remove(0);
+        index.save();
+
+        let mut index = reopen(dir.path());
+        assert_eq!(index.entries.len(), 1);
+        let cached = index.load([path]).0.remove(0);
+        assert!(!index.dirty, "unchanged file should come from the cache");
+
+        // Serializes exactly like the parsed item, including custom key order
+        assert_eq!(
+            parser::serialize(&cached.frontmatter, &cached.body).unwrap(),
+            parser::serialize(&loaded.frontmatter, &loaded.body).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_changed_and_deleted_files_are_refreshed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("260101-AAA-login-crash.md");
+        fs::write(&path, CONTENT).unwrap();
+
+        let mut index = index_in(dir.path());
+        index.load([path.clone()]);
+        index.save();
+
+        fs::write(&path, CONTENT.replace("Login crash", "Logout crash!")).unwrap();
+        let mut index = reopen(dir.path());
+        assert_eq!(index.load([path.clone()]).0[0].title(), "Logout crash!");
+        assert!(index.tokens.contains_key("logout"));
+        assert!(!index.tokens.contains_key("login"));
+        index.save();
+
+        fs::remove_file(&path).unwrap();
+        let mut index = reopen(dir.path());
+        assert!(index.load([path]).0.is_empty());
+        index.save();
+        assert!(reopen(dir.path()).entries.is_empty());
+        assert!(reopen(dir.path()).tokens.is_empty());
+    }
+
+    #[test]
+    fn test_narrow_by_tokens() {
+        let dir = tempfile::tempdir().unwrap();
+        let crash = dir.path().join("260101-AAA-login-crash.md");
+        let other = dir.path().join("260102-BBB-other.md");
+        fs::write(&crash, CONTENT).unwrap();
+        fs::write(
+            &other,
+            CONTENT
+                .replace("260101-AAA", "260102-BBB")
+                .replace("Login crash", "Other")
+                .replace("crashes", "works"),
+        )
+        .unwrap();
+
+        let paths = vec![crash, other];
+        let mut index = index_in(dir.path());
+        index.load(paths.clone());
+
+        assert_eq!(index.narrow(paths.clone(), &["rash"]), paths[..1]);
+        assert_eq!(index.narrow(paths.clone(), &["on sub"]), paths);
+        assert!(index.narrow(paths.clone(), &["login", "works"]).is_empty());
+        // Words without letters or digits don't narrow anything
+        assert_eq!(index.narrow(paths.clone(), &["--"]), paths);
+
+        // Files without a cached entry are always kept
+        let new = dir.path().join("260103-CCC-new.md");
+        fs::write(&new, CONTENT).unwrap();
+        assert_eq!(index.narrow(vec![new.clone()], &["missing"]), [new]);
+    }
+
+    #[test]
+    fn test_new_index_is_git_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("260101-AAA-login-crash.md");
+        fs::write(&path, CONTENT).unwrap();
+        fs::write(dir.path().join(".gitignore"), "*.tmp").unwrap();
+
+        let mut index = index_in(dir.path());
+        index.load([path.clone()]);
+        index.save();
+        fs::write(&path, CONTENT.replace("Login", "Logout")).unwrap();
+        index.load([path]);
+        index.save();
+
+        let gitignore = fs::read_to_string(dir.path().join(".gitignore")).unwrap();
+        assert_eq!(gitignore, "*.tmp\n.index.json\n");
+    }
+
+    #[test]
+    fn test_corrupt_index_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".index.json"), "{ not json").unwrap();
+        assert!(serde_json::from_str::<Index>("{ not json").is_err());
+
+        let path = dir.path().join("260101-AAA-login-crash.md");
+        fs::write(&path, CONTENT).unwrap();
+        let mut index = index_in(dir.path());
+        assert_eq!(index.load([path]).0.len(), 1);
+        index.save();
+        assert_eq!(reopen(dir.path()).entries.len(), 1);
+    }
+
+    #[test]
+    fn test_non_finite_numbers_are_not_cached() {
+        let (frontmatter, _) =
+            parser::parse(&CONTENT.replace("estimate: 3", "estimate: .nan")).unwrap();
+        assert!(!is_cacheable(&frontmatter));
+        let (frontmatter, _) = parser::parse(CONTENT).unwrap();
+        assert!(is_cacheable(&frontmatter));
+    }
+}
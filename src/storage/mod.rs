@@ -6,20 +6,29 @@
 //! Licensed under the MIT License.
 
 pub mod git;
+pub mod index;
 
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{bail, Context, Result};
 use walkdir::WalkDir;
 
+use self::index::Index;
+
 use crate::{
     config::Config,
     constants::{ATTACHMENT_INFIX, ITEM_FILE_EXTENSION},
     id,
-    item::{slugify, Item},
+    item::{slugify, Item, Status},
 };
 
 /// Walks markdown files in a directory with specified depth constraints.
+///
+/// Markdown attachments (`{id}-Attachment-...md`) are not items and are
+/// skipped.
 fn walk_markdown_files(
     path: PathBuf,
     min_depth: usize,
@@ -36,6 +45,11 @@ fn walk_markdown_files(
                 .extension()
                 .is_some_and(|ext| ext == ITEM_FILE_EXTENSION)
         })
+        .filter(|e| {
+            e.file_name()
+                .to_str()
+                .map_or(true, |name| AttachmentFileName::parse(name).is_none())
+        })
         .map(walkdir::DirEntry::into_path)
 }
 
@@ -65,15 +79,174 @@ pub fn walk_all(config: &Config) -> impl Iterator<Item = PathBuf> {
     walk_items(config).chain(walk_archived(config))
 }
 
+/// Walks every file in the queuestack directory: items, archived items,
+/// templates, and attachments.
+pub fn walk_stack_files(config: &Config) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(config.stack_path())
+        .min_depth(1)
+        .max_depth(3)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+}
+
 /// Loads all items (both active and archived) into memory.
 ///
-/// Silently skips items that fail to parse.
-pub fn load_all_items(config: &Config) -> Vec<Item> {
-    walk_all(config)
-        .filter_map(|path| Item::load(&path).ok())
+/// Returns the items and the files that failed to parse.
+pub fn load_all_items(config: &Config) -> (Vec<Item>, Vec<ParseFailure>) {
+    load_items(config, walk_all(config))
+}
+
+/// Loads items from the given paths, using the item index if enabled.
+///
+/// Returns the items and the files that failed to parse, which callers
+/// report (see [`check_parse_failures`]) or deliberately ignore.
+pub fn load_items(
+    config: &Config,
+    paths: impl IntoIterator<Item = PathBuf>,
+) -> (Vec<Item>, Vec<ParseFailure>) {
+    load_items_containing(config, paths, &[])
+}
+
+/// Like [`load_items`], but may skip files that cannot contain all `words`.
+///
+/// Without the item index every file is loaded; callers still have to check
+/// the returned items for matches.
+pub fn load_items_containing(
+    config: &Config,
+    paths: impl IntoIterator<Item = PathBuf>,
+    words: &[&str],
+) -> (Vec<Item>, Vec<ParseFailure>) {
+    let Some(mut index) = Index::open(config) else {
+        let mut items = Vec::new();
+        let mut failures = Vec::new();
+        for path in paths {
+            match Item::load(&path) {
+                Ok(item) => items.push(item),
+                Err(err) => failures.push(ParseFailure::new(&path, &err)),
+            }
+        }
+        return (items, failures);
+    };
+
+    let paths = index.narrow(paths.into_iter().collect(), words);
+    let loaded = index.load(paths);
+    index.save();
+    loaded
+}
+
+/// Collects the IDs of all open items (used to resolve `blocked_by` links).
+///
+/// Files that fail to parse are left out; listing commands report them.
+pub fn collect_open_ids(config: &Config) -> HashSet<String> {
+    load_items(config, walk_items(config))
+        .0
+        .into_iter()
+        .filter(|item| item.status() == Status::Open)
+        .map(|item| item.id().to_string())
         .collect()
 }
 
+// =============================================================================
+// Parse Failures
+// =============================================================================
+
+/// An item file that failed to parse while loading items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFailure {
+    pub path: PathBuf,
+    /// Error message (without the path)
+    pub message: String,
+    /// Line and column (1-based) of a YAML error in the frontmatter
+    pub location: Option<(usize, usize)>,
+}
+
+impl ParseFailure {
+    /// Creates a failure from an [`Item::load`] error.
+    ///
+    /// For YAML errors the message and location come from `serde_yml`, which
+    /// counts lines from the opening `---`, so they match the file's lines.
+    pub fn new(path: &Path, err: &anyhow::Error) -> Self {
+        let yaml = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<serde_yml::Error>());
+        let location = yaml
+            .and_then(serde_yml::Error::location)
+            .map(|location| (location.line(), location.column()));
+        let message = yaml.map_or_else(
+            || err.root_cause().to_string(),
+            |yaml| {
+                // "YAML parse error at line 3, column 9: <reason>"; the
+                // location is kept separately
+                let message = yaml.to_string();
+                message
+                    .split_once(" at line ")
+                    .and_then(|(_, rest)| rest.split_once(": "))
+                    .map_or_else(|| message.clone(), |(_, reason)| reason.to_string())
+            },
+        );
+        Self {
+            path: path.to_path_buf(),
+            message,
+            location,
+        }
+    }
+
+    /// Formats the failure as `path:line:column: message`, with the path
+    /// relative to the project root.
+    pub fn describe(&self, config: &Config) -> String {
+        let path = config.relative_path(&self.path);
+        match self.location {
+            Some((line, column)) => {
+                format!("{}:{line}:{column}: {}", path.display(), self.message)
+            }
+            None => format!("{}: {}", path.display(), self.message),
+        }
+    }
+}
+
+/// Returns the distinct failures sorted by path.
+///
+/// A command may load the same file more than once (e.g. all items, then
+/// the open ones), so each file is only reported once.
+pub fn distinct_parse_failures(failures: &[ParseFailure]) -> Vec<&ParseFailure> {
+    let mut failures: Vec<&ParseFailure> = failures.iter().collect();
+    failures.sort_by(|a, b| a.path.cmp(&b.path));
+    failures.dedup_by(|a, b| a.path == b.path);
+    failures
+}
+
+/// Fails if any file failed to parse, in strict mode.
+///
+/// `strict` is the `--strict` flag; the project's `strict` option enables
+/// strict mode as well. Listing commands call this after loading their items
+/// and before printing anything, so strict mode fails without partial output.
+pub fn check_parse_failures(
+    config: &Config,
+    strict: bool,
+    failures: &[ParseFailure],
+) -> Result<()> {
+    if !strict && !config.strict() {
+        return Ok(());
+    }
+    let failures = distinct_parse_failures(failures);
+    if failures.is_empty() {
+        return Ok(());
+    }
+    let lines: Vec<String> = failures
+        .iter()
+        .map(|failure| format!("  {}", failure.describe(config)))
+        .collect();
+    let count = failures.len();
+    bail!(
+        "{count} item file{} failed to parse (strict mode):\n{}",
+        if count == 1 { "" } else { "s" },
+        lines.join("\n")
+    );
+}
+
 /// An item loaded from disk along with its path.
 pub struct LoadedItem {
     /// The path to the item file
@@ -173,6 +346,18 @@ pub fn find_by_id(config: &Config, partial_id: &str) -> Result<PathBuf> {
     }
 }
 
+/// Finds an item by its full ID (case-insensitive), without partial matching.
+pub fn find_by_full_id(config: &Config, id: &str) -> Result<PathBuf> {
+    walk_all(config)
+        .find(|path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(crate::id::extract_from_filename)
+                .is_some_and(|found| found.eq_ignore_ascii_case(id))
+        })
+        .ok_or_else(|| anyhow::anyhow!("No item found with ID '{id}'"))
+}
+
 /// Extracts the slug portion from a filename.
 ///
 /// For a filename like `260116-188QYZ0-bug-report.md`, returns `"bug-report"`.
@@ -241,26 +426,20 @@ pub fn find_template(config: &Config, reference: &str) -> Result<PathBuf> {
         );
     }
 
-    // No ID match - try title match
-    let title_matches: Vec<_> = templates
-        .iter()
-        .filter(|path| {
-            Item::load(path)
-                .ok()
-                .is_some_and(|item| item.title().to_uppercase().contains(&ref_upper))
-        })
-        .cloned()
+    // No ID match - try title match (loading each template only once)
+    let title_matches: Vec<_> = load_items(config, templates.iter().cloned())
+        .0
+        .into_iter()
+        .filter(|item| item.title().to_uppercase().contains(&ref_upper))
         .collect();
 
     if title_matches.len() == 1 {
-        return Ok(title_matches.into_iter().next().unwrap());
+        let item = title_matches.into_iter().next().unwrap();
+        return item.path.context("Template has no path");
     }
 
     if title_matches.len() > 1 {
-        let titles: Vec<_> = title_matches
-            .iter()
-            .filter_map(|p| Item::load(p).ok().map(|item| item.title().to_string()))
-            .collect();
+        let titles: Vec<_> = title_matches.iter().map(Item::title).collect();
         bail!(
             "Multiple templates match title '{reference}':\n  {}",
             titles.join("\n  ")
@@ -436,6 +615,18 @@ pub fn archive_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String
     move_item_to_dir(config, path, &dest_dir)
 }
 
+/// Moves an item file to the system trash with the `trash` command.
+pub fn trash_item(path: &Path) -> Result<()> {
+    let status = std::process::Command::new("trash")
+        .arg(path)
+        .status()
+        .context("Failed to execute trash command")?;
+    if !status.success() {
+        bail!("Failed to move item to trash");
+    }
+    Ok(())
+}
+
 /// Moves an item from the archive back to queuestack.
 ///
 /// Derives category from archive path structure and restores to same category.
@@ -632,6 +823,34 @@ pub fn process_attachment(
     })
 }
 
+/// Processes attachment sources for the item at `path` and saves it.
+///
+/// Returns one `AttachmentResult` per source, in order.
+pub fn add_attachments(
+    item: &mut crate::item::Item,
+    path: &Path,
+    sources: &[String],
+) -> Result<Vec<AttachmentResult>> {
+    // Set path so attachment_dir() works
+    item.path = Some(path.to_path_buf());
+
+    let item_dir = item
+        .attachment_dir()
+        .ok_or_else(|| anyhow::anyhow!("Invalid item path"))?
+        .to_path_buf();
+    let item_id = item.id().to_string();
+
+    let results = sources
+        .iter()
+        .map(|source| process_attachment(source, item, &item_dir, &item_id))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Save updated item with attachments
+    item.save(path)?;
+
+    Ok(results)
+}
+
 /// Copies a file as an attachment to the item's directory.
 ///
 /// Returns the new filename using the standard attachment naming convention.
@@ -0,0 +1,43 @@
+//! Converts rendered Markdown into ratatui text.
+//!
+//! `crate::markdown` produces terminal-independent lines of toned spans; this
+//! module maps each tone to a ratatui style so TUI screens can display item
+//! bodies and comments the same way `qs show` does.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::markdown::{self, Tone};
+
+/// Returns the style for a tone.
+pub fn tone_style(tone: Tone) -> Style {
+    let style = Style::default();
+    match tone {
+        Tone::Plain => style,
+        Tone::Heading => style.fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Tone::Strong => style.add_modifier(Modifier::BOLD),
+        Tone::Emphasis => style.add_modifier(Modifier::ITALIC),
+        Tone::Strikethrough => style.add_modifier(Modifier::CROSSED_OUT),
+        Tone::Code => style.fg(Color::Yellow),
+        Tone::Link => style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED),
+        Tone::Marker => style.fg(Color::DarkGray),
+        Tone::Alert => style.fg(Color::Red).add_modifier(Modifier::BOLD),
+        Tone::Highlight => style.fg(Color::Black).bg(Color::Yellow),
+    }
+}
+
+/// Converts rendered lines into ratatui lines.
+pub fn to_lines(lines: &[markdown::Line]) -> Vec<Line<'static>> {
+    lines
+        .iter()
+        .map(|line| {
+            Line::from(
+                line.iter()
+                    .map(|span| Span::styled(span.text.clone(), tone_style(span.tone)))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
@@ -3,6 +3,7 @@
 //! Provides interactive TUI components using ratatui.
 
 pub mod event;
+pub mod markdown;
 pub mod screens;
 pub mod terminal;
 pub mod widgets;
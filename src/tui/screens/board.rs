@@ -0,0 +1,452 @@
+//! Kanban board screen.
+//!
+//! Shows items as cards in columns (workflow states, labels, or categories).
+//! Cards can be selected, opened, and moved to a neighbouring column; the
+//! caller decides what a move means for the item on disk.
+
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::{
+    constants::UI_BOARD_MIN_COLUMN_WIDTH,
+    tui::{event::TuiEvent, run, AppResult, TuiApp},
+    ui,
+};
+
+/// A card on the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardCard {
+    pub path: PathBuf,
+    pub id: String,
+    pub title: String,
+}
+
+/// A column of cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardColumn {
+    pub name: String,
+    pub cards: Vec<BoardCard>,
+}
+
+/// The result of moving a card, returned by the board's `mover`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MovedCard {
+    /// The card's new path
+    pub path: PathBuf,
+    /// Extra information about the move (e.g. unblocked items)
+    pub notes: Vec<String>,
+    /// Problems that didn't stop the move (e.g. attachments left behind)
+    pub warnings: Vec<String>,
+}
+
+/// Message shown in the title bar after a move.
+enum Notice {
+    Info(String),
+    Warning(String),
+    Error(String),
+}
+
+/// Kanban board screen.
+///
+/// `mover` is called with a card's path and the index of the target column
+/// and returns the card's new path with notes and warnings for the title bar.
+pub struct BoardScreen<F> {
+    title: String,
+    columns: Vec<BoardColumn>,
+    /// Focused column
+    focus: usize,
+    /// Selected card per column
+    selected: Vec<usize>,
+    /// First visible column (when not all columns fit)
+    first_column: usize,
+    notice: Option<Notice>,
+    mover: F,
+}
+
+impl<F> BoardScreen<F>
+where
+    F: FnMut(&Path, usize) -> anyhow::Result<MovedCard>,
+{
+    /// Creates a board with the first column focused.
+    pub fn new(title: impl Into<String>, columns: Vec<BoardColumn>, mover: F) -> Self {
+        let selected = vec![0; columns.len()];
+        Self {
+            title: title.into(),
+            columns,
+            focus: 0,
+            selected,
+            first_column: 0,
+            notice: None,
+            mover,
+        }
+    }
+
+    /// Returns the selected card of the focused column.
+    fn selected_card(&self) -> Option<&BoardCard> {
+        self.columns
+            .get(self.focus)?
+            .cards
+            .get(self.selected[self.focus])
+    }
+
+    fn focus_column(&mut self, delta: isize) {
+        self.focus = self
+            .focus
+            .saturating_add_signed(delta)
+            .min(self.columns.len().saturating_sub(1));
+    }
+
+    fn select_card(&mut self, delta: isize) {
+        let Some(column) = self.columns.get(self.focus) else {
+            return;
+        };
+        let last = column.cards.len().saturating_sub(1);
+        let selected = &mut self.selected[self.focus];
+        *selected = selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Moves the selected card one column to the left or right.
+    fn move_card(&mut self, delta: isize) {
+        let from = self.focus;
+        let to = from.saturating_add_signed(delta);
+        if to == from || to >= self.columns.len() {
+            return;
+        }
+        let index = self.selected[from];
+        let Some(card) = self.columns[from].cards.get(index) else {
+            return;
+        };
+
+        match (self.mover)(&card.path, to) {
+            Ok(moved) => {
+                let mut card = self.columns[from].cards.remove(index);
+                card.path = moved.path;
+                let text =
+                    std::iter::once(format!("Moved {} to {}", card.id, self.columns[to].name))
+                        .chain(moved.notes)
+                        .chain(moved.warnings.iter().cloned())
+                        .collect::<Vec<_>>()
+                        .join(" · ");
+                self.notice = Some(if moved.warnings.is_empty() {
+                    Notice::Info(text)
+                } else {
+                    Notice::Warning(text)
+                });
+
+                // Keep cards ordered by ID and follow the moved card
+                let cards = &mut self.columns[to].cards;
+                let position = cards.partition_point(|other| other.id < card.id);
+                cards.insert(position, card);
+                self.selected[from] = index.min(self.columns[from].cards.len().saturating_sub(1));
+                self.selected[to] = position;
+                self.focus = to;
+            }
+            Err(err) => self.notice = Some(Notice::Error(format!("{err:#}"))),
+        }
+    }
+
+    /// Number of columns that fit into `width`.
+    fn visible_columns(&self, width: u16) -> usize {
+        usize::from(width / UI_BOARD_MIN_COLUMN_WIDTH).clamp(1, self.columns.len().max(1))
+    }
+
+    fn render_column(&self, frame: &mut Frame, area: Rect, index: usize) {
+        let column = &self.columns[index];
+        let focused = index == self.focus;
+        let border_color = if focused {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .title(format!(" {} ({}) ", column.name, column.cards.len()));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Each card takes two lines: ID and title
+        let width = usize::from(inner.width);
+        let capacity = usize::from(inner.height / 2).max(1);
+        let selected = self.selected[index];
+        let offset = (selected + 1).saturating_sub(capacity);
+
+        let mut lines = Vec::new();
+        for (i, card) in column.cards.iter().enumerate().skip(offset).take(capacity) {
+            let style = if focused && i == selected {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(Span::styled(
+                ui::pad_to_width(&ui::truncate(&card.id, width), width),
+                style.fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(Span::styled(
+                ui::pad_to_width(&ui::truncate(&card.title, width), width),
+                style,
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+impl<F> TuiApp for BoardScreen<F>
+where
+    F: FnMut(&Path, usize) -> anyhow::Result<MovedCard>,
+{
+    type Output = PathBuf;
+
+    fn handle_event(&mut self, event: &TuiEvent) -> Option<AppResult<Self::Output>> {
+        let TuiEvent::Key(key) = event else {
+            return None;
+        };
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(AppResult::Cancelled);
+        }
+
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Left if shift => self.move_card(-1),
+            KeyCode::Right if shift => self.move_card(1),
+            KeyCode::Char('H' | '<') => self.move_card(-1),
+            KeyCode::Char('L' | '>') => self.move_card(1),
+            KeyCode::Left | KeyCode::Char('h') => self.focus_column(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.focus_column(1),
+            KeyCode::Up | KeyCode::Char('k') => self.select_card(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.select_card(1),
+            KeyCode::Enter => {
+                if let Some(card) = self.selected_card() {
+                    return Some(AppResult::Done(card.path.clone()));
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => return Some(AppResult::Cancelled),
+            _ => {}
+        }
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let chunks = Layout::vertical([
+            Constraint::Length(3), // Title
+            Constraint::Min(4),    // Columns
+            Constraint::Length(3), // Help
+        ])
+        .split(area);
+
+        // Title with the last move's result
+        let mut title = Line::from(self.title.as_str());
+        match &self.notice {
+            Some(Notice::Info(text)) => title.push_span(Span::styled(
+                format!("  {text}"),
+                Style::default().fg(Color::Green),
+            )),
+            Some(Notice::Warning(text)) => title.push_span(Span::styled(
+                format!("  {text}"),
+                Style::default().fg(Color::Yellow),
+            )),
+            Some(Notice::Error(text)) => title.push_span(Span::styled(
+                format!("  {text}"),
+                Style::default().fg(Color::Red),
+            )),
+            None => {}
+        }
+        let title_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        frame.render_widget(Paragraph::new(title).block(title_block), chunks[0]);
+
+        // Columns, scrolled horizontally to keep the focused one visible
+        if self.columns.is_empty() {
+            let empty = Paragraph::new("No columns.")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL));
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            let visible = self.visible_columns(chunks[1].width);
+            if self.focus < self.first_column {
+                self.first_column = self.focus;
+            } else if self.focus >= self.first_column + visible {
+                self.first_column = self.focus + 1 - visible;
+            }
+            let constraints = vec![Constraint::Fill(1); visible];
+            let areas = Layout::horizontal(constraints).split(chunks[1]);
+            let end = (self.first_column + visible).min(self.columns.len());
+            for (area, index) in areas.iter().zip(self.first_column..end) {
+                self.render_column(frame, *area, index);
+            }
+        }
+
+        // Help
+        let key = |text: &'static str| Span::styled(text, Style::default().fg(Color::Cyan));
+        let help = Line::from(vec![
+            key("←/→"),
+            Span::raw(" Column  "),
+            key("↑/↓"),
+            Span::raw(" Card  "),
+            key("Shift+←/→"),
+            Span::raw(" Move  "),
+            key("Enter"),
+            Span::raw(" Open  "),
+            key("Esc"),
+            Span::raw(" Quit"),
+        ]);
+        let help_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(Paragraph::new(help).block(help_block), chunks[2]);
+    }
+}
+
+/// Runs the board screen.
+///
+/// Returns the path of the card opened with Enter, or `Ok(None)` if the board
+/// was closed.
+pub fn show_board<F>(
+    title: &str,
+    columns: Vec<BoardColumn>,
+    mover: F,
+) -> anyhow::Result<Option<PathBuf>>
+where
+    F: FnMut(&Path, usize) -> anyhow::Result<MovedCard>,
+{
+    run(BoardScreen::new(title, columns, mover))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    fn key(code: KeyCode) -> TuiEvent {
+        TuiEvent::Key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    fn shift(code: KeyCode) -> TuiEvent {
+        TuiEvent::Key(KeyEvent::new(code, KeyModifiers::SHIFT))
+    }
+
+    fn card(id: &str) -> BoardCard {
+        BoardCard {
+            path: PathBuf::from(format!("{id}.md")),
+            id: id.to_string(),
+            title: format!("Title {id}"),
+        }
+    }
+
+    fn columns() -> Vec<BoardColumn> {
+        vec![
+            BoardColumn {
+                name: "todo".to_string(),
+                cards: vec![card("A"), card("C")],
+            },
+            BoardColumn {
+                name: "done".to_string(),
+                cards: vec![card("B")],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_navigation_and_open() {
+        let mut board = BoardScreen::new("Board", columns(), |path: &Path, _| {
+            Ok(MovedCard {
+                path: path.into(),
+                ..MovedCard::default()
+            })
+        });
+
+        board.handle_event(&key(KeyCode::Down));
+        assert_eq!(board.selected_card().unwrap().id, "C");
+        board.handle_event(&key(KeyCode::Down));
+        assert_eq!(board.selected_card().unwrap().id, "C");
+
+        board.handle_event(&key(KeyCode::Char('l')));
+        assert_eq!(board.selected_card().unwrap().id, "B");
+        board.handle_event(&key(KeyCode::Right));
+        assert_eq!(board.focus, 1);
+
+        let result = board.handle_event(&key(KeyCode::Enter));
+        assert_eq!(result, Some(AppResult::Done(PathBuf::from("B.md"))));
+        assert_eq!(
+            board.handle_event(&key(KeyCode::Esc)),
+            Some(AppResult::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_move_card_calls_mover() {
+        let mut moves = Vec::new();
+        let mut board = BoardScreen::new("Board", columns(), |path: &Path, to| {
+            moves.push((path.to_path_buf(), to));
+            Ok(MovedCard {
+                path: PathBuf::from("moved.md"),
+                notes: vec!["Unblocked: B".to_string()],
+                ..MovedCard::default()
+            })
+        });
+
+        board.handle_event(&key(KeyCode::Down));
+        board.handle_event(&shift(KeyCode::Right));
+
+        // The card follows its move and stays ordered by ID
+        assert_eq!(board.focus, 1);
+        let ids: Vec<_> = board.columns[1]
+            .cards
+            .iter()
+            .map(|c| c.id.as_str())
+            .collect();
+        assert_eq!(ids, ["B", "C"]);
+        assert_eq!(
+            board.selected_card().unwrap().path,
+            PathBuf::from("moved.md")
+        );
+        assert_eq!(board.columns[0].cards.len(), 1);
+        assert!(
+            matches!(board.notice, Some(Notice::Info(ref text)) if text == "Moved C to done · Unblocked: B")
+        );
+
+        // No column to the right of the last one
+        board.handle_event(&key(KeyCode::Char('L')));
+        drop(board);
+        assert_eq!(moves, [(PathBuf::from("C.md"), 1)]);
+    }
+
+    #[test]
+    fn test_failed_move_keeps_card() {
+        let mut board = BoardScreen::new("Board", columns(), |_: &Path, _| {
+            anyhow::bail!("not allowed")
+        });
+
+        board.handle_event(&shift(KeyCode::Right));
+        assert_eq!(board.focus, 0);
+        assert_eq!(board.columns[0].cards.len(), 2);
+        assert!(matches!(board.notice, Some(Notice::Error(ref e)) if e == "not allowed"));
+    }
+
+    #[test]
+    fn test_move_warnings_are_shown() {
+        let mut board = BoardScreen::new("Board", columns(), |path: &Path, _| {
+            Ok(MovedCard {
+                path: path.into(),
+                warnings: vec!["'B' is still blocked by open item(s): D".to_string()],
+                ..MovedCard::default()
+            })
+        });
+
+        board.handle_event(&shift(KeyCode::Right));
+        assert!(matches!(
+            board.notice,
+            Some(Notice::Warning(ref text)) if text.ends_with("still blocked by open item(s): D")
+        ));
+    }
+}
@@ -1,36 +1,56 @@
 //! Item selection screen with action popup and filter overlay.
 //!
 //! Provides an interactive list of items with a popup menu for quick actions
-//! like View, Edit, Close/Reopen, and Delete. Also supports filtering by
-//! search query, labels, and category.
+//! like View, Comments, Edit, Close/Reopen, and Delete. Also supports filtering
+//! by search query (with the `qs list --query` syntax), labels, and category,
+//! cycling through saved views, and saving the current filter as a new view.
+//!
+//! A preview pane (toggled with `p`) shows the highlighted item's metadata,
+//! description, and comments next to the list.
+//!
+//! Items can be marked (`Space`, or `V` for a range) to apply one action to
+//! all of them through the bulk action menu.
 
-use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    path::PathBuf,
+};
 
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Layout},
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
 use crate::{
-    config::Config,
-    constants::{UI_LABELS_TRUNCATE_LEN, UI_TITLE_TRUNCATE_LEN},
-    item::{matches_any_label, matches_category_filter, matches_search_text, Item, Status},
+    config::{Column, Config, View, DEFAULT_COLUMNS},
+    constants::UI_PREVIEW_MIN_WIDTH,
+    item::{
+        due, matches_all_labels, matches_assignee_filter, matches_category_filter,
+        matches_search_text,
+        rank::{self, Matcher, SearchMode},
+        Comment, Item, Query, Status,
+    },
     storage,
     tui::{
         event::TuiEvent,
+        markdown,
         widgets::{
             ActionMenu, ActionMenuResult, FilterOverlay, FilterOverlayResult, FilterState,
-            MenuItem, SelectAction, SelectList,
+            MenuItem, PreviewPane, SelectAction, SelectList, TextInput, TextPopup,
         },
         AppResult, TuiApp,
     },
-    ui::{pad_to_width, truncate},
+    ui,
 };
 
+/// Width of horizontal rules in the comments popup.
+const COMMENT_RULE_WIDTH: usize = 40;
+
 /// Actions that can be performed on an item.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ItemAction {
@@ -44,12 +64,30 @@ pub enum ItemAction {
     Reopen(PathBuf),
     /// Delete item (move to trash)
     Delete(PathBuf),
+    /// Show the list for another saved view (`None` = no view)
+    ShowView(Option<String>),
+    /// Apply an action to all marked items
+    Bulk(BulkAction, Vec<PathBuf>),
+}
+
+/// Actions that can be applied to several marked items at once.
+///
+/// Labels and categories are asked for by the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    Close,
+    Reopen,
+    AddLabel,
+    RemoveLabel,
+    MoveCategory,
+    Delete,
 }
 
 /// Internal action kinds for the popup menu.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ActionKind {
     View,
+    Comments,
     Edit,
     Close,
     Reopen,
@@ -62,12 +100,15 @@ struct ItemInfo {
     path: PathBuf,
     status: Status,
     display: String,
+    /// Open item past its due date (highlighted in red)
+    overdue: bool,
     /// For filtering
+    item: Item,
     title: String,
-    id: String,
-    body: String,
     labels: Vec<String>,
+    assignees: Vec<String>,
     category: Option<String>,
+    comments: Vec<Comment>,
 }
 
 /// Screen state.
@@ -80,8 +121,17 @@ enum ScreenState {
         menu: ActionMenu,
         actions: Vec<ActionKind>,
     },
+    /// Showing the action popup for the marked items (`None` = cancel).
+    ShowingBulkPopup {
+        menu: ActionMenu,
+        actions: Vec<Option<BulkAction>>,
+    },
     /// Showing the filter overlay.
     ShowingFilter { overlay: Box<FilterOverlay> },
+    /// Showing an item's comments.
+    ShowingComments { popup: TextPopup },
+    /// Asking for the name of a new view.
+    SavingView { input: TextInput },
 }
 
 /// Item selection screen with action popup and filter support.
@@ -90,10 +140,14 @@ pub struct ItemActionScreen {
     all_items: Vec<ItemInfo>,
     /// Indices into `all_items` that match the current filter
     filtered_indices: Vec<usize>,
+    /// Search snippet lines by index into `all_items`
+    snippets: HashMap<usize, Line<'static>>,
     /// Current filter state
     filter_state: FilterState,
     /// Available labels for filter overlay
     available_labels: Vec<String>,
+    /// Available assignees for filter overlay
+    available_assignees: Vec<String>,
     /// Available categories for filter overlay
     available_categories: Vec<String>,
     /// The display list widget
@@ -101,77 +155,96 @@ pub struct ItemActionScreen {
     header: String,
     prompt: String,
     state: ScreenState,
+    /// Config (for saving views)
+    config: Config,
+    /// Name of the view the list was built from
+    view: Option<String>,
+    /// Feedback shown next to the prompt (e.g. after saving a view)
+    notice: Option<String>,
+    /// Preview pane for the highlighted item (`None` = hidden)
+    preview: Option<PreviewPane>,
+    /// Item index and width the preview was rendered for
+    preview_source: Option<(usize, u16)>,
+    /// IDs of open items, loaded on first preview of a blocked item
+    open_ids: Option<HashSet<String>>,
+    /// Indices into `all_items` of marked items (kept while filtering)
+    marked: HashSet<usize>,
+    /// List position where the current range selection (`V`) started
+    range_anchor: Option<usize>,
 }
 
 impl ItemActionScreen {
     /// Create a new item action screen.
+    ///
+    /// `view` names the saved view the items were listed with; its columns are
+    /// used for the list.
     pub fn new<T: AsRef<Item>>(
         prompt: &str,
         items: &[T],
         config: &Config,
         available_labels: Vec<String>,
+        available_assignees: Vec<String>,
         available_categories: Vec<String>,
+        view: Option<&str>,
     ) -> Self {
-        let header = format!(
-            "{:<15} {:>6}  {:<40}  {:<20}  {}",
-            "ID", "Status", "Title", "Labels", "Category"
-        );
+        let columns: &[Column] = view
+            .and_then(|name| config.view(name).ok())
+            .map_or(DEFAULT_COLUMNS, View::columns);
+        let header = ui::item_header(columns);
 
+        let today = due::today();
         let all_items: Vec<ItemInfo> = items
             .iter()
             .map(|item| {
                 let item = item.as_ref();
-                let status_str = match item.status() {
-                    Status::Open => "open",
-                    Status::Closed => "closed",
-                    Status::Template => "template",
-                };
-                let labels_str = truncate(&item.labels().join(", "), UI_LABELS_TRUNCATE_LEN);
                 let category_opt = item
                     .path
                     .as_ref()
                     .and_then(|p| storage::derive_category(config, p));
-                let category = category_opt.as_deref().unwrap_or("");
-                let title_truncated = truncate(item.title(), UI_TITLE_TRUNCATE_LEN);
-
-                let display = format!(
-                    "{:<15} {:>6}  {}  {}  {}",
-                    item.id(),
-                    status_str,
-                    pad_to_width(&title_truncated, 40),
-                    pad_to_width(&labels_str, 20),
-                    category
-                );
 
                 ItemInfo {
                     path: item.path.clone().unwrap_or_default(),
                     status: item.status(),
-                    display,
+                    display: ui::item_row(item, columns, config),
+                    overdue: item.is_overdue(today),
+                    item: item.clone(),
                     title: item.title().to_string(),
-                    id: item.id().to_string(),
-                    body: item.body.clone(),
                     labels: item.labels().to_vec(),
+                    assignees: item.assignees().to_vec(),
                     category: category_opt,
+                    comments: item.comments(),
                 }
             })
             .collect();
 
         // Initially all items are shown
-        let filtered_indices: Vec<usize> = (0..all_items.len()).collect();
-        let display_strings: Vec<String> = all_items.iter().map(|i| i.display.clone()).collect();
-        let list = SelectList::new(display_strings);
-
-        Self {
+        let mut screen = Self {
+            filtered_indices: (0..all_items.len()).collect(),
+            snippets: HashMap::new(),
             all_items,
-            filtered_indices,
             filter_state: FilterState::default(),
             available_labels,
+            available_assignees,
             available_categories,
-            list,
+            list: SelectList::new(Vec::<String>::new()),
             header,
             prompt: prompt.to_string(),
             state: ScreenState::Browsing,
-        }
+            config: config.clone(),
+            view: view.map(|name| {
+                config
+                    .view(name)
+                    .map_or_else(|_| name.to_string(), |v| v.name.clone())
+            }),
+            notice: None,
+            preview: None,
+            preview_source: None,
+            open_ids: None,
+            marked: HashSet::new(),
+            range_anchor: None,
+        };
+        screen.rebuild_display_list();
+        screen
     }
 
     /// Apply the current filter state to update `filtered_indices` and rebuild the list.
@@ -179,7 +252,10 @@ impl ItemActionScreen {
     /// Uses shared filter predicates from `item::search` module.
     fn apply_filter(&mut self) {
         let search = &self.filter_state.search;
+        // Query syntax when it parses, plain text search while it's still being typed
+        let query = Query::parse(search).ok();
         let filter_labels = &self.filter_state.labels;
+        let filter_assignees = &self.filter_state.assignees;
         let filter_category = &self.filter_state.category;
 
         self.filtered_indices = self
@@ -187,13 +263,27 @@ impl ItemActionScreen {
             .iter()
             .enumerate()
             .filter(|(_, item)| {
-                // Search filter (FTS) - uses shared predicate
+                // Search filter (FTS) - uses shared predicates
                 let search_ok = search.is_empty()
-                    || matches_search_text(&item.title, &item.id, &item.body, search);
+                    || query.as_ref().map_or_else(
+                        || {
+                            matches_search_text(
+                                item.item.title(),
+                                item.item.id(),
+                                &item.item.body,
+                                search,
+                            )
+                        },
+                        |query| query.matches(&item.item, item.category.as_deref(), true),
+                    );
 
-                // Label filter (OR logic) - uses shared predicate
+                // Label filter (AND logic, like `--label`) - uses shared predicate
                 let labels_ok =
-                    filter_labels.is_empty() || matches_any_label(&item.labels, filter_labels);
+                    filter_labels.is_empty() || matches_all_labels(&item.labels, filter_labels);
+
+                // Assignee filter (OR logic) - uses shared predicate
+                let assignees_ok = filter_assignees.is_empty()
+                    || matches_assignee_filter(&item.assignees, filter_assignees);
 
                 // Category filter - uses shared predicate
                 let category_ok = filter_category.is_none()
@@ -202,14 +292,50 @@ impl ItemActionScreen {
                         filter_category.as_deref().unwrap_or(""),
                     );
 
-                search_ok && labels_ok && category_ok
+                search_ok && labels_ok && assignees_ok && category_ok
             })
             .map(|(i, _)| i)
             .collect();
 
+        self.rank_search_results(query.as_ref());
+        // List positions change, so a started range no longer applies
+        self.range_anchor = None;
         self.rebuild_display_list();
     }
 
+    /// Orders filtered items by search relevance and collects their snippets.
+    ///
+    /// Ranks by the query's text terms, or by the whole search text while it
+    /// doesn't parse as a query.
+    fn rank_search_results(&mut self, query: Option<&Query>) {
+        self.snippets.clear();
+        let search = self.filter_state.search.as_str();
+        if search.is_empty() {
+            return;
+        }
+
+        let terms = query.map_or_else(|| vec![search], Query::text_terms);
+        let matchers: Vec<Matcher> = terms
+            .into_iter()
+            .filter_map(|term| Matcher::new(term, SearchMode::Plain).ok())
+            .collect();
+
+        let mut ranked: Vec<_> = self
+            .filtered_indices
+            .iter()
+            .map(|&i| (i, rank::score_all(&matchers, &self.all_items[i].item, true)))
+            .collect();
+        rank::sort_by_score(&mut ranked);
+
+        self.filtered_indices = ranked.iter().map(|&(i, _)| i).collect();
+        for (i, hit) in ranked {
+            if let Some(line) = hit.snippet.as_ref().and_then(|s| s.match_line()) {
+                let line = markdown::to_lines(&[ui::snippet_line(line)]).remove(0);
+                self.snippets.insert(i, line);
+            }
+        }
+    }
+
     /// Rebuild the `SelectList` based on `filtered_indices`.
     fn rebuild_display_list(&mut self) {
         let display_strings: Vec<String> = self
@@ -217,9 +343,101 @@ impl ItemActionScreen {
             .iter()
             .map(|&i| self.all_items[i].display.clone())
             .collect();
+        let overdue: HashSet<usize> = self
+            .filtered_indices
+            .iter()
+            .enumerate()
+            .filter(|(_, &i)| self.all_items[i].overdue)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        let details = self
+            .filtered_indices
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, i)| Some((pos, self.snippets.get(i)?.clone())))
+            .collect();
 
         // Create new list with filtered items
-        self.list = SelectList::new(display_strings);
+        self.list = SelectList::new(display_strings)
+            .with_alerts(overdue)
+            .with_details(details);
+        self.sync_marks();
+    }
+
+    /// List positions between the range anchor and the selection.
+    fn range_positions(&self) -> std::ops::RangeInclusive<usize> {
+        let current = self.list.selected_index().unwrap_or(0);
+        let anchor = self.range_anchor.unwrap_or(current);
+        anchor.min(current)..=anchor.max(current)
+    }
+
+    /// Show marked items (and a started range) in the list.
+    fn sync_marks(&mut self) {
+        let mut positions: HashSet<usize> = self
+            .filtered_indices
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| self.marked.contains(i))
+            .map(|(pos, _)| pos)
+            .collect();
+        if self.range_anchor.is_some() {
+            positions.extend(self.range_positions());
+        }
+        self.list.set_marks(positions);
+    }
+
+    /// Mark or unmark the selected item and move to the next one.
+    fn toggle_mark(&mut self) {
+        let Some(pos) = self.list.selected_index() else {
+            return;
+        };
+        let Some(index) = self.actual_index(pos) else {
+            return;
+        };
+        if !self.marked.remove(&index) {
+            self.marked.insert(index);
+        }
+        if pos + 1 < self.filtered_indices.len() {
+            self.list.select_next();
+        }
+        self.sync_marks();
+    }
+
+    /// Start a range selection, or mark the items of the started one.
+    fn toggle_range(&mut self) {
+        if self.filtered_indices.is_empty() {
+            return;
+        }
+        if self.range_anchor.is_some() {
+            self.commit_range();
+        } else {
+            self.range_anchor = self.list.selected_index();
+        }
+        self.sync_marks();
+    }
+
+    /// Mark the items of the started range selection.
+    fn commit_range(&mut self) {
+        if self.range_anchor.is_none() {
+            return;
+        }
+        let marked: Vec<usize> = self
+            .range_positions()
+            .filter_map(|pos| self.actual_index(pos))
+            .collect();
+        self.marked.extend(marked);
+        self.range_anchor = None;
+    }
+
+    /// Paths of the marked items, in list order.
+    fn marked_paths(&self) -> Vec<PathBuf> {
+        let mut indices: Vec<usize> = self.marked.iter().copied().collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .map(|i| self.all_items[i].path.clone())
+            .collect()
     }
 
     /// Get the actual item index from the filtered list index.
@@ -228,7 +446,7 @@ impl ItemActionScreen {
     }
 
     /// Build popup menu items based on item status.
-    fn build_popup_items(status: Status) -> (Vec<MenuItem>, Vec<ActionKind>) {
+    fn build_popup_items(status: Status, comments: usize) -> (Vec<MenuItem>, Vec<ActionKind>) {
         let mut items = Vec::new();
         let mut actions = Vec::new();
 
@@ -236,6 +454,16 @@ impl ItemActionScreen {
         items.push(MenuItem::action("View...", "open in editor", actions.len()));
         actions.push(ActionKind::View);
 
+        if comments > 0 {
+            let description = if comments == 1 {
+                "1 comment".to_string()
+            } else {
+                format!("{comments} comments")
+            };
+            items.push(MenuItem::action("Comments...", description, actions.len()));
+            actions.push(ActionKind::Comments);
+        }
+
         if status == Status::Open {
             items.push(MenuItem::action(
                 "Edit...",
@@ -295,7 +523,8 @@ impl ItemActionScreen {
                 } else {
                     "Actions (Archived)"
                 };
-                let (menu_items, actions) = Self::build_popup_items(item.status);
+                let (menu_items, actions) =
+                    Self::build_popup_items(item.status, item.comments.len());
                 let menu = ActionMenu::new(title, menu_items);
                 self.state = ScreenState::ShowingPopup {
                     item_index: actual_idx,
@@ -306,10 +535,151 @@ impl ItemActionScreen {
         }
     }
 
+    /// Build popup menu items for the marked items.
+    fn build_bulk_popup_items(
+        any_open: bool,
+        any_closed: bool,
+        any_labels: bool,
+    ) -> (Vec<MenuItem>, Vec<Option<BulkAction>>) {
+        let mut items = Vec::new();
+        let mut actions = Vec::new();
+
+        // Section 1: Status actions
+        if any_open {
+            items.push(MenuItem::action_colored(
+                "Close",
+                "archive open items",
+                Color::Yellow,
+                actions.len(),
+            ));
+            actions.push(Some(BulkAction::Close));
+        }
+        if any_closed {
+            items.push(MenuItem::action_colored(
+                "Reopen",
+                "restore archived items",
+                Color::Green,
+                actions.len(),
+            ));
+            actions.push(Some(BulkAction::Reopen));
+        }
+
+        // Section 2: Label and category actions
+        items.push(MenuItem::separator());
+        items.push(MenuItem::action("Add label...", "to all", actions.len()));
+        actions.push(Some(BulkAction::AddLabel));
+        if any_labels {
+            items.push(MenuItem::action(
+                "Remove label...",
+                "from all",
+                actions.len(),
+            ));
+            actions.push(Some(BulkAction::RemoveLabel));
+        }
+        items.push(MenuItem::action(
+            "Move to category...",
+            "move files",
+            actions.len(),
+        ));
+        actions.push(Some(BulkAction::MoveCategory));
+
+        // Section 3: Delete
+        items.push(MenuItem::separator());
+        items.push(MenuItem::action_colored(
+            "Delete",
+            "move to trash",
+            Color::Red,
+            actions.len(),
+        ));
+        actions.push(Some(BulkAction::Delete));
+
+        // Section 4: Cancel
+        items.push(MenuItem::separator());
+        items.push(MenuItem::action("Cancel", "ESC", actions.len()));
+        actions.push(None);
+
+        (items, actions)
+    }
+
+    /// Open the bulk action popup for the marked items.
+    fn open_bulk_popup(&mut self) {
+        let marked = || self.marked.iter().map(|&i| &self.all_items[i]);
+        let (menu_items, actions) = Self::build_bulk_popup_items(
+            marked().any(|item| item.status == Status::Open),
+            marked().any(|item| item.status == Status::Closed),
+            marked().any(|item| !item.labels.is_empty()),
+        );
+        let count = self.marked.len();
+        let title = if count == 1 {
+            "Actions (1 item)".to_string()
+        } else {
+            format!("Actions ({count} items)")
+        };
+        let menu = ActionMenu::new(title, menu_items);
+        self.state = ScreenState::ShowingBulkPopup { menu, actions };
+    }
+
+    /// Handle events while showing the bulk action popup.
+    fn handle_bulk_popup(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
+        let ScreenState::ShowingBulkPopup { menu, actions } = &mut self.state else {
+            return None;
+        };
+
+        if let TuiEvent::Key(key) = event {
+            // Handle Ctrl+C
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return Some(AppResult::Cancelled);
+            }
+
+            match menu.handle_key(*key) {
+                Some(ActionMenuResult::Selected(action_idx)) => {
+                    if let Some(action) = actions[action_idx] {
+                        let paths = self.marked_paths();
+                        return Some(AppResult::Done(ItemAction::Bulk(action, paths)));
+                    }
+                    self.state = ScreenState::Browsing;
+                }
+                Some(ActionMenuResult::Cancelled) => {
+                    self.state = ScreenState::Browsing;
+                }
+                None => {}
+            }
+        }
+        None
+    }
+
+    /// Open the comments popup for an item.
+    fn open_comments(&mut self, item_index: usize) {
+        let item = &self.all_items[item_index];
+        // Wrapping is left to the popup, so the width only sizes rules
+        let lines = ui::comment_lines(&item.comments, COMMENT_RULE_WIDTH);
+        let popup = TextPopup::new(item.title.clone(), markdown::to_lines(&lines));
+        self.state = ScreenState::ShowingComments { popup };
+    }
+
+    /// Handle events while showing the comments popup.
+    fn handle_comments(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
+        let ScreenState::ShowingComments { popup } = &mut self.state else {
+            return None;
+        };
+
+        if let TuiEvent::Key(key) = event {
+            // Handle Ctrl+C
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return Some(AppResult::Cancelled);
+            }
+            if popup.handle_key(*key) {
+                self.state = ScreenState::Browsing;
+            }
+        }
+        None
+    }
+
     /// Open the filter overlay.
     fn open_filter(&mut self) {
         let overlay = FilterOverlay::new(
             self.available_labels.clone(),
+            self.available_assignees.clone(),
             self.available_categories.clone(),
             &self.filter_state,
         );
@@ -339,7 +709,58 @@ impl ItemActionScreen {
                 return None;
             }
 
-            match self.list.handle_key(*key) {
+            // Handle 'v' to switch to the next saved view
+            if key.code == KeyCode::Char('v') && !self.config.views().is_empty() {
+                return Some(AppResult::Done(ItemAction::ShowView(self.next_view())));
+            }
+
+            // Handle 'p' to toggle the preview pane
+            if key.code == KeyCode::Char('p') {
+                self.toggle_preview();
+                return None;
+            }
+
+            // Scroll keys go to the preview while it's shown
+            if let Some(preview) = &mut self.preview {
+                if preview.handle_key(*key) {
+                    return None;
+                }
+            }
+
+            // Marking for bulk actions: Space marks one item, 'V' a range
+            match key.code {
+                KeyCode::Char(' ') => {
+                    self.toggle_mark();
+                    return None;
+                }
+                KeyCode::Char('V') => {
+                    self.toggle_range();
+                    return None;
+                }
+                KeyCode::Esc if self.range_anchor.is_some() => {
+                    self.range_anchor = None;
+                    self.sync_marks();
+                    return None;
+                }
+                KeyCode::Esc if !self.marked.is_empty() => {
+                    self.marked.clear();
+                    self.sync_marks();
+                    return None;
+                }
+                KeyCode::Enter if self.range_anchor.is_some() || !self.marked.is_empty() => {
+                    self.commit_range();
+                    self.sync_marks();
+                    self.open_bulk_popup();
+                    return None;
+                }
+                _ => {}
+            }
+
+            let action = self.list.handle_key(*key);
+            if self.range_anchor.is_some() {
+                self.sync_marks();
+            }
+            match action {
                 SelectAction::Confirm => {
                     if !self.filtered_indices.is_empty() {
                         self.open_popup();
@@ -354,6 +775,55 @@ impl ItemActionScreen {
         }
     }
 
+    /// Show or hide the preview pane.
+    fn toggle_preview(&mut self) {
+        self.preview = if self.preview.is_some() {
+            None
+        } else {
+            Some(PreviewPane::new("Preview"))
+        };
+        self.preview_source = None;
+    }
+
+    /// Render the highlighted item into the preview pane.
+    ///
+    /// Only re-renders when the selection or the pane width changed (e.g. on
+    /// terminal resize); a new selection scrolls back to the top.
+    fn update_preview(&mut self, width: u16) {
+        let selected = self
+            .list
+            .selected_index()
+            .and_then(|i| self.actual_index(i));
+        let Some(preview) = &mut self.preview else {
+            return;
+        };
+        let source = selected.map(|i| (i, width));
+        if source == self.preview_source {
+            return;
+        }
+        if selected != self.preview_source.map(|(i, _)| i) {
+            preview.reset_scroll();
+        }
+        self.preview_source = source;
+
+        let lines = selected.map_or_else(Vec::new, |i| {
+            let item = &self.all_items[i].item;
+            let open_ids = if item.blocked_by().is_empty() {
+                &HashSet::new()
+            } else {
+                self.open_ids
+                    .get_or_insert_with(|| storage::collect_open_ids(&self.config))
+            };
+            markdown::to_lines(&ui::item_lines(
+                &self.config,
+                item,
+                open_ids,
+                usize::from(width),
+            ))
+        });
+        preview.set_lines(lines);
+    }
+
     /// Handle events while showing the popup.
     fn handle_popup(
         &mut self,
@@ -378,6 +848,10 @@ impl ItemActionScreen {
                     let path = item.path.clone();
                     match actions[action_idx] {
                         ActionKind::View => Some(AppResult::Done(ItemAction::View(path))),
+                        ActionKind::Comments => {
+                            self.open_comments(item_index);
+                            None
+                        }
                         ActionKind::Edit => Some(AppResult::Done(ItemAction::Edit(path))),
                         ActionKind::Close => Some(AppResult::Done(ItemAction::Close(path))),
                         ActionKind::Reopen => Some(AppResult::Done(ItemAction::Reopen(path))),
@@ -426,6 +900,14 @@ impl ItemActionScreen {
                         self.state = ScreenState::Browsing;
                         None
                     }
+                    Some(FilterOverlayResult::SaveAsView(new_state)) => {
+                        self.filter_state = new_state;
+                        self.apply_filter();
+                        self.state = ScreenState::SavingView {
+                            input: TextInput::new("View name"),
+                        };
+                        None
+                    }
                     Some(FilterOverlayResult::Cancelled) => {
                         self.state = ScreenState::Browsing;
                         None
@@ -437,30 +919,157 @@ impl ItemActionScreen {
         }
     }
 
+    /// Returns the view after the current one (`None` after the last view).
+    fn next_view(&self) -> Option<String> {
+        let views = self.config.views();
+        let next = self.view.as_ref().map_or(0, |name| {
+            views
+                .iter()
+                .position(|v| v.name.eq_ignore_ascii_case(name))
+                .map_or(0, |i| i + 1)
+        });
+        views.get(next).map(|v| v.name.clone())
+    }
+
+    /// Builds a view from the current view and filter state.
+    ///
+    /// The current view's settings are kept and the overlay's filters added,
+    /// so the new view lists the same items.
+    fn view_from_filter(&self, name: &str) -> View {
+        let mut view = self
+            .view
+            .as_deref()
+            .and_then(|current| self.config.view(current).ok())
+            .cloned()
+            .unwrap_or_default();
+        view.name = name.to_string();
+
+        let mut query: Vec<String> = view.query.take().into_iter().collect();
+        let search = self.filter_state.search.trim();
+        if !search.is_empty() {
+            query.push(search.to_string());
+        }
+        view.labels.extend(self.filter_state.labels.iter().cloned());
+        view.query = match query.len() {
+            0 => None,
+            1 => query.pop(),
+            _ => Some(
+                query
+                    .iter()
+                    .map(|part| format!("({part})"))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+            ),
+        };
+        view.assignees
+            .extend(self.filter_state.assignees.iter().cloned());
+        if let Some(category) = &self.filter_state.category {
+            view.category = Some(category.clone());
+        }
+        view
+    }
+
+    /// Handle events while asking for a view name.
+    fn handle_saving_view(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
+        let ScreenState::SavingView { input } = &mut self.state else {
+            return None;
+        };
+
+        match event {
+            TuiEvent::Paste(content) => {
+                input.insert_text(content);
+                None
+            }
+            TuiEvent::Key(key) => {
+                // Handle Ctrl+C
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    return Some(AppResult::Cancelled);
+                }
+
+                match key.code {
+                    KeyCode::Esc => {
+                        self.state = ScreenState::Browsing;
+                    }
+                    KeyCode::Enter => {
+                        let name = input.content().trim().to_string();
+                        if name.is_empty() {
+                            return None;
+                        }
+                        let view = self.view_from_filter(&name);
+                        match self.config.save_view(view) {
+                            Ok(()) => {
+                                self.notice = Some(format!("Saved view '{name}'"));
+                                self.view = Some(name);
+                                self.state = ScreenState::Browsing;
+                            }
+                            Err(err) => {
+                                let ScreenState::SavingView { input } = &mut self.state else {
+                                    return None;
+                                };
+                                *input = input.clone().with_warning(format!("{err:#}"));
+                            }
+                        }
+                    }
+                    _ => {
+                        input.handle_key(*key);
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
     fn render_list(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
-        // Layout: prompt, header, list, help
-        let chunks = Layout::vertical([
+        // Layout: prompt, header, list, help (with the preview right of header and list)
+        let rows = Layout::vertical([
             Constraint::Length(3), // Prompt
-            Constraint::Length(1), // Header
-            Constraint::Min(5),    // List
+            Constraint::Min(6),    // Header and list
             Constraint::Length(3), // Help
         ])
         .split(area);
+        let show_preview = self.preview.is_some() && area.width >= UI_PREVIEW_MIN_WIDTH;
+        let body = if show_preview {
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(rows[1])
+        } else {
+            Layout::horizontal([Constraint::Fill(1)]).split(rows[1])
+        };
+        let list_rows = Layout::vertical([
+            Constraint::Length(1), // Header
+            Constraint::Min(5),    // List
+        ])
+        .split(body[0]);
+        let chunks = [rows[0], list_rows[0], list_rows[1], rows[2]];
 
-        // Prompt with filter indicator
+        // Prompt with view name, filter indicator, and notice
         let filter_active = !self.filter_state.is_empty();
-        let prompt_text = if filter_active {
-            format!(
-                "{} ({} of {} items)",
-                self.prompt,
+        let mut prompt_text = self.prompt.clone();
+        if let Some(view) = &self.view {
+            let _ = write!(prompt_text, " [view: {view}]");
+        }
+        if filter_active {
+            let _ = write!(
+                prompt_text,
+                " ({} of {} items)",
                 self.filtered_indices.len(),
                 self.all_items.len()
-            )
-        } else {
-            self.prompt.clone()
-        };
+            );
+        }
+        let mut prompt_line = Line::from(prompt_text);
+        if !self.marked.is_empty() {
+            prompt_line.push_span(Span::styled(
+                format!("  {} marked", self.marked.len()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if let Some(notice) = &self.notice {
+            prompt_line.push_span(Span::styled(
+                format!("  {notice}"),
+                Style::default().fg(Color::Green),
+            ));
+        }
 
         let prompt_border_color = if filter_active {
             Color::Yellow
@@ -471,7 +1080,7 @@ impl ItemActionScreen {
         let prompt_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(prompt_border_color));
-        let prompt = Paragraph::new(prompt_text).block(prompt_block);
+        let prompt = Paragraph::new(prompt_line).block(prompt_block);
         frame.render_widget(prompt, chunks[0]);
 
         // Header
@@ -497,24 +1106,40 @@ impl ItemActionScreen {
             self.list.render(chunks[2], frame.buffer_mut(), true);
         }
 
-        // Help - with filter right-aligned
+        // Preview (width without the pane's borders)
+        if show_preview {
+            self.update_preview(body[1].width.saturating_sub(2));
+            if let Some(preview) = &mut self.preview {
+                preview.render(body[1], frame.buffer_mut());
+            }
+        }
+
+        self.render_help(frame, chunks[3]);
+    }
+
+    /// Render the help line, with filter and view keys right-aligned.
+    fn render_help(&self, frame: &mut Frame, area: Rect) {
         let help_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::DarkGray));
-        let help_inner = help_block.inner(chunks[3]);
-        frame.render_widget(help_block, chunks[3]);
+        let help_inner = help_block.inner(area);
+        frame.render_widget(help_block, area);
 
-        // Left side: Enter Select, Esc Cancel
+        // Left side: Enter Select, Space Mark, V Range, Esc Cancel
         let left_spans = vec![
             Span::styled("Enter", Style::default().fg(Color::Cyan)),
             Span::raw(" Select  "),
+            Span::styled("Space", Style::default().fg(Color::Cyan)),
+            Span::raw(" Mark  "),
+            Span::styled("V", Style::default().fg(Color::Cyan)),
+            Span::raw(" Range  "),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
             Span::raw(" Cancel"),
         ];
         let left_help = Paragraph::new(Line::from(left_spans));
         frame.render_widget(left_help, help_inner);
 
-        // Right side: c Clear (grayed when no filter), f Filter
+        // Right side: p Preview, v View (only with saved views), c Clear (grayed when no filter), f Filter
         let filter_active = !self.filter_state.is_empty();
         let clear_style = if filter_active {
             Style::default().fg(Color::Cyan)
@@ -526,12 +1151,20 @@ impl ItemActionScreen {
         } else {
             Style::default().fg(Color::DarkGray)
         };
-        let right_spans = vec![
+        let mut right_spans = vec![
+            Span::styled("p", Style::default().fg(Color::Cyan)),
+            Span::raw(" Preview  "),
+        ];
+        if !self.config.views().is_empty() {
+            right_spans.push(Span::styled("v", Style::default().fg(Color::Cyan)));
+            right_spans.push(Span::raw(" View  "));
+        }
+        right_spans.extend([
             Span::styled("c", clear_style),
             Span::styled(" Clear  ", clear_text_style),
             Span::styled("f", Style::default().fg(Color::Cyan)),
             Span::raw(" Filter"),
-        ];
+        ]);
         let right_help =
             Paragraph::new(Line::from(right_spans)).alignment(ratatui::layout::Alignment::Right);
         frame.render_widget(right_help, help_inner);
@@ -553,7 +1186,10 @@ impl TuiApp for ItemActionScreen {
                 let actions = actions.clone();
                 self.handle_popup(event, item_index, &actions)
             }
+            ScreenState::ShowingBulkPopup { .. } => self.handle_bulk_popup(event),
             ScreenState::ShowingFilter { .. } => self.handle_filter(event),
+            ScreenState::ShowingComments { .. } => self.handle_comments(event),
+            ScreenState::SavingView { .. } => self.handle_saving_view(event),
         }
     }
 
@@ -563,12 +1199,24 @@ impl TuiApp for ItemActionScreen {
 
         // Render overlays on top
         match &mut self.state {
-            ScreenState::ShowingPopup { menu, .. } => {
+            ScreenState::ShowingPopup { menu, .. } | ScreenState::ShowingBulkPopup { menu, .. } => {
                 menu.render(frame.area(), frame.buffer_mut());
             }
             ScreenState::ShowingFilter { overlay } => {
                 overlay.render(frame.area(), frame.buffer_mut());
             }
+            ScreenState::ShowingComments { popup } => {
+                popup.render(frame.area(), frame.buffer_mut());
+            }
+            ScreenState::SavingView { input } => {
+                let area = frame.area();
+                let width = area.width.clamp(20, 60);
+                let x = area.x + area.width.saturating_sub(width) / 2;
+                let y = area.y + area.height.saturating_sub(3) / 2;
+                let popup_area = Rect::new(x, y, width, 3.min(area.height));
+                frame.render_widget(Clear, popup_area);
+                input.render(popup_area, frame.buffer_mut(), true);
+            }
             ScreenState::Browsing => {}
         }
     }
@@ -582,7 +1230,9 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
     items: &[T],
     config: &Config,
     available_labels: Vec<String>,
+    available_assignees: Vec<String>,
     available_categories: Vec<String>,
+    view: Option<&str>,
 ) -> anyhow::Result<Option<ItemAction>> {
     use crate::tui::run;
     let screen = ItemActionScreen::new(
@@ -590,7 +1240,9 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
         items,
         config,
         available_labels,
+        available_assignees,
         available_categories,
+        view,
     );
     run(screen)
 }
@@ -2,7 +2,7 @@
 //!
 //! Replaces dialoguer's Select for item selection.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -14,8 +14,10 @@ use ratatui::{
     Frame,
 };
 
+use crate::markdown;
 use crate::tui::{
     event::TuiEvent,
+    markdown::to_lines,
     run,
     widgets::{SelectAction, SelectList},
     AppResult, TuiApp,
@@ -47,6 +49,11 @@ impl SelectScreen {
         self.list = self.list.with_disabled(disabled);
         self
     }
+
+    fn with_details(mut self, details: HashMap<usize, ratatui::text::Line<'static>>) -> Self {
+        self.list = self.list.with_details(details);
+        self
+    }
 }
 
 impl TuiApp for SelectScreen {
@@ -171,6 +178,33 @@ pub fn select_from_list_with_header<T: ToString>(
     run(app)
 }
 
+/// Select from a list with a header row and extra lines below some items.
+///
+/// `details` pairs item indices with rendered lines (e.g. search snippets), shown
+/// indented below the item.
+/// Returns `Some(index)` if an item was selected, `None` if cancelled.
+pub fn select_from_list_with_details<T: ToString>(
+    prompt: &str,
+    header: &str,
+    options: &[T],
+    details: Vec<(usize, markdown::Line)>,
+) -> Result<Option<usize>> {
+    let items: Vec<String> = options.iter().map(ToString::to_string).collect();
+
+    if items.is_empty() {
+        anyhow::bail!("No items to select from");
+    }
+
+    let details = details
+        .into_iter()
+        .filter_map(|(i, line)| Some((i, to_lines(&[line]).pop()?)))
+        .collect();
+    let app = SelectScreen::new(prompt, items)
+        .with_header(header)
+        .with_details(details);
+    run(app)
+}
+
 /// Select from a list with some items disabled (visible but not selectable).
 ///
 /// `selectable_indices` contains the indices that CAN be selected.
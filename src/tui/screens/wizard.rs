@@ -1,6 +1,7 @@
 //! New item wizard screen.
 //!
 //! Two-panel wizard for creating new items with Tab navigation.
+//! Projects with a field schema get an extra Fields panel.
 
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
@@ -11,16 +12,21 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{
-    event::TuiEvent,
-    widgets::{MultiSelect, SelectList, TextInput},
-    AppResult, TuiApp,
+use crate::{
+    config::FieldDef,
+    item::fields::display_value,
+    tui::{
+        event::TuiEvent,
+        widgets::{MultiSelect, SelectList, TextInput},
+        AppResult, TuiApp,
+    },
 };
 
 /// Wizard panels for breadcrumb display.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WizardPanel {
     Meta,
+    Fields,
     Attachments,
 }
 
@@ -28,6 +34,7 @@ impl WizardPanel {
     const fn name(self) -> &'static str {
         match self {
             Self::Meta => "Meta",
+            Self::Fields => "Fields",
             Self::Attachments => "Attachments",
         }
     }
@@ -39,6 +46,7 @@ pub enum FocusedWidget {
     Title,
     Category,
     Labels,
+    Fields,
     Attachments,
 }
 
@@ -48,7 +56,8 @@ impl FocusedWidget {
         match self {
             Self::Title => Self::Category,
             Self::Category => Self::Labels,
-            Self::Labels => Self::Attachments,
+            Self::Labels => Self::Fields,
+            Self::Fields => Self::Attachments,
             Self::Attachments => Self::Title,
         }
     }
@@ -59,7 +68,8 @@ impl FocusedWidget {
             Self::Title => Self::Attachments,
             Self::Category => Self::Title,
             Self::Labels => Self::Category,
-            Self::Attachments => Self::Labels,
+            Self::Fields => Self::Labels,
+            Self::Attachments => Self::Fields,
         }
     }
 
@@ -67,11 +77,68 @@ impl FocusedWidget {
     const fn panel(self) -> WizardPanel {
         match self {
             Self::Title | Self::Category | Self::Labels => WizardPanel::Meta,
+            Self::Fields => WizardPanel::Fields,
             Self::Attachments => WizardPanel::Attachments,
         }
     }
 }
 
+/// Input row for a schema field.
+#[derive(Debug, Clone)]
+struct FieldInput {
+    def: FieldDef,
+    input: TextInput,
+}
+
+impl FieldInput {
+    fn new(def: &FieldDef, value: Option<String>) -> Self {
+        let label = def.default_value().map_or_else(
+            || format!("{} ({})", def.name, def.describe()),
+            |default| {
+                format!(
+                    "{} ({}) [default: {}]",
+                    def.name,
+                    def.describe(),
+                    display_value(&default)
+                )
+            },
+        );
+        let input = TextInput::new(label).with_initial(value.unwrap_or_default());
+        Self {
+            def: def.clone(),
+            input,
+        }
+    }
+
+    /// Returns a short problem description, or `None` if the value is valid.
+    fn problem(&self) -> Option<&'static str> {
+        let content = self.input.content().trim();
+        if content.is_empty() {
+            let missing = self.def.required && self.def.default.is_none();
+            return missing.then_some("required");
+        }
+        self.def.coerce(content).is_err().then_some("invalid")
+    }
+
+    /// Cycles through the field's choices (enum values, booleans).
+    fn cycle(&mut self, forward: bool) {
+        let choices = self.def.choices();
+        if choices.is_empty() {
+            return;
+        }
+        let current = choices
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(self.input.content().trim()));
+        let next = match (current, forward) {
+            (None, true) => 0,
+            (None, false) => choices.len() - 1,
+            (Some(i), true) => (i + 1) % choices.len(),
+            (Some(i), false) => (i + choices.len() - 1) % choices.len(),
+        };
+        self.input = self.input.clone().with_initial(choices[next].clone());
+    }
+}
+
 /// Output from the wizard.
 #[derive(Debug, Clone)]
 pub struct WizardOutput {
@@ -79,6 +146,8 @@ pub struct WizardOutput {
     pub attachments: Vec<String>,
     pub category: Option<String>,
     pub labels: Vec<String>,
+    /// Raw values of schema fields as `(name, value)` (empty = unset).
+    pub fields: Vec<(String, String)>,
     /// If true, skip opening the editor after creating the item.
     pub skip_editor: bool,
 }
@@ -97,6 +166,8 @@ pub struct NewItemWizard {
     labels_list: MultiSelect,
     label_input: TextInput,
     label_input_mode: bool,
+    field_inputs: Vec<FieldInput>,
+    field_index: usize,
     /// Whether this wizard is editing an existing item (changes header).
     is_editing: bool,
     /// The ID of the item being edited (shown in header when editing).
@@ -130,6 +201,8 @@ impl NewItemWizard {
                 .with_action_item_last(),
             label_input: TextInput::new("New label"),
             label_input_mode: false,
+            field_inputs: Vec::new(),
+            field_index: 0,
             is_editing: false,
             item_id: None,
         }
@@ -185,6 +258,16 @@ impl NewItemWizard {
         self
     }
 
+    /// Add inputs for the project's schema fields, pre-filled from `values`.
+    #[must_use]
+    pub fn with_fields(mut self, schema: &[FieldDef], values: &serde_yml::Mapping) -> Self {
+        self.field_inputs = schema
+            .iter()
+            .map(|def| FieldInput::new(def, values.get(&def.name).map(display_value)))
+            .collect();
+        self
+    }
+
     /// Mark this wizard as editing mode (changes header text).
     #[must_use]
     pub const fn for_editing(mut self) -> Self {
@@ -199,9 +282,39 @@ impl NewItemWizard {
         self
     }
 
-    /// Check if saving is allowed (title must not be empty).
+    /// Check if saving is allowed (title must not be empty, fields must be valid).
     fn can_save(&self) -> bool {
         !self.title_input.content().trim().is_empty()
+            && self.field_inputs.iter().all(|f| f.problem().is_none())
+    }
+
+    /// Panels shown in the header (Fields only when the project has a schema).
+    fn panels(&self) -> Vec<WizardPanel> {
+        if self.field_inputs.is_empty() {
+            vec![WizardPanel::Meta, WizardPanel::Attachments]
+        } else {
+            vec![
+                WizardPanel::Meta,
+                WizardPanel::Fields,
+                WizardPanel::Attachments,
+            ]
+        }
+    }
+
+    /// Move focus forward, skipping the Fields panel when there is no schema.
+    fn focus_next(&mut self) {
+        self.focused = self.focused.next();
+        if self.focused == FocusedWidget::Fields && self.field_inputs.is_empty() {
+            self.focus_next();
+        }
+    }
+
+    /// Move focus backward, skipping the Fields panel when there is no schema.
+    fn focus_prev(&mut self) {
+        self.focused = self.focused.prev();
+        if self.focused == FocusedWidget::Fields && self.field_inputs.is_empty() {
+            self.focus_prev();
+        }
     }
 
     /// Check if we're in any input mode (category or label creation).
@@ -223,6 +336,11 @@ impl NewItemWizard {
             attachments: self.attachments.clone(),
             category: self.category.clone(),
             labels,
+            fields: self
+                .field_inputs
+                .iter()
+                .map(|f| (f.def.name.clone(), f.input.content().trim().to_string()))
+                .collect(),
             skip_editor,
         }
     }
@@ -233,11 +351,11 @@ impl NewItemWizard {
     ) -> Option<AppResult<WizardOutput>> {
         match key.code {
             KeyCode::Tab => {
-                self.focused = self.focused.next();
+                self.focus_next();
                 None
             }
             KeyCode::BackTab => {
-                self.focused = self.focused.prev();
+                self.focus_prev();
                 None
             }
             KeyCode::Char('s')
@@ -276,11 +394,11 @@ impl NewItemWizard {
     ) -> Option<AppResult<WizardOutput>> {
         match key.code {
             KeyCode::Tab => {
-                self.focused = self.focused.next();
+                self.focus_next();
                 None
             }
             KeyCode::BackTab => {
-                self.focused = self.focused.prev();
+                self.focus_prev();
                 None
             }
             KeyCode::Char('s')
@@ -355,11 +473,11 @@ impl NewItemWizard {
         } else {
             match key.code {
                 KeyCode::Tab => {
-                    self.focused = self.focused.next();
+                    self.focus_next();
                     None
                 }
                 KeyCode::BackTab => {
-                    self.focused = self.focused.prev();
+                    self.focus_prev();
                     None
                 }
                 KeyCode::Char('s')
@@ -405,6 +523,68 @@ impl NewItemWizard {
         }
     }
 
+    fn handle_fields_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<AppResult<WizardOutput>> {
+        match key.code {
+            KeyCode::Tab => {
+                self.focus_next();
+                None
+            }
+            KeyCode::BackTab => {
+                self.focus_prev();
+                None
+            }
+            KeyCode::Char('s')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && key.modifiers.contains(KeyModifiers::ALT) =>
+            {
+                // Ctrl+Alt+S: save without opening editor
+                if self.can_save() {
+                    Some(AppResult::Done(self.complete(true)))
+                } else {
+                    None
+                }
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Ctrl+S: save and open editor
+                if self.can_save() {
+                    Some(AppResult::Done(self.complete(false)))
+                } else {
+                    None
+                }
+            }
+            KeyCode::Esc => Some(AppResult::Cancelled),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(AppResult::Cancelled)
+            }
+            KeyCode::Up => {
+                self.field_index = self.field_index.saturating_sub(1);
+                None
+            }
+            KeyCode::Down | KeyCode::Enter => {
+                if self.field_index + 1 < self.field_inputs.len() {
+                    self.field_index += 1;
+                }
+                None
+            }
+            _ => {
+                let field = self.field_inputs.get_mut(self.field_index)?;
+                // Fields with choices cycle with Left/Right instead of moving the cursor
+                match key.code {
+                    KeyCode::Left | KeyCode::Right if !field.def.choices().is_empty() => {
+                        field.cycle(key.code == KeyCode::Right);
+                    }
+                    _ => {
+                        field.input.handle_key(key);
+                    }
+                }
+                None
+            }
+        }
+    }
+
     fn get_category_at_index(&self, idx: usize) -> Option<String> {
         // Index 0 = "(none)", 1..n-1 = actual categories, last = "+ Create new..."
         if idx == 0 {
@@ -453,11 +633,11 @@ impl NewItemWizard {
         } else {
             match key.code {
                 KeyCode::Tab => {
-                    self.focused = self.focused.next();
+                    self.focus_next();
                     None
                 }
                 KeyCode::BackTab => {
-                    self.focused = self.focused.prev();
+                    self.focus_prev();
                     None
                 }
                 KeyCode::Char('s')
@@ -523,6 +703,11 @@ impl TuiApp for NewItemWizard {
                     FocusedWidget::Labels if self.label_input_mode => {
                         self.label_input.insert_text(content);
                     }
+                    FocusedWidget::Fields => {
+                        if let Some(field) = self.field_inputs.get_mut(self.field_index) {
+                            field.input.insert_text(content);
+                        }
+                    }
                     _ => {}
                 }
                 None
@@ -532,6 +717,7 @@ impl TuiApp for NewItemWizard {
                 FocusedWidget::Attachments => self.handle_attachments_key(*key),
                 FocusedWidget::Category => self.handle_category_key(*key),
                 FocusedWidget::Labels => self.handle_labels_key(*key),
+                FocusedWidget::Fields => self.handle_fields_key(*key),
             },
             _ => None,
         }
@@ -554,6 +740,7 @@ impl TuiApp for NewItemWizard {
         // Content area - show panel based on current focus
         match self.focused.panel() {
             WizardPanel::Meta => self.render_meta_panel(frame, chunks[1]),
+            WizardPanel::Fields => self.render_fields_panel(frame, chunks[1]),
             WizardPanel::Attachments => self.render_attachments_panel(frame, chunks[1]),
         }
 
@@ -566,8 +753,8 @@ impl NewItemWizard {
     fn render_header(&self, frame: &mut Frame, area: Rect) {
         let current_panel = self.focused.panel();
 
-        // Panel indicators: Meta > Attachments
-        let panels = [WizardPanel::Meta, WizardPanel::Attachments];
+        // Panel indicators: Meta > (Fields >) Attachments
+        let panels = self.panels();
         let indicators: Vec<Span> = panels
             .iter()
             .enumerate()
@@ -685,6 +872,24 @@ impl NewItemWizard {
         }
     }
 
+    fn render_fields_panel(&self, frame: &mut Frame, area: Rect) {
+        // One 3-row input per field, scrolled to keep the selected field visible
+        let visible = usize::from(area.height / 3).max(1);
+        let offset = (self.field_index + 1).saturating_sub(visible);
+
+        let rows = Layout::vertical(vec![Constraint::Length(3); visible]).split(area);
+        for (row, (i, field)) in rows
+            .iter()
+            .zip(self.field_inputs.iter().enumerate().skip(offset))
+        {
+            let input = field.problem().map_or_else(
+                || field.input.clone(),
+                |problem| field.input.clone().with_warning(problem),
+            );
+            input.render(*row, frame.buffer_mut(), i == self.field_index);
+        }
+    }
+
     fn render_attachments_panel(&self, frame: &mut Frame, area: Rect) {
         let focused = self.focused == FocusedWidget::Attachments;
         let border_color = if focused {
@@ -757,6 +962,12 @@ impl NewItemWizard {
                     Span::styled("Enter", key_on),
                     Span::styled(" Toggle", txt_on),
                 ],
+                FocusedWidget::Fields => vec![
+                    Span::styled("↑↓", key_on),
+                    Span::styled(" Field  ", txt_on),
+                    Span::styled("←→", key_on),
+                    Span::styled(" Choose", txt_on),
+                ],
                 FocusedWidget::Attachments => vec![
                     Span::styled("Enter", key_on),
                     Span::styled(" Add  ", txt_on),
@@ -945,13 +1156,57 @@ mod tests {
     fn test_focused_widget_navigation() {
         assert_eq!(FocusedWidget::Title.next(), FocusedWidget::Category);
         assert_eq!(FocusedWidget::Category.next(), FocusedWidget::Labels);
-        assert_eq!(FocusedWidget::Labels.next(), FocusedWidget::Attachments);
+        assert_eq!(FocusedWidget::Labels.next(), FocusedWidget::Fields);
+        assert_eq!(FocusedWidget::Fields.next(), FocusedWidget::Attachments);
         assert_eq!(FocusedWidget::Attachments.next(), FocusedWidget::Title);
 
         assert_eq!(FocusedWidget::Title.prev(), FocusedWidget::Attachments);
         assert_eq!(FocusedWidget::Category.prev(), FocusedWidget::Title);
         assert_eq!(FocusedWidget::Labels.prev(), FocusedWidget::Category);
-        assert_eq!(FocusedWidget::Attachments.prev(), FocusedWidget::Labels);
+        assert_eq!(FocusedWidget::Fields.prev(), FocusedWidget::Labels);
+        assert_eq!(FocusedWidget::Attachments.prev(), FocusedWidget::Fields);
+    }
+
+    #[test]
+    fn test_fields_panel_skipped_without_schema() {
+        let mut wizard = NewItemWizard::new(vec![], vec![]);
+        wizard.focused = FocusedWidget::Labels;
+        wizard.focus_next();
+        assert_eq!(wizard.focused, FocusedWidget::Attachments);
+        wizard.focus_prev();
+        assert_eq!(wizard.focused, FocusedWidget::Labels);
+    }
+
+    #[test]
+    fn test_field_inputs_validate_and_cycle() {
+        let schema: Vec<FieldDef> = vec![
+            toml::from_str("name = \"severity\"\ntype = \"enum\"\nvalues = [\"minor\", \"major\"]")
+                .unwrap(),
+            toml::from_str("name = \"estimate\"\ntype = \"int\"\nrequired = true").unwrap(),
+        ];
+        let mut wizard = NewItemWizard::new(vec![], vec![])
+            .with_title("Task")
+            .with_fields(&schema, &serde_yml::Mapping::new());
+        assert!(!wizard.can_save(), "required field is empty");
+
+        wizard.field_inputs[0].cycle(true);
+        assert_eq!(wizard.field_inputs[0].input.content(), "minor");
+        wizard.field_inputs[0].cycle(false);
+        assert_eq!(wizard.field_inputs[0].input.content(), "major");
+
+        wizard.field_inputs[1].input.insert_text("three");
+        assert!(!wizard.can_save(), "invalid int");
+        wizard.field_inputs[1].input = TextInput::new("estimate").with_initial("3");
+        assert!(wizard.can_save());
+
+        let output = wizard.complete(true);
+        assert_eq!(
+            output.fields,
+            vec![
+                ("severity".to_string(), "major".to_string()),
+                ("estimate".to_string(), "3".to_string())
+            ]
+        );
     }
 
     #[test]
@@ -959,6 +1214,7 @@ mod tests {
         assert_eq!(FocusedWidget::Title.panel(), WizardPanel::Meta);
         assert_eq!(FocusedWidget::Category.panel(), WizardPanel::Meta);
         assert_eq!(FocusedWidget::Labels.panel(), WizardPanel::Meta);
+        assert_eq!(FocusedWidget::Fields.panel(), WizardPanel::Fields);
         assert_eq!(FocusedWidget::Attachments.panel(), WizardPanel::Attachments);
     }
 }
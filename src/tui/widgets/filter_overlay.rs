@@ -1,6 +1,6 @@
 //! Filter overlay widget for interactive list filtering.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
@@ -19,6 +19,8 @@ pub struct FilterState {
     pub search: String,
     /// Selected labels (AND logic)
     pub labels: Vec<String>,
+    /// Selected assignees (OR logic)
+    pub assignees: Vec<String>,
     /// Selected category (None = all categories)
     pub category: Option<String>,
 }
@@ -26,13 +28,17 @@ pub struct FilterState {
 impl FilterState {
     /// Check if the filter is empty (no filtering applied).
     pub fn is_empty(&self) -> bool {
-        self.search.is_empty() && self.labels.is_empty() && self.category.is_none()
+        self.search.is_empty()
+            && self.labels.is_empty()
+            && self.assignees.is_empty()
+            && self.category.is_none()
     }
 
     /// Clear all filters.
     pub fn clear(&mut self) {
         self.search.clear();
         self.labels.clear();
+        self.assignees.clear();
         self.category = None;
     }
 }
@@ -42,6 +48,8 @@ impl FilterState {
 pub enum FilterOverlayResult {
     /// User applied the filter
     Applied(FilterState),
+    /// User applied the filter and wants to save it as a view
+    SaveAsView(FilterState),
     /// User cancelled (keeps previous filter)
     Cancelled,
 }
@@ -52,6 +60,7 @@ enum FilterFocus {
     #[default]
     Search,
     Labels,
+    Assignees,
     Category,
 }
 
@@ -59,7 +68,8 @@ impl FilterFocus {
     const fn next(self) -> Self {
         match self {
             Self::Search => Self::Labels,
-            Self::Labels => Self::Category,
+            Self::Labels => Self::Assignees,
+            Self::Assignees => Self::Category,
             Self::Category => Self::Search,
         }
     }
@@ -68,19 +78,23 @@ impl FilterFocus {
         match self {
             Self::Search => Self::Category,
             Self::Labels => Self::Search,
-            Self::Category => Self::Labels,
+            Self::Assignees => Self::Labels,
+            Self::Category => Self::Assignees,
         }
     }
 }
 
-/// Filter overlay widget combining search, labels, and category filters.
+/// Filter overlay widget combining search, labels, assignees, and category filters.
 pub struct FilterOverlay {
     search_input: TextInput,
     labels_select: MultiSelect,
+    assignees_select: MultiSelect,
     category_select: SelectList,
     focus: FilterFocus,
     /// Available labels for reference
     available_labels: Vec<String>,
+    /// Available assignees for reference
+    available_assignees: Vec<String>,
     /// Available categories for reference
     available_categories: Vec<String>,
 }
@@ -89,6 +103,7 @@ impl FilterOverlay {
     /// Create a new filter overlay with available options.
     pub fn new(
         available_labels: Vec<String>,
+        available_assignees: Vec<String>,
         available_categories: Vec<String>,
         initial_state: &FilterState,
     ) -> Self {
@@ -102,6 +117,13 @@ impl FilterOverlay {
             MultiSelect::new(available_labels.clone()).with_selected(&initial_state.labels)
         };
 
+        // Build assignees multi-select
+        let assignees_select = if available_assignees.is_empty() {
+            MultiSelect::new(vec!["(no assignees)".to_string()])
+        } else {
+            MultiSelect::new(available_assignees.clone()).with_selected(&initial_state.assignees)
+        };
+
         // Build category select with "(all)" option
         let mut category_items = vec!["(all)".to_string()];
         category_items.extend(available_categories.clone());
@@ -111,9 +133,11 @@ impl FilterOverlay {
         let mut overlay = Self {
             search_input,
             labels_select,
+            assignees_select,
             category_select,
             focus: FilterFocus::Search,
             available_labels,
+            available_assignees,
             available_categories,
         };
 
@@ -144,6 +168,16 @@ impl FilterOverlay {
                 .collect()
         };
 
+        let assignees: Vec<String> = if self.available_assignees.is_empty() {
+            vec![]
+        } else {
+            self.assignees_select
+                .selected_items()
+                .into_iter()
+                .map(String::from)
+                .collect()
+        };
+
         let category = self.category_select.selected_index().and_then(|idx| {
             if idx == 0 {
                 None // "(all)" selected
@@ -155,6 +189,7 @@ impl FilterOverlay {
         FilterState {
             search,
             labels,
+            assignees,
             category,
         }
     }
@@ -177,6 +212,9 @@ impl FilterOverlay {
             }
             KeyCode::Enter => Some(FilterOverlayResult::Applied(self.state())),
             KeyCode::Esc => Some(FilterOverlayResult::Cancelled),
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(FilterOverlayResult::SaveAsView(self.state()))
+            }
             _ => {
                 // Delegate to focused widget
                 match self.focus {
@@ -188,6 +226,11 @@ impl FilterOverlay {
                             self.labels_select.handle_key(key);
                         }
                     }
+                    FilterFocus::Assignees => {
+                        if !self.available_assignees.is_empty() {
+                            self.assignees_select.handle_key(key);
+                        }
+                    }
                     FilterFocus::Category => {
                         // Only allow navigation, not confirm/cancel
                         match key.code {
@@ -209,13 +252,19 @@ impl FilterOverlay {
     /// Calculate the overlay dimensions.
     #[allow(clippy::cast_possible_truncation)]
     fn overlay_size(&self) -> (u16, u16) {
-        // Width: enough for labels and categories side by side
+        // Width: enough for labels, assignees, and categories side by side
         let max_label_width = self
             .available_labels
             .iter()
             .map(|l| l.width())
             .max()
             .unwrap_or(10);
+        let max_assignee_width = self
+            .available_assignees
+            .iter()
+            .map(|a| a.width())
+            .max()
+            .unwrap_or(12);
         let max_cat_width = self
             .available_categories
             .iter()
@@ -223,15 +272,17 @@ impl FilterOverlay {
             .max()
             .unwrap_or(10);
 
-        // Width: labels + categories + padding (prefix + checkbox/radio + borders)
-        // Each side needs: 2 ("> ") + 4 ("[ ] " or "(•) ") + label + 2 (borders) + 2 (padding)
-        let content_width = (max_label_width + 12) + (max_cat_width + 12) + 4;
-        let width = content_width.clamp(50, 100) as u16;
+        // Width: labels + assignees + categories + padding (prefix + checkbox/radio + borders)
+        // Each column needs: 2 ("> ") + 4 ("[ ] " or "(•) ") + label + 2 (borders) + 2 (padding)
+        let content_width =
+            (max_label_width + 12) + (max_assignee_width + 12) + (max_cat_width + 12) + 4;
+        let width = content_width.clamp(66, 120) as u16;
 
-        // Height: search (3) + labels/categories (max 10 rows) + help (2) + borders
+        // Height: search (3) + lists (max 10 rows) + help (2) + borders
         let list_height = self
             .available_labels
             .len()
+            .max(self.available_assignees.len())
             .max(self.available_categories.len() + 1)
             .min(8);
         let height = (3 + list_height + 3 + 2) as u16;
@@ -284,75 +335,41 @@ impl FilterOverlay {
         self.search_input
             .render(chunks[0], buf, self.focus == FilterFocus::Search);
 
-        // Split middle area for labels and categories
-        let list_chunks =
-            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(chunks[1]);
+        // Split middle area for labels, assignees, and categories
+        let list_chunks = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(chunks[1]);
 
         // Render labels (left)
-        self.render_labels_section(list_chunks[0], buf);
+        render_checklist(
+            list_chunks[0],
+            buf,
+            " Labels ",
+            &self.available_labels,
+            &self.labels_select,
+            self.focus == FilterFocus::Labels,
+        );
+
+        // Render assignees (middle)
+        render_checklist(
+            list_chunks[1],
+            buf,
+            " Assignees ",
+            &self.available_assignees,
+            &self.assignees_select,
+            self.focus == FilterFocus::Assignees,
+        );
 
         // Render categories (right)
-        self.render_category_section(list_chunks[1], buf);
+        self.render_category_section(list_chunks[2], buf);
 
         // Render help text
         self.render_help(chunks[2], buf);
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    fn render_labels_section(&self, area: Rect, buf: &mut Buffer) {
-        let focused = self.focus == FilterFocus::Labels;
-        let border_style = if focused {
-            Style::default().fg(Color::Cyan)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        };
-
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(border_style)
-            .title(" Labels ");
-
-        let inner = block.inner(area);
-        block.render(area, buf);
-
-        if self.available_labels.is_empty() {
-            let text = Paragraph::new("(no labels)").style(Style::default().fg(Color::DarkGray));
-            text.render(inner, buf);
-        } else {
-            // Render each label with checkbox
-            for (i, label) in self.available_labels.iter().enumerate() {
-                if i >= inner.height as usize {
-                    break;
-                }
-                let y = inner.y + i as u16;
-                let is_selected = self.labels_select.selected_index() == Some(i);
-                let is_checked = self
-                    .labels_select
-                    .selected_items()
-                    .contains(&label.as_str());
-
-                let checkbox = if is_checked { "[x] " } else { "[ ] " };
-                let prefix = if is_selected && focused { "> " } else { "  " };
-
-                let style = if is_selected && focused {
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default()
-                };
-
-                let line = Line::from(vec![
-                    Span::styled(prefix, style),
-                    Span::styled(checkbox, style),
-                    Span::styled(label, style),
-                ]);
-                buf.set_line(inner.x, y, &line, inner.width);
-            }
-        }
-    }
-
     #[allow(clippy::cast_possible_truncation)]
     fn render_category_section(&self, area: Rect, buf: &mut Buffer) {
         let focused = self.focus == FilterFocus::Category;
@@ -408,12 +425,14 @@ impl FilterOverlay {
 
     #[allow(clippy::unused_self, clippy::cast_possible_truncation)]
     fn render_help(&self, area: Rect, buf: &mut Buffer) {
-        // Left side: Enter Apply, Esc Cancel
+        // Left side: Enter Apply, Esc Cancel, ^S Save view
         let left = Line::from(vec![
             Span::styled("Enter", Style::default().fg(Color::Cyan)),
             Span::raw(" Apply  "),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
-            Span::raw(" Cancel"),
+            Span::raw(" Cancel  "),
+            Span::styled("^S", Style::default().fg(Color::Cyan)),
+            Span::raw(" Save view"),
         ]);
         buf.set_line(area.x, area.y, &left, area.width);
 
@@ -430,6 +449,67 @@ impl FilterOverlay {
     }
 }
 
+/// Render a bordered multi-select list with checkboxes.
+#[allow(clippy::cast_possible_truncation)]
+fn render_checklist(
+    area: Rect,
+    buf: &mut Buffer,
+    title: &str,
+    options: &[String],
+    select: &MultiSelect,
+    focused: bool,
+) {
+    let border_style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .title(title);
+
+    let inner = block.inner(area);
+    block.render(area, buf);
+
+    if options.is_empty() {
+        let empty = format!("(no {})", title.trim().to_lowercase());
+        let text = Paragraph::new(empty).style(Style::default().fg(Color::DarkGray));
+        text.render(inner, buf);
+        return;
+    }
+
+    // Render each option with checkbox
+    let checked = select.selected_items();
+    for (i, option) in options.iter().enumerate() {
+        if i >= inner.height as usize {
+            break;
+        }
+        let y = inner.y + i as u16;
+        let is_selected = select.selected_index() == Some(i);
+        let is_checked = checked.contains(&option.as_str());
+
+        let checkbox = if is_checked { "[x] " } else { "[ ] " };
+        let prefix = if is_selected && focused { "> " } else { "  " };
+
+        let style = if is_selected && focused {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+
+        let line = Line::from(vec![
+            Span::styled(prefix, style),
+            Span::styled(checkbox, style),
+            Span::styled(option, style),
+        ]);
+        buf.set_line(inner.x, y, &line, inner.width);
+    }
+}
+
 /// Calculate a centered rectangle within the given area.
 fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let x = area.x + area.width.saturating_sub(width) / 2;
@@ -458,20 +538,41 @@ mod tests {
         let mut state = FilterState {
             search: "test".to_string(),
             labels: vec!["bug".to_string()],
+            assignees: vec!["Ann".to_string()],
             category: Some("features".to_string()),
         };
         state.clear();
         assert!(state.is_empty());
     }
 
+    #[test]
+    fn test_ctrl_s_saves_as_view() {
+        let state = FilterState {
+            search: "crash".to_string(),
+            ..FilterState::default()
+        };
+        let mut overlay = FilterOverlay::new(Vec::new(), Vec::new(), Vec::new(), &state);
+
+        let result = overlay.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(result, Some(FilterOverlayResult::SaveAsView(state)));
+        // Without Ctrl, 's' is typed into the search box
+        assert_eq!(
+            overlay.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty())),
+            None
+        );
+        assert_eq!(overlay.state().search, "crashs");
+    }
+
     #[test]
     fn test_focus_navigation() {
         assert_eq!(FilterFocus::Search.next(), FilterFocus::Labels);
-        assert_eq!(FilterFocus::Labels.next(), FilterFocus::Category);
+        assert_eq!(FilterFocus::Labels.next(), FilterFocus::Assignees);
+        assert_eq!(FilterFocus::Assignees.next(), FilterFocus::Category);
         assert_eq!(FilterFocus::Category.next(), FilterFocus::Search);
 
         assert_eq!(FilterFocus::Search.prev(), FilterFocus::Category);
         assert_eq!(FilterFocus::Labels.prev(), FilterFocus::Search);
-        assert_eq!(FilterFocus::Category.prev(), FilterFocus::Labels);
+        assert_eq!(FilterFocus::Assignees.prev(), FilterFocus::Labels);
+        assert_eq!(FilterFocus::Category.prev(), FilterFocus::Assignees);
     }
 }
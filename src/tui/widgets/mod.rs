@@ -3,11 +3,15 @@
 mod action_menu;
 mod filter_overlay;
 mod multi_select;
+mod preview_pane;
 mod select_list;
 mod text_input;
+mod text_popup;
 
 pub use action_menu::{ActionMenu, ActionMenuResult, MenuItem};
 pub use filter_overlay::{FilterOverlay, FilterOverlayResult, FilterState};
 pub use multi_select::MultiSelect;
+pub use preview_pane::PreviewPane;
 pub use select_list::{SelectAction, SelectList};
 pub use text_input::TextInput;
+pub use text_popup::TextPopup;
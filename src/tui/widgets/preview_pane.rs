@@ -0,0 +1,125 @@
+//! Scrollable side pane for read-only text.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+/// A bordered pane showing wrapped, scrollable text next to other content.
+///
+/// Unlike [`TextPopup`](super::TextPopup) the pane is not modal: it only takes
+/// its own scroll keys, so the surrounding screen keeps its navigation.
+pub struct PreviewPane {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+    /// Visible height from the last render (for page scrolling)
+    page: u16,
+}
+
+impl PreviewPane {
+    /// Create an empty pane with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines: Vec::new(),
+            scroll: 0,
+            page: 10,
+        }
+    }
+
+    /// Replace the content, keeping the scroll offset where possible.
+    pub fn set_lines(&mut self, lines: Vec<Line<'static>>) {
+        self.lines = lines;
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    /// Scroll back to the top.
+    pub fn reset_scroll(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Current scroll offset (in lines).
+    pub const fn scroll(&self) -> u16 {
+        self.scroll
+    }
+
+    /// Largest useful scroll offset.
+    #[allow(clippy::cast_possible_truncation)]
+    fn max_scroll(&self) -> u16 {
+        (self.lines.len() as u16).saturating_sub(1)
+    }
+
+    /// Handle a scroll key (`J`/`K`, `PageUp`/`PageDown`).
+    ///
+    /// Returns true when the key was used.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('K') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('J') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(self.page),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(self.page),
+            _ => return false,
+        }
+        self.scroll = self.scroll.min(self.max_scroll());
+        true
+    }
+
+    /// Render the pane into the given area.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(format!(" {} ", self.title));
+
+        self.page = block.inner(area).height.max(1);
+
+        Paragraph::new(self.lines.clone())
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key_event(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn pane(lines: usize) -> PreviewPane {
+        let mut pane = PreviewPane::new("Test");
+        pane.set_lines((0..lines).map(|i| Line::from(i.to_string())).collect());
+        pane
+    }
+
+    #[test]
+    fn test_scroll_is_clamped() {
+        let mut pane = pane(3);
+        assert!(pane.handle_key(key_event(KeyCode::Char('K'))));
+        assert_eq!(pane.scroll(), 0);
+
+        pane.handle_key(key_event(KeyCode::PageDown));
+        assert_eq!(pane.scroll(), 2);
+
+        // Shorter content pulls the offset back
+        pane.set_lines(vec![Line::from("only")]);
+        assert_eq!(pane.scroll(), 0);
+    }
+
+    #[test]
+    fn test_list_keys_are_not_used() {
+        let mut pane = pane(3);
+        assert!(!pane.handle_key(key_event(KeyCode::Char('j'))));
+        assert!(!pane.handle_key(key_event(KeyCode::Down)));
+        assert_eq!(pane.scroll(), 0);
+    }
+}
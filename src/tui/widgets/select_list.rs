@@ -1,13 +1,13 @@
 //! Single-select scrollable list widget.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget},
 };
 
@@ -29,6 +29,12 @@ pub struct SelectList {
     title: String,
     /// Indices of items that are disabled (shown but not selectable)
     disabled: HashSet<usize>,
+    /// Indices of items that need attention (shown in red)
+    alerts: HashSet<usize>,
+    /// Extra lines shown below items (e.g. search snippets)
+    details: HashMap<usize, Line<'static>>,
+    /// Indices of marked items (for bulk actions, shown with `*`)
+    marks: HashSet<usize>,
 }
 
 impl SelectList {
@@ -44,6 +50,9 @@ impl SelectList {
             state,
             title: String::new(),
             disabled: HashSet::new(),
+            alerts: HashSet::new(),
+            details: HashMap::new(),
+            marks: HashSet::new(),
         }
     }
 
@@ -67,6 +76,25 @@ impl SelectList {
         self
     }
 
+    /// Set which indices need attention (rendered in red, e.g. overdue items).
+    #[must_use]
+    pub fn with_alerts(mut self, alerts: HashSet<usize>) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    /// Set extra lines shown indented below items (e.g. search snippets).
+    #[must_use]
+    pub fn with_details(mut self, details: HashMap<usize, Line<'static>>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Set which indices are marked (for bulk actions).
+    pub fn set_marks(&mut self, marks: HashSet<usize>) {
+        self.marks = marks;
+    }
+
     /// Select the first enabled item.
     fn select_first_enabled(&mut self) {
         for i in 0..self.items.len() {
@@ -186,6 +214,8 @@ impl SelectList {
             .map(|(i, item)| {
                 let is_selected = Some(i) == self.state.selected();
                 let is_disabled = self.disabled.contains(&i);
+                let is_alert = self.alerts.contains(&i);
+                let is_marked = self.marks.contains(&i);
 
                 let style = if !focused {
                     // Unfocused: all content muted
@@ -193,6 +223,11 @@ impl SelectList {
                 } else if is_disabled {
                     // Disabled items shown dimmed
                     Style::default().fg(Color::DarkGray)
+                } else if is_alert && !is_selected {
+                    // Alert items shown in red (selection highlight still wins)
+                    Style::default().fg(Color::Red)
+                } else if is_marked && !is_selected {
+                    Style::default().fg(Color::Yellow)
                 } else if is_selected {
                     Style::default()
                         .fg(Color::Cyan)
@@ -201,15 +236,21 @@ impl SelectList {
                     Style::default()
                 };
 
-                let prefix = if is_selected && !is_disabled && focused {
-                    "> "
-                } else {
-                    "  "
+                let prefix = match (is_selected && !is_disabled && focused, is_marked) {
+                    (true, true) => ">*",
+                    (true, false) => "> ",
+                    (false, true) => " *",
+                    (false, false) => "  ",
                 };
-                ListItem::new(Line::from(vec![
-                    Span::styled(prefix, style),
-                    Span::styled(item, style),
-                ]))
+                let line = Line::from(vec![Span::styled(prefix, style), Span::styled(item, style)]);
+                match self.details.get(&i) {
+                    Some(detail) => {
+                        let mut detail = detail.clone();
+                        detail.spans.insert(0, Span::raw("    "));
+                        ListItem::new(Text::from(vec![line, detail]))
+                    }
+                    None => ListItem::new(line),
+                }
             })
             .collect();
 
@@ -236,6 +277,9 @@ impl Clone for SelectList {
         let mut new_list = Self::new(self.items.clone());
         new_list.title.clone_from(&self.title);
         new_list.disabled.clone_from(&self.disabled);
+        new_list.alerts.clone_from(&self.alerts);
+        new_list.details.clone_from(&self.details);
+        new_list.marks.clone_from(&self.marks);
         // Always preserve selection state, including None (all items disabled)
         new_list.state.select(self.state.selected());
         new_list
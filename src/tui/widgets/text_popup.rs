@@ -0,0 +1,152 @@
+//! Scrollable modal popup for read-only text.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// A centered popup showing wrapped, scrollable text.
+pub struct TextPopup {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+    /// Visible height from the last render (for page scrolling)
+    page: u16,
+}
+
+impl TextPopup {
+    /// Create a popup with the given title and content.
+    pub fn new(title: impl Into<String>, lines: Vec<Line<'static>>) -> Self {
+        Self {
+            title: title.into(),
+            lines,
+            scroll: 0,
+            page: 10,
+        }
+    }
+
+    /// Current scroll offset (in lines).
+    pub const fn scroll(&self) -> u16 {
+        self.scroll
+    }
+
+    /// Largest useful scroll offset.
+    #[allow(clippy::cast_possible_truncation)]
+    fn max_scroll(&self) -> u16 {
+        (self.lines.len() as u16).saturating_sub(1)
+    }
+
+    /// Handle a key press.
+    ///
+    /// Returns true when the popup should close.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(self.page),
+            KeyCode::PageDown | KeyCode::Char(' ') => {
+                self.scroll = self.scroll.saturating_add(self.page);
+            }
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => self.scroll = self.max_scroll(),
+            _ => {}
+        }
+        self.scroll = self.scroll.min(self.max_scroll());
+        false
+    }
+
+    /// Render the popup as a centered modal overlay.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let width = (area.width * 4 / 5).max(40).min(area.width);
+        let height = (area.height * 4 / 5).max(8).min(area.height);
+        let popup_area = centered_rect(width, height, area);
+
+        // Dim the background
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_style(
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::DIM),
+                    );
+                }
+            }
+        }
+
+        // Clear the popup area
+        Clear.render(popup_area, buf);
+
+        let key = Style::default().fg(Color::Cyan);
+        let help = Line::from(vec![
+            Span::raw(" "),
+            Span::styled("↑↓", key),
+            Span::raw(" Scroll  "),
+            Span::styled("Esc", key),
+            Span::raw(" Close "),
+        ]);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(format!(" {} ", self.title))
+            .title_bottom(help.right_aligned());
+
+        self.page = block.inner(popup_area).height.max(1);
+
+        Paragraph::new(self.lines.clone())
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .render(popup_area, buf);
+    }
+}
+
+/// Calculate a centered rectangle within the given area.
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    Rect::new(x, y, width.min(area.width), height.min(area.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key_event(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn popup(lines: usize) -> TextPopup {
+        TextPopup::new(
+            "Test",
+            (0..lines).map(|i| Line::from(i.to_string())).collect(),
+        )
+    }
+
+    #[test]
+    fn test_scroll_is_clamped() {
+        let mut popup = popup(3);
+        assert!(!popup.handle_key(key_event(KeyCode::Up)));
+        assert_eq!(popup.scroll(), 0);
+
+        popup.handle_key(key_event(KeyCode::PageDown));
+        assert_eq!(popup.scroll(), 2);
+
+        popup.handle_key(key_event(KeyCode::Home));
+        popup.handle_key(key_event(KeyCode::Down));
+        assert_eq!(popup.scroll(), 1);
+    }
+
+    #[test]
+    fn test_close_keys() {
+        let mut popup = popup(1);
+        assert!(popup.handle_key(key_event(KeyCode::Esc)));
+        assert!(popup.handle_key(key_event(KeyCode::Char('q'))));
+    }
+}
@@ -6,9 +6,13 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::hash::BuildHasher;
 use std::io::IsTerminal;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 
 use std::path::Path;
 
@@ -16,16 +20,23 @@ use owo_colors::OwoColorize;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    config::Config,
+    config::{Column, Config, DEFAULT_COLUMNS},
     constants::{
-        UI_COL_ID_WIDTH, UI_COL_STATUS_WIDTH, UI_LABELS_TRUNCATE_LEN, UI_TITLE_TRUNCATE_LEN,
+        UI_COL_DATE_WIDTH, UI_COL_ID_WIDTH, UI_COL_NAME_WIDTH, UI_COL_PRIORITY_WIDTH,
+        UI_COL_STATUS_WIDTH, UI_LABELS_TRUNCATE_LEN, UI_TITLE_TRUNCATE_LEN,
     },
     editor,
-    item::{Item, Status},
+    item::{
+        due, fields,
+        rank::{Snippet, SnippetLine},
+        Comment, Item,
+    },
+    markdown::{self, Line, Span, Tone},
     storage::{self, AttachmentResult},
     tui::screens::{
-        confirm as tui_confirm, select_from_list as tui_select,
+        confirm as tui_confirm, prompt_text as tui_prompt_text, select_from_list as tui_select,
         select_from_list_filtered as tui_select_filtered,
+        select_from_list_with_details as tui_select_with_details,
         select_from_list_with_header as tui_select_with_header,
         select_item_with_actions as tui_select_item_with_actions, ItemAction,
     },
@@ -122,6 +133,45 @@ impl InteractiveArgs {
     }
 }
 
+// =============================================================================
+// Text Input
+// =============================================================================
+
+/// Reads all of stdin, failing if stdin is a terminal.
+///
+/// `what` names the expected input in the error message (e.g. "comment text").
+pub fn read_stdin(what: &str) -> Result<String> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        anyhow::bail!("Expected {what} on stdin, but stdin is a terminal");
+    }
+    let mut text = String::new();
+    std::io::Read::read_to_string(&mut stdin, &mut text)
+        .with_context(|| format!("Failed to read {what} from stdin"))?;
+    Ok(text)
+}
+
+/// Resolves a text argument, where `-` reads from stdin.
+pub fn read_text_arg(value: &str, what: &str) -> Result<String> {
+    if value == "-" {
+        read_stdin(what)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Resolves `--body <text|->` and `--body-file <path>` into the body text.
+///
+/// Returns `None` if neither was given.
+pub fn read_body(body: Option<&str>, body_file: Option<&Path>) -> Result<Option<String>> {
+    if let Some(path) = body_file {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read body file: {}", path.display()))?;
+        return Ok(Some(text));
+    }
+    body.map(|value| read_text_arg(value, "body")).transpose()
+}
+
 // =============================================================================
 // Interactive Selection
 // =============================================================================
@@ -160,7 +210,7 @@ pub fn select_from_list_with_header<T: ToString>(
 
 /// Interactive selection for items - returns index.
 ///
-/// Formats items as columns: ID | Status | Title | Labels | Category
+/// Formats items as columns: ID | Status | Priority | Title | Labels | Category
 /// Works with both `&[Item]` and `&[&Item]` via `AsRef<Item>`.
 /// Returns `Some(index)` if an item was selected, `None` if cancelled.
 pub fn select_item<T: AsRef<Item>>(
@@ -168,70 +218,125 @@ pub fn select_item<T: AsRef<Item>>(
     items: &[T],
     config: &Config,
 ) -> Result<Option<usize>> {
-    let header = format!(
-        "{:<id_w$} {:>status_w$}  {:<title_w$}  {:<labels_w$}  {}",
-        "ID",
-        "Status",
-        "Title",
-        "Labels",
-        "Category",
-        id_w = UI_COL_ID_WIDTH,
-        status_w = UI_COL_STATUS_WIDTH,
-        title_w = UI_TITLE_TRUNCATE_LEN,
-        labels_w = UI_LABELS_TRUNCATE_LEN,
-    );
+    let (header, options) = item_rows(items, config);
+    select_from_list_with_header(prompt, &header, &options)
+}
 
-    let options: Vec<String> = items
+/// Interactive selection for search results - returns index.
+///
+/// Like [`select_item`], with each item's matching snippet line shown below it.
+pub fn select_item_with_snippets<T: AsRef<Item>>(
+    prompt: &str,
+    items: &[T],
+    snippets: &[Option<&Snippet>],
+    config: &Config,
+) -> Result<Option<usize>> {
+    let (header, options) = item_rows(items, config);
+    let details = snippets
         .iter()
-        .map(|item| {
-            let item = item.as_ref();
-            let status = match item.status() {
-                Status::Open => "open",
-                Status::Closed => "closed",
-                Status::Template => "template",
-            };
-            let labels = truncate(&item.labels().join(", "), UI_LABELS_TRUNCATE_LEN);
-            let category_opt = item
-                .path
-                .as_ref()
-                .and_then(|p| storage::derive_category(config, p));
-            let category = category_opt.as_deref().unwrap_or("");
-            let title = truncate(item.title(), UI_TITLE_TRUNCATE_LEN);
-            // Use display-width-aware padding for proper alignment with CJK/emoji
-            format!(
-                "{:<id_w$} {:>status_w$}  {}  {}  {}",
-                item.id(),
-                status,
-                pad_to_width(&title, UI_TITLE_TRUNCATE_LEN),
-                pad_to_width(&labels, UI_LABELS_TRUNCATE_LEN),
-                category,
-                id_w = UI_COL_ID_WIDTH,
-                status_w = UI_COL_STATUS_WIDTH,
-            )
-        })
+        .enumerate()
+        .filter_map(|(i, snippet)| Some((i, snippet_line(snippet.as_ref()?.match_line()?))))
         .collect();
+    tui_select_with_details(prompt, &header, &options, details)
+}
 
-    select_from_list_with_header(prompt, &header, &options)
+/// Formats items as the default columns: ID | Status | Priority | Title | Labels | Category.
+///
+/// Returns the header row and one row per item.
+fn item_rows<T: AsRef<Item>>(items: &[T], config: &Config) -> (String, Vec<String>) {
+    let options = items
+        .iter()
+        .map(|item| item_row(item.as_ref(), DEFAULT_COLUMNS, config))
+        .collect();
+    (item_header(DEFAULT_COLUMNS), options)
+}
+
+/// Formats the header row for the given list columns.
+pub fn item_header(columns: &[Column]) -> String {
+    join_columns(columns, |column| column.to_string())
+}
+
+/// Formats one item as a row of the given list columns.
+///
+/// Cells are truncated and padded by display width, so rows line up with
+/// [`item_header`] even with CJK or emoji text.
+pub fn item_row(item: &Item, columns: &[Column], config: &Config) -> String {
+    join_columns(columns, |column| match column {
+        Column::Id => item.id().to_string(),
+        Column::Status => config.display_state(item),
+        Column::Priority => item.priority().unwrap_or("").to_string(),
+        Column::Title => item.title().to_string(),
+        Column::Labels => item.labels().join(", "),
+        Column::Category => item
+            .path
+            .as_ref()
+            .and_then(|p| storage::derive_category(config, p))
+            .unwrap_or_default(),
+        Column::Assignees => item.assignees().join(", "),
+        Column::Author => item.author().to_string(),
+        Column::Due => item.due().map(|d| d.to_string()).unwrap_or_default(),
+        Column::Created => item.created_at().format("%Y-%m-%d").to_string(),
+        Column::Updated => item.last_updated().format("%Y-%m-%d").to_string(),
+    })
+}
+
+/// Returns the display width of a list column.
+const fn column_width(column: Column) -> usize {
+    match column {
+        Column::Id => UI_COL_ID_WIDTH,
+        Column::Status => UI_COL_STATUS_WIDTH,
+        Column::Priority => UI_COL_PRIORITY_WIDTH,
+        Column::Title => UI_TITLE_TRUNCATE_LEN,
+        Column::Labels => UI_LABELS_TRUNCATE_LEN,
+        Column::Category | Column::Assignees | Column::Author => UI_COL_NAME_WIDTH,
+        Column::Due | Column::Created | Column::Updated => UI_COL_DATE_WIDTH,
+    }
+}
+
+/// Joins cells into a row: the status is right-aligned (and never truncated,
+/// since workflow states vary in length), the last cell is left unpadded.
+fn join_columns(columns: &[Column], cell: impl Fn(Column) -> String) -> String {
+    let mut row = String::new();
+    for (i, &column) in columns.iter().enumerate() {
+        let width = column_width(column);
+        let text = cell(column);
+        if i > 0 {
+            row.push_str(if column == Column::Status { " " } else { "  " });
+        }
+        if column == Column::Status {
+            let _ = write!(row, "{text:>width$}");
+        } else if i + 1 == columns.len() {
+            row.push_str(&text);
+        } else {
+            row.push_str(&pad_to_width(&truncate(&text, width), width));
+        }
+    }
+    row
 }
 
 /// Interactive item selection with action popup.
 ///
 /// Shows items in a list and when an item is selected, shows a popup menu
-/// with actions (View, Edit, Close/Reopen, Delete).
+/// with actions (View, Edit, Close/Reopen, Delete). `view` is the saved view
+/// the items were listed with (sets the columns and where view cycling starts).
 /// Returns the selected action, or `Ok(None)` if cancelled.
 pub fn select_item_with_actions<T: AsRef<Item>>(
     prompt: &str,
     items: &[T],
     config: &Config,
     available_labels: Vec<String>,
+    available_assignees: Vec<String>,
     available_categories: Vec<String>,
+    view: Option<&str>,
 ) -> Result<Option<ItemAction>> {
     tui_select_item_with_actions(
         prompt,
         items,
         config,
         available_labels,
+        available_assignees,
         available_categories,
+        view,
     )
 }
 
@@ -243,6 +348,13 @@ pub fn confirm(message: &str) -> Result<Option<bool>> {
     tui_confirm(message)
 }
 
+/// Prompt for a line of text.
+///
+/// Returns the entered text, or `Ok(None)` if cancelled.
+pub fn prompt_text(prompt: &str, label: &str) -> Result<Option<String>> {
+    tui_prompt_text(prompt, label)
+}
+
 /// Opens an item in the editor and prints its relative path.
 pub fn open_item_in_editor(item: &Item, config: &Config) -> Result<()> {
     let path = item.path.as_ref().context("Item has no path")?;
@@ -266,6 +378,15 @@ pub fn print_success(verb: &str, config: &Config, path: &Path) {
     );
 }
 
+/// Returns true if styled output should be written to stdout.
+///
+/// Colors are disabled when stdout is not a terminal or `NO_COLOR` is set
+/// to a non-empty value (see <https://no-color.org>).
+pub fn use_color() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    !no_color && std::io::stdout().is_terminal()
+}
+
 /// Prints warnings with yellow prefix.
 pub fn print_warnings(warnings: &[String]) {
     for warning in warnings {
@@ -273,6 +394,53 @@ pub fn print_warnings(warnings: &[String]) {
     }
 }
 
+/// Warns about item files that failed to parse when dropped.
+///
+/// Listing commands keep one alive while they print or show their items, so
+/// the warning is a footer below the listing (or after the TUI exits) rather
+/// than scrolling past before it. Strict mode is checked separately, before
+/// anything is shown (see [`storage::check_parse_failures`]).
+#[must_use = "the warning is printed when the footer is dropped"]
+pub struct ParseFailureFooter<'a> {
+    config: &'a Config,
+    failures: Vec<storage::ParseFailure>,
+}
+
+impl<'a> ParseFailureFooter<'a> {
+    pub const fn new(config: &'a Config, failures: Vec<storage::ParseFailure>) -> Self {
+        Self { config, failures }
+    }
+}
+
+impl Drop for ParseFailureFooter<'_> {
+    fn drop(&mut self) {
+        print_parse_failures(self.config, &self.failures);
+    }
+}
+
+/// Prints a warning for item files that failed to parse and were skipped.
+///
+/// Goes to stderr, so piped and JSON output stay clean.
+pub fn print_parse_failures(config: &Config, failures: &[storage::ParseFailure]) {
+    let failures = storage::distinct_parse_failures(failures);
+    if failures.is_empty() {
+        return;
+    }
+    let files = if failures.len() == 1 {
+        "1 item file".to_string()
+    } else {
+        format!("{} item files", failures.len())
+    };
+    eprintln!(
+        "{} {files} could not be parsed and {} skipped:",
+        "warning:".yellow(),
+        if failures.len() == 1 { "was" } else { "were" }
+    );
+    for failure in failures {
+        eprintln!("  {}", failure.describe(config));
+    }
+}
+
 // =============================================================================
 // Attachment Processing
 // =============================================================================
@@ -291,21 +459,10 @@ pub fn process_and_save_attachments(
     path: &Path,
     sources: &[String],
 ) -> Result<usize> {
-    use crate::storage;
-
-    // Set path so attachment_dir() works
-    item.path = Some(path.to_path_buf());
-
-    let item_dir = item
-        .attachment_dir()
-        .ok_or_else(|| anyhow::anyhow!("Invalid item path"))?
-        .to_path_buf();
-    let item_id = item.id().to_string();
-
     let mut added_count = 0;
 
-    for source in sources {
-        match storage::process_attachment(source, item, &item_dir, &item_id)? {
+    for result in crate::storage::add_attachments(item, path, sources)? {
+        match result {
             AttachmentResult::UrlAdded(url) => {
                 println!("  {} {}", "+".green(), url);
                 added_count += 1;
@@ -320,12 +477,213 @@ pub fn process_and_save_attachments(
         }
     }
 
-    // Save updated item with attachments
-    item.save(path)?;
-
     Ok(added_count)
 }
 
+// =============================================================================
+// Item Rendering
+// =============================================================================
+
+/// Indentation of comment text below its author line.
+const COMMENT_INDENT: &str = "  ";
+
+/// Width of the item header's label column.
+const HEADER_LABEL_WIDTH: usize = 12;
+
+/// Builds the full view of an item: the metadata header, the rendered
+/// description, and the comments, separated by rules of `width`.
+///
+/// Used by `qs show` and the TUI preview pane. `open_ids` highlights
+/// blockers that are still open.
+pub fn item_lines<S: BuildHasher>(
+    config: &Config,
+    item: &Item,
+    open_ids: &HashSet<String, S>,
+    width: usize,
+) -> Vec<Line> {
+    let rule = || vec![Span::new("─".repeat(width), Tone::Marker)];
+    let mut lines = header_lines(config, item, open_ids);
+    if !item.description().trim().is_empty() {
+        lines.push(rule());
+        lines.push(Vec::new());
+        lines.extend(markdown::render(item.description(), width));
+        lines.push(Vec::new());
+    }
+
+    let comments = item.comments();
+    if !comments.is_empty() {
+        lines.push(rule());
+        lines.push(Vec::new());
+        lines.extend(comment_lines(&comments, width));
+    }
+
+    // Drop the trailing blank line
+    if lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Formats a timestamp with its age, e.g. `2026-01-09 12:00 (3d ago)`.
+fn timestamp_spans(at: DateTime<Utc>) -> Vec<Span> {
+    vec![
+        Span::plain(at.format("%Y-%m-%d %H:%M").to_string()),
+        Span::new(format!(" ({})", format_age(at, Utc::now())), Tone::Marker),
+    ]
+}
+
+/// Builds the item header: the title followed by one row per metadata field.
+///
+/// Empty fields are left out.
+fn header_lines<S: BuildHasher>(
+    config: &Config,
+    item: &Item,
+    open_ids: &HashSet<String, S>,
+) -> Vec<Line> {
+    let mut lines = vec![vec![Span::new(item.title(), Tone::Heading)], Vec::new()];
+    let mut row = |label: &str, value: Vec<Span>| {
+        let label = format!("{} ", pad_to_width(label, HEADER_LABEL_WIDTH - 1));
+        let mut line = vec![Span::new(label, Tone::Marker)];
+        line.extend(value);
+        lines.push(line);
+    };
+
+    row("ID", vec![Span::plain(item.id())]);
+    row("Status", vec![Span::plain(config.display_state(item))]);
+    if let Some(priority) = item.priority() {
+        row("Priority", vec![Span::plain(priority)]);
+    }
+    if let Some(date) = item.due() {
+        let mut value = vec![Span::plain(due::format_due(date))];
+        if item.is_overdue(due::today()) {
+            value.push(Span::new(" (overdue)", Tone::Alert));
+        }
+        row("Due", value);
+    }
+    if !item.labels().is_empty() {
+        row("Labels", vec![Span::plain(item.labels().join(", "))]);
+    }
+    if let Some(category) = item
+        .path
+        .as_ref()
+        .and_then(|p| storage::derive_category(config, p))
+    {
+        row("Category", vec![Span::plain(category)]);
+    }
+    if !item.assignees().is_empty() {
+        row("Assignees", vec![Span::plain(item.assignees().join(", "))]);
+    }
+    row("Author", vec![Span::plain(item.author())]);
+    row("Created", timestamp_spans(item.created_at()));
+    if let Some(updated_at) = item.updated_at() {
+        row("Updated", timestamp_spans(updated_at));
+    }
+    if let Some(closed_at) = item.closed_at() {
+        row("Closed", timestamp_spans(closed_at));
+    }
+
+    if !item.blocked_by().is_empty() {
+        // Highlight blockers that are still open
+        let mut value = Vec::new();
+        for (i, id) in item.blocked_by().iter().enumerate() {
+            if i > 0 {
+                value.push(Span::plain(", "));
+            }
+            let tone = if open_ids.contains(id) {
+                Tone::Alert
+            } else {
+                Tone::Plain
+            };
+            value.push(Span::new(id.as_str(), tone));
+        }
+        row("Blocked by", value);
+    }
+    if !item.blocks().is_empty() {
+        row("Blocks", vec![Span::plain(item.blocks().join(", "))]);
+    }
+    if !item.related().is_empty() {
+        row("Related", vec![Span::plain(item.related().join(", "))]);
+    }
+
+    for (key, value) in item.custom_fields() {
+        if value.is_null() {
+            continue;
+        }
+        row(key, vec![Span::plain(fields::display_value(value))]);
+    }
+
+    for (i, attachment) in item.attachments().iter().enumerate() {
+        let label = if i == 0 { "Attachments" } else { "" };
+        row(label, vec![Span::plain(attachment.as_str())]);
+    }
+
+    lines.push(Vec::new());
+    lines
+}
+
+/// Builds the comments section: a heading, then each comment's author, time,
+/// and indented text.
+pub fn comment_lines(comments: &[Comment], width: usize) -> Vec<Line> {
+    let now = Utc::now();
+    let mut lines = vec![
+        vec![Span::new(
+            format!("Comments ({})", comments.len()),
+            Tone::Heading,
+        )],
+        Vec::new(),
+    ];
+
+    for comment in comments {
+        lines.push(vec![
+            Span::new(comment.author.as_str(), Tone::Strong),
+            Span::new(
+                format!(
+                    " · {} ({})",
+                    comment.created_at.format("%Y-%m-%d %H:%M"),
+                    format_age(comment.created_at, now)
+                ),
+                Tone::Marker,
+            ),
+        ]);
+        for mut line in markdown::render(&comment.text, width.saturating_sub(COMMENT_INDENT.len()))
+        {
+            if !line.is_empty() {
+                line.insert(0, Span::plain(COMMENT_INDENT));
+            }
+            lines.push(line);
+        }
+        lines.push(Vec::new());
+    }
+
+    lines
+}
+
+/// Prefix of the matching line in a search snippet.
+const SNIPPET_MATCH_PREFIX: &str = "  > ";
+
+/// Prefix of context lines in a search snippet.
+const SNIPPET_CONTEXT_PREFIX: &str = "    ";
+
+/// Builds the lines of a search snippet: the matching line with highlighted
+/// matches between dimmed context lines.
+pub fn snippet_lines(snippet: &Snippet) -> Vec<Line> {
+    snippet.lines.iter().map(snippet_line).collect()
+}
+
+/// Builds a single snippet line.
+pub fn snippet_line(line: &SnippetLine) -> Line {
+    let (prefix, tone) = if line.is_match {
+        (SNIPPET_MATCH_PREFIX, Tone::Plain)
+    } else {
+        (SNIPPET_CONTEXT_PREFIX, Tone::Marker)
+    };
+    let mut spans = vec![Span::new(prefix, Tone::Marker)];
+    spans.extend(line.segments().into_iter().map(|(text, highlighted)| {
+        Span::new(text, if highlighted { Tone::Highlight } else { tone })
+    }));
+    spans
+}
+
 // =============================================================================
 // String Utilities
 // =============================================================================
@@ -360,6 +718,26 @@ pub fn truncate(s: &str, max_width: usize) -> String {
     }
 }
 
+/// Formats how long ago `then` was, e.g. `3 days ago`.
+pub fn format_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(then);
+    let (count, unit) = if elapsed.num_minutes() < 1 {
+        return "just now".to_string();
+    } else if elapsed.num_hours() < 1 {
+        (elapsed.num_minutes(), "minute")
+    } else if elapsed.num_days() < 1 {
+        (elapsed.num_hours(), "hour")
+    } else if elapsed.num_days() < 30 {
+        (elapsed.num_days(), "day")
+    } else if elapsed.num_days() < 365 {
+        (elapsed.num_days() / 30, "month")
+    } else {
+        (elapsed.num_days() / 365, "year")
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {unit}{plural} ago")
+}
+
 /// Pads a string to the specified display width using spaces.
 ///
 /// Uses Unicode display width (accounts for wide CJK characters and emojis)
@@ -377,6 +755,21 @@ pub fn pad_to_width(s: &str, width: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_age() {
+        let now = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ago = |secs: i64| format_age(now - chrono::Duration::seconds(secs), now);
+
+        assert_eq!(ago(30), "just now");
+        assert_eq!(ago(60), "1 minute ago");
+        assert_eq!(ago(3 * 3600), "3 hours ago");
+        assert_eq!(ago(86400), "1 day ago");
+        assert_eq!(ago(45 * 86400), "1 month ago");
+        assert_eq!(ago(800 * 86400), "2 years ago");
+    }
+
     // ==========================================================================
     // Truncate Tests (display width based)
     // ==========================================================================
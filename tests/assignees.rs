@@ -0,0 +1,150 @@
+//! # Assignee Tests
+//!
+//! Tests for the `assignees` field: `qs new --assign`, `qs update
+//! --assign/--unassign`, and `qs list --assignee/--mine`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, json_ids, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Creates three items: one for Test User, one for Ann, and one unassigned.
+fn setup_assigned() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Mine", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Anns", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Nobodys", "open", &[], None);
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--assign", "Test User"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["update", "--id", "260102", "--assign", "Ann"])
+        .assert()
+        .success();
+    env
+}
+
+// =============================================================================
+// Setting Assignees
+// =============================================================================
+
+#[test]
+fn test_new_with_assignees() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args([
+            "new",
+            "Review PR",
+            "--no-interactive",
+            "--assign",
+            "Jane Doe",
+            "Bob",
+        ])
+        .assert()
+        .success();
+
+    let path = env.find_item_by_id("26").expect("item should exist");
+    let content = env.read_item(&path);
+    assert!(
+        content.contains("assignees:\n  - Jane Doe\n  - Bob\n"),
+        "{content}"
+    );
+}
+
+#[test]
+fn test_update_assign_and_unassign() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--assign", "Ann", "Bob"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--unassign", "ann"])
+        .assert()
+        .success();
+
+    let path = env.find_item_by_id("260101").unwrap();
+    let content = env.read_item(&path);
+    assert!(content.contains("assignees:\n  - Bob\n"), "{content}");
+
+    // Assigning someone twice is not a change
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--assign", "BOB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes to apply."));
+}
+
+#[test]
+fn test_show_and_json_include_assignees() {
+    let env = setup_assigned();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260102"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Assignees   Ann"));
+
+    let output = qs_cmd(&env)
+        .args(["show", "--id", "260102", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["assignees"], serde_json::json!(["Ann"]));
+}
+
+// =============================================================================
+// Filtering
+// =============================================================================
+
+#[test]
+fn test_list_by_assignee() {
+    let env = setup_assigned();
+
+    assert_eq!(
+        json_ids(&env, &["list", "--assignee", "ANN"]),
+        ["260102-BBB"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--assignee", "Ann", "Test User"]),
+        ["260101-AAA", "260102-BBB"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--assignee", "none"]),
+        ["260103-CCC"]
+    );
+}
+
+#[test]
+fn test_list_mine() {
+    let env = setup_assigned();
+
+    assert_eq!(json_ids(&env, &["list", "--mine"]), ["260101-AAA"]);
+}
+
+#[test]
+fn test_list_mine_without_user_fails() {
+    let env = setup_assigned();
+    env.write_global_config("interactive = false\nuse_git_user = false\n");
+
+    qs_cmd(&env)
+        .args(["list", "--mine", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot resolve the current user"));
+}
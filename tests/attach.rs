@@ -438,13 +438,14 @@ fn make_attachments_filter(id: &str) -> ListOptions {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: Some(id.to_string()),
         file: None,
+        ..Default::default()
     }
 }
 
@@ -526,6 +527,7 @@ fn test_update_category_moves_attachments() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        ..Default::default()
     };
     commands::update(args).expect("update category should succeed");
 
@@ -0,0 +1,239 @@
+//! # Board Tests
+//!
+//! Tests for `qs board`: column grouping by status, label, and category, the
+//! printed and JSON output, and moving items between columns.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, json_records, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, board::Board, GroupBy, LinkArgs},
+    config::Config,
+    item::{Item, Status},
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Sets up a project with a few items across labels and categories.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+
+    create_test_item(&env, "260101-AAA", "Login crash", "open", &["todo"], None);
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Slow queries",
+        "open",
+        &["doing", "perf"],
+        Some("backend"),
+    );
+    create_test_item(&env, "260103-CCC", "Docs", "open", &[], Some("docs"));
+    env
+}
+
+/// Runs `qs board` with `--format json` and returns column names with item IDs.
+fn json_board(env: &TestEnv, args: &[&str]) -> Vec<(String, Vec<String>)> {
+    let args: Vec<&str> = std::iter::once("board")
+        .chain(args.iter().copied())
+        .collect();
+    json_records(env, &args)
+        .iter()
+        .map(|column| {
+            let ids = column["items"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|item| item["id"].as_str().unwrap().to_string())
+                .collect();
+            (column["name"].as_str().unwrap().to_string(), ids)
+        })
+        .collect()
+}
+
+/// Moves an item to the named board column.
+fn move_to(board: &Board, config: &Config, id: &str, column: &str, env: &TestEnv) {
+    let index = board.columns().iter().position(|c| c == column).unwrap();
+    let path = env.find_item_by_id(id).unwrap();
+    board
+        .move_item(config, &path, index)
+        .expect("move should succeed");
+}
+
+// =============================================================================
+// Grouping
+// =============================================================================
+
+#[test]
+fn test_board_by_status() {
+    let env = setup();
+
+    let board = json_board(&env, &[]);
+    assert_eq!(board[0].0, "open");
+    assert_eq!(board[0].1, ["260101-AAA", "260102-BBB", "260103-CCC"]);
+    assert_eq!(board[1], ("closed".to_string(), vec![]));
+}
+
+#[test]
+fn test_board_by_label() {
+    let env = setup();
+
+    let board = json_board(
+        &env,
+        &["--by", "label", "--labels", "todo", "doing", "done"],
+    );
+    let names: Vec<_> = board.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["unlabeled", "todo", "doing", "done"]);
+    assert_eq!(board[0].1, ["260103-CCC"]);
+    assert_eq!(board[1].1, ["260101-AAA"]);
+    assert_eq!(board[2].1, ["260102-BBB"]);
+
+    // Without --labels, every label in use is a column
+    let names: Vec<_> = json_board(&env, &["--by", "label"])
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(names, ["unlabeled", "doing", "perf", "todo"]);
+}
+
+#[test]
+fn test_board_by_category() {
+    let env = setup();
+
+    let board = json_board(&env, &["--by", "category"]);
+    assert_eq!(
+        board,
+        [
+            ("uncategorized".to_string(), vec!["260101-AAA".to_string()]),
+            ("backend".to_string(), vec!["260102-BBB".to_string()]),
+            ("docs".to_string(), vec!["260103-CCC".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn test_board_prints_columns() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["board", "--by", "category"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("uncategorized (1)"))
+        .stdout(predicate::str::contains("backend (1)"))
+        .stdout(predicate::str::contains("260102-BBB  Slow queries"));
+}
+
+// =============================================================================
+// Moving Cards
+// =============================================================================
+
+#[test]
+fn test_move_by_status_closes_and_reopens() {
+    let env = setup();
+    let config = Config::load().unwrap();
+    let board = Board::new(&config, GroupBy::Status, &[]).unwrap();
+
+    move_to(&board, &config, "260101-AAA", "closed", &env);
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    assert!(path.starts_with(env.archive_path()));
+    assert_eq!(Item::load(&path).unwrap().status(), Status::Closed);
+
+    move_to(&board, &config, "260101-AAA", "open", &env);
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    assert!(!path.starts_with(env.archive_path()));
+    assert_eq!(Item::load(&path).unwrap().status(), Status::Open);
+}
+
+#[test]
+fn test_move_to_closed_returns_dependents() {
+    let env = setup();
+    commands::link(&LinkArgs {
+        id: Some("260101".to_string()),
+        blocks: vec!["260102".to_string(), "260103".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+    commands::link(&LinkArgs {
+        id: Some("260103".to_string()),
+        blocked_by: vec!["260102".to_string()],
+        ..Default::default()
+    })
+    .unwrap();
+    let config = Config::load().unwrap();
+    let board = Board::new(&config, GroupBy::Status, &[]).unwrap();
+
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let moved = board.move_item(&config, &path, 1).unwrap();
+    assert!(moved.path.starts_with(env.archive_path()));
+    assert_eq!(moved.notes, ["Unblocked: 260102-BBB"]);
+    assert_eq!(
+        moved.warnings,
+        ["'260103-CCC' is still blocked by open item(s): 260102-BBB"]
+    );
+}
+
+#[test]
+fn test_move_by_status_respects_transitions() {
+    let env = setup();
+    env.write_project_config(
+        r#"
+[workflow]
+states = ["todo", "doing", "done"]
+archived = ["done"]
+
+[workflow.transitions]
+todo = ["doing"]
+doing = ["done", "todo"]
+"#,
+    );
+    let config = Config::load().unwrap();
+    let board = Board::new(&config, GroupBy::Status, &[]).unwrap();
+    assert_eq!(board.columns(), ["todo", "doing", "done"]);
+
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let err = board.move_item(&config, &path, 2).unwrap_err();
+    assert!(err.to_string().contains("Cannot move"));
+
+    move_to(&board, &config, "260101-AAA", "doing", &env);
+    let item = Item::load(&env.find_item_by_id("260101-AAA").unwrap()).unwrap();
+    assert_eq!(item.state(), Some("doing"));
+}
+
+#[test]
+fn test_move_by_label_swaps_labels() {
+    let env = setup();
+    let config = Config::load().unwrap();
+    let labels = ["todo".to_string(), "doing".to_string(), "done".to_string()];
+    let board = Board::new(&config, GroupBy::Label, &labels).unwrap();
+
+    move_to(&board, &config, "260102-BBB", "done", &env);
+    let item = Item::load(&env.find_item_by_id("260102-BBB").unwrap()).unwrap();
+    assert_eq!(item.labels(), ["perf", "done"]);
+    assert!(item.frontmatter.updated_at.is_some());
+
+    // The unlabeled column removes the board's labels only
+    move_to(&board, &config, "260102-BBB", "unlabeled", &env);
+    let item = Item::load(&env.find_item_by_id("260102-BBB").unwrap()).unwrap();
+    assert_eq!(item.labels(), ["perf"]);
+}
+
+#[test]
+fn test_move_by_category_moves_file() {
+    let env = setup();
+    let config = Config::load().unwrap();
+    let board = Board::new(&config, GroupBy::Category, &[]).unwrap();
+
+    move_to(&board, &config, "260101-AAA", "docs", &env);
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    assert!(path.starts_with(env.stack_path().join("docs")));
+
+    move_to(&board, &config, "260102-BBB", "uncategorized", &env);
+    let path = env.find_item_by_id("260102-BBB").unwrap();
+    assert_eq!(path.parent().unwrap(), env.stack_path());
+}
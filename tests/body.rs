@@ -0,0 +1,187 @@
+//! # Body Input Tests
+//!
+//! Tests for `--body`, `--body-file`, and `--append-body` on `qs new` and
+//! `qs update`, including stdin input via `-`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Returns the Markdown body of an item file (everything after the frontmatter).
+fn body_of(env: &TestEnv, partial_id: &str) -> String {
+    let path = env.find_item_by_id(partial_id).expect("item should exist");
+    let content = env.read_item(&path);
+    let (_, body) = content[3..].split_once("\n---\n").expect("frontmatter");
+    body.trim_start_matches('\n').to_string()
+}
+
+// =============================================================================
+// qs new
+// =============================================================================
+
+#[test]
+fn test_new_with_body() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args([
+            "new",
+            "Crash",
+            "--no-interactive",
+            "--body",
+            "## Steps\n\n1. Open app",
+        ])
+        .assert()
+        .success();
+
+    assert_eq!(body_of(&env, "26"), "## Steps\n\n1. Open app\n");
+}
+
+#[test]
+fn test_new_with_body_from_stdin() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["new", "Crash", "--no-interactive", "--body", "-"])
+        .write_stdin("Piped body\n")
+        .assert()
+        .success();
+
+    assert_eq!(body_of(&env, "26"), "Piped body\n");
+}
+
+#[test]
+fn test_new_with_body_file() {
+    let env = setup_test_env_non_interactive();
+    let file = env.project_path().join("report.md");
+    fs::write(&file, "From a file.\n").unwrap();
+
+    qs_cmd(&env)
+        .args(["new", "Crash", "--no-interactive", "--body-file"])
+        .arg(&file)
+        .assert()
+        .success();
+
+    assert_eq!(body_of(&env, "26"), "From a file.\n");
+}
+
+#[test]
+fn test_new_with_missing_body_file_creates_nothing() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args([
+            "new",
+            "Crash",
+            "--no-interactive",
+            "--body-file",
+            "missing.md",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read body file"));
+
+    assert!(env.list_stack_files().is_empty());
+}
+
+#[test]
+fn test_new_body_and_body_file_conflict() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["new", "Crash", "--body", "a", "--body-file", "b.md"])
+        .assert()
+        .failure();
+}
+
+// =============================================================================
+// qs update
+// =============================================================================
+
+#[test]
+fn test_update_replaces_body() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--body", "New description"])
+        .assert()
+        .success();
+
+    assert_eq!(body_of(&env, "260101"), "New description\n");
+}
+
+#[test]
+fn test_update_append_body_from_stdin() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--append-body", "-"])
+        .write_stdin("Found the cause.\n")
+        .assert()
+        .success();
+
+    assert_eq!(
+        body_of(&env, "260101"),
+        "Test item body.\n\nFound the cause.\n"
+    );
+}
+
+#[test]
+fn test_update_body_keeps_comments() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "Keep me"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--body", "Rewritten"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--append-body", "Addendum"])
+        .assert()
+        .success();
+
+    let body = body_of(&env, "260101");
+    assert!(body.starts_with("Rewritten\n\nAddendum\n\n<!-- qs:comments -->"));
+    assert!(body.contains("Keep me"));
+    assert!(!body.contains("Test item body."));
+}
+
+#[test]
+fn test_update_empty_append_fails() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--append-body", " "])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Text to append cannot be empty"));
+}
+
+#[test]
+fn test_update_same_body_is_no_change() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--body", "Test item body."])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes to apply."));
+}
@@ -0,0 +1,363 @@
+//! # Bulk Operation Tests
+//!
+//! Tests for applying one change to many items: closing, reopening, labels,
+//! categories, and per-item results, from the TUI's bulk actions and from
+//! `--query`, `--stdin`, and `--dry-run` on the command line.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::bulk::{self, BulkOp},
+    config::Config,
+    item::{Item, Status},
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Sets up a project with three open items.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+
+    create_test_item(&env, "260101-AAA", "First", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], Some("backend"));
+    create_test_item(&env, "260103-CCC", "Third", "open", &["bug", "ui"], None);
+    env
+}
+
+/// Returns the current paths of the given item IDs.
+fn paths(env: &TestEnv, ids: &[&str]) -> Vec<PathBuf> {
+    ids.iter()
+        .map(|id| env.find_item_by_id(id).unwrap())
+        .collect()
+}
+
+/// Loads an item by ID.
+fn load(env: &TestEnv, id: &str) -> Item {
+    Item::load(&env.find_item_by_id(id).unwrap()).unwrap()
+}
+
+// =============================================================================
+// Operations
+// =============================================================================
+
+#[test]
+fn test_bulk_close_and_reopen() {
+    let env = setup();
+    let config = Config::load().unwrap();
+
+    let outcomes = bulk::apply(
+        &config,
+        &BulkOp::Close,
+        &paths(&env, &["260101-AAA", "260102-BBB"]),
+        false,
+    )
+    .unwrap();
+    assert!(outcomes.iter().all(|o| matches!(o.result, Ok(Some(_)))));
+    assert_eq!(env.list_archive_files().len(), 2);
+    assert_eq!(load(&env, "260101-AAA").status(), Status::Closed);
+
+    // Already open items are reported unchanged
+    let outcomes = bulk::apply(
+        &config,
+        &BulkOp::Reopen,
+        &paths(&env, &["260101-AAA", "260103-CCC"]),
+        false,
+    )
+    .unwrap();
+    assert!(matches!(outcomes[0].result, Ok(Some(_))));
+    assert!(matches!(outcomes[1].result, Ok(None)));
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_bulk_labels() {
+    let env = setup();
+    let config = Config::load().unwrap();
+    let all = paths(&env, &["260101-AAA", "260102-BBB", "260103-CCC"]);
+
+    let outcomes = bulk::apply(
+        &config,
+        &BulkOp::AddLabel("Urgent".to_string()),
+        &all,
+        false,
+    )
+    .unwrap();
+    assert!(outcomes.iter().all(|o| matches!(o.result, Ok(Some(_)))));
+    assert_eq!(load(&env, "260102-BBB").labels(), ["urgent"]);
+
+    let outcomes = bulk::apply(
+        &config,
+        &BulkOp::RemoveLabel("bug".to_string()),
+        &all,
+        false,
+    )
+    .unwrap();
+    assert!(matches!(outcomes[1].result, Ok(None)));
+    assert_eq!(load(&env, "260101-AAA").labels(), ["urgent"]);
+    assert_eq!(load(&env, "260103-CCC").labels(), ["ui", "urgent"]);
+}
+
+#[test]
+fn test_bulk_empty_label_fails() {
+    let env = setup();
+    let config = Config::load().unwrap();
+
+    let result = bulk::apply(
+        &config,
+        &BulkOp::AddLabel("  ".to_string()),
+        &paths(&env, &["260101-AAA"]),
+        false,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bulk_move_category() {
+    let env = setup();
+    let config = Config::load().unwrap();
+
+    let outcomes = bulk::apply(
+        &config,
+        &BulkOp::MoveCategory(Some("backend".to_string())),
+        &paths(&env, &["260101-AAA", "260102-BBB"]),
+        false,
+    )
+    .unwrap();
+    assert!(matches!(outcomes[0].result, Ok(Some(_))));
+    assert!(matches!(outcomes[1].result, Ok(None)));
+    assert_eq!(env.list_category_files("backend").len(), 2);
+
+    bulk::apply(
+        &config,
+        &BulkOp::MoveCategory(None),
+        &paths(&env, &["260101-AAA", "260102-BBB"]),
+        false,
+    )
+    .unwrap();
+    assert!(env.list_category_files("backend").is_empty());
+}
+
+#[test]
+fn test_bulk_reports_failures_per_item() {
+    let env = setup();
+    let config = Config::load().unwrap();
+
+    let mut targets = paths(&env, &["260101-AAA"]);
+    targets.push(env.stack_path().join("missing.md"));
+    targets.extend(paths(&env, &["260103-CCC"]));
+
+    let outcomes = bulk::apply(&config, &BulkOp::Close, &targets, false).unwrap();
+    assert!(matches!(outcomes[0].result, Ok(Some(_))));
+    assert!(outcomes[1].result.is_err());
+    assert!(matches!(outcomes[2].result, Ok(Some(_))));
+
+    let err = bulk::print_summary(&config, "Closed", &outcomes, false).unwrap_err();
+    assert!(err.to_string().contains("1 of 3 items failed"));
+}
+
+#[test]
+fn test_bulk_describe() {
+    assert_eq!(BulkOp::Close.describe(1), "Close 1 item");
+    assert_eq!(
+        BulkOp::RemoveLabel("bug".to_string()).describe(3),
+        "Remove label 'bug' from 3 items"
+    );
+    assert_eq!(
+        BulkOp::MoveCategory(None).describe(2),
+        "Move 2 items out of their category"
+    );
+}
+
+// =============================================================================
+// Command Line
+// =============================================================================
+
+#[test]
+fn test_close_where() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["close", "--query", "label:bug"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Closed item"))
+        .stdout(predicate::str::contains("2 changed, 0 unchanged, 0 failed"));
+    assert_eq!(env.list_archive_files().len(), 2);
+
+    // Reopen matches closed items
+    qs_cmd(&env)
+        .args(["reopen", "-q", "label:ui"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 changed"));
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_close_where_alias() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["close", "--where", "label:bug"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 changed, 0 unchanged, 0 failed"));
+    assert_eq!(env.list_archive_files().len(), 2);
+}
+
+#[test]
+fn test_close_stdin_from_list() {
+    let env = setup();
+
+    let listed = qs_cmd(&env)
+        .args(["list", "--label", "bug", "--no-interactive"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    qs_cmd(&env)
+        .args(["close", "--stdin"])
+        .write_stdin(listed)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 changed"));
+    assert_eq!(env.list_archive_files().len(), 2);
+}
+
+#[test]
+fn test_close_stdin_from_search_skips_snippets() {
+    let env = setup();
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(
+        &path,
+        format!("{content}\nThe crash happens on submit.\n260102 shows it too.\n"),
+    )
+    .unwrap();
+
+    let found = qs_cmd(&env)
+        .args(["search", "crash", "--full-text", "--no-interactive"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert!(String::from_utf8_lossy(&found).contains("    260102 shows it too."));
+
+    // Only the result line is used; snippet lines never resolve to other items
+    qs_cmd(&env)
+        .args(["close", "--stdin"])
+        .write_stdin(found)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 changed, 0 unchanged, 0 failed"));
+    assert_eq!(load(&env, "260101-AAA").status(), Status::Closed);
+    assert_eq!(load(&env, "260102-BBB").status(), Status::Open);
+}
+
+#[test]
+fn test_stdin_reports_unknown_items() {
+    let env = setup();
+
+    // Partial IDs are rejected on stdin
+    qs_cmd(&env)
+        .args(["close", "--stdin"])
+        .write_stdin("260101-AAA\nnope\n\n260103-CCC  Third\n260102\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("2 changed, 0 unchanged, 2 failed"))
+        .stderr(predicate::str::contains("nope"))
+        .stderr(predicate::str::contains("No item found with ID '260102'"));
+    assert_eq!(env.list_archive_files().len(), 2);
+}
+
+#[test]
+fn test_bulk_close_reports_dependents() {
+    let env = setup();
+    qs_cmd(&env)
+        .args(["link", "--id", "260101-AAA", "--blocks", "260102-BBB"])
+        .assert()
+        .success();
+
+    qs_cmd(&env)
+        .args(["close", "--query", "label:bug", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unblocked").not());
+
+    qs_cmd(&env)
+        .args(["close", "--query", "label:bug"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unblocked: 260102-BBB Second"));
+}
+
+#[test]
+fn test_dry_run_changes_nothing() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["close", "--query", "label:bug", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would change"))
+        .stdout(predicate::str::contains("2 would change"));
+    assert!(env.list_archive_files().is_empty());
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260102", "--label", "urgent", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 would change"));
+    assert!(load(&env, "260102-BBB").labels().is_empty());
+}
+
+#[test]
+fn test_update_where() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["update", "--query", "label:bug", "--label", "ui"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 changed, 1 unchanged, 0 failed"));
+    assert_eq!(load(&env, "260101-AAA").labels(), ["bug", "ui"]);
+
+    qs_cmd(&env)
+        .args(["update", "--query", "label:bug", "--category", "bugs"])
+        .assert()
+        .success();
+    assert_eq!(env.list_category_files("bugs").len(), 2);
+}
+
+#[test]
+fn test_update_where_rejects_title() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["update", "--query", "label:bug", "--title", "Same"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--title cannot be used"));
+}
+
+#[test]
+fn test_where_without_matches() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["close", "--query", "label:nothing"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No items found."));
+}
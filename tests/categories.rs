@@ -22,13 +22,14 @@ fn test_categories_empty_project() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -52,13 +53,14 @@ fn test_categories_shows_unique_categories() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -92,13 +94,14 @@ fn test_categories_includes_archived_items() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should include categories from both open and archived items
@@ -118,13 +121,14 @@ fn test_categories_without_init() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -0,0 +1,158 @@
+//! # Comment Command Tests
+//!
+//! Tests for `qs comment`: argument and stdin input, the comments section
+//! in the item body, and how `qs show` and JSON output present comments.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Login fails", "open", &["bug"], None);
+    env
+}
+
+fn read_item(env: &TestEnv) -> String {
+    let path = env.find_item_by_id("260101").expect("item should exist");
+    env.read_item(&path)
+}
+
+// =============================================================================
+// Adding Comments
+// =============================================================================
+
+#[test]
+fn test_comment_from_argument() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "Reproduced on macOS"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Commented on item"));
+
+    let content = read_item(&env);
+    assert!(content.contains("Test item body."), "Description is kept");
+    assert!(content.contains("<!-- qs:comments -->\n## Comments"));
+    assert!(content.contains(" — Test User\n\nReproduced on macOS\n"));
+}
+
+#[test]
+fn test_comment_from_stdin() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101"])
+        .write_stdin("Line one\n\n- a list item\n")
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "-"])
+        .write_stdin("Second comment\n")
+        .assert()
+        .success();
+
+    let content = read_item(&env);
+    assert!(content.contains("Line one\n\n- a list item\n"));
+    assert!(content.contains("Second comment"));
+    assert_eq!(content.matches("## Comments").count(), 1);
+}
+
+#[test]
+fn test_empty_comment_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "   "])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Comment cannot be empty"));
+
+    assert!(!read_item(&env).contains("## Comments"));
+}
+
+#[test]
+fn test_comments_survive_close() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "Fixed in main"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+
+    assert!(read_item(&env).contains("Fixed in main"));
+}
+
+// =============================================================================
+// Presentation
+// =============================================================================
+
+#[test]
+fn test_show_lists_comments_separately() {
+    let env = setup();
+
+    for text in ["First **note**", "Second note"] {
+        qs_cmd(&env)
+            .args(["comment", "--id", "260101", text])
+            .assert()
+            .success();
+    }
+
+    let output = qs_cmd(&env)
+        .args(["show", "--id", "260101"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(stdout.contains("Test item body."));
+    assert!(stdout.contains("Comments (2)"));
+    assert!(stdout.contains("Test User · "));
+    assert!(stdout.contains("  First note"), "Comment text is rendered");
+    assert!(!stdout.contains("qs:comments"), "Marker is hidden");
+    assert!(
+        stdout.find("Test item body.") < stdout.find("Comments (2)"),
+        "Description comes first"
+    );
+}
+
+#[test]
+fn test_json_includes_comments() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "Looks good"])
+        .assert()
+        .success();
+
+    let output = qs_cmd(&env)
+        .args(["show", "--id", "260101", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("stdout should be valid JSON");
+
+    let comments = json["comments"].as_array().expect("comments array");
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0]["author"], "Test User");
+    assert_eq!(comments[0]["text"], "Looks good");
+    assert!(comments[0]["created_at"].is_string());
+}
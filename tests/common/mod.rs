@@ -7,12 +7,16 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+// Each test binary compiles this module and uses only part of it.
+#![allow(dead_code, clippy::unused_self, clippy::option_if_let_else)]
+
 use std::{
     env, fs,
     path::{Path, PathBuf},
-    sync::Mutex,
+    sync::{Condvar, Mutex, PoisonError},
 };
 
+use assert_cmd::Command;
 use tempfile::TempDir;
 
 // Re-export from library - this is the mechanism for test isolation
@@ -20,7 +24,32 @@ use queuestack::set_home_override;
 
 /// Global lock to ensure tests run sequentially.
 /// This prevents races when tests change the current directory.
-static TEST_LOCK: Mutex<()> = Mutex::new(());
+///
+/// A flag (true while a `TestEnv` exists) rather than a `MutexGuard` kept in
+/// `TestEnv`, so tests can hold their environment to the end without tripping
+/// `clippy::significant_drop_tightening`.
+static TEST_LOCK: Mutex<bool> = Mutex::new(false);
+
+/// Signaled when a `TestEnv` releases the test lock.
+static TEST_LOCK_RELEASED: Condvar = Condvar::new();
+
+/// Waits until no other test holds the test lock, then takes it.
+fn acquire_test_lock() {
+    // Recover from poisoned mutex (if a previous test panicked while holding the lock)
+    let mut locked = TEST_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    while *locked {
+        locked = TEST_LOCK_RELEASED
+            .wait(locked)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+    *locked = true;
+}
+
+/// Releases the test lock and wakes up one waiting test.
+fn release_test_lock() {
+    *TEST_LOCK.lock().unwrap_or_else(PoisonError::into_inner) = false;
+    TEST_LOCK_RELEASED.notify_one();
+}
 
 /// Test environment that manages temporary directories for both
 /// the "home" directory (for global config) and the project directory.
@@ -31,9 +60,6 @@ pub struct TestEnv {
     pub project_dir: TempDir,
     /// Original current directory to restore on drop
     original_cwd: PathBuf,
-    /// Guard for the test lock
-    #[allow(dead_code)]
-    test_guard: std::sync::MutexGuard<'static, ()>,
 }
 
 impl TestEnv {
@@ -42,8 +68,7 @@ impl TestEnv {
     /// Uses thread-local storage to redirect global config (no env var modification).
     /// Changes to the project directory for the duration of the test.
     pub fn new() -> Self {
-        // Recover from poisoned mutex (if a previous test panicked while holding the lock)
-        let test_guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        acquire_test_lock();
 
         let home_dir = TempDir::new().expect("Failed to create temp home dir");
         let project_dir = TempDir::new().expect("Failed to create temp project dir");
@@ -59,7 +84,6 @@ impl TestEnv {
             home_dir,
             project_dir,
             original_cwd,
-            test_guard,
         }
     }
 
@@ -134,7 +158,7 @@ impl TestEnv {
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .collect()
     }
 
@@ -158,8 +182,7 @@ impl TestEnv {
 
     /// Counts total items across all locations.
     pub fn count_all_items(&self) -> usize {
-        let stack_count = self.count_items_recursive(&self.stack_path());
-        stack_count
+        self.count_items_recursive(&self.stack_path())
     }
 
     /// Counts .md files recursively in a directory.
@@ -187,7 +210,7 @@ impl TestEnv {
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .find(|p| {
                 p.file_name()
                     .and_then(|n| n.to_str())
@@ -203,6 +226,8 @@ impl Drop for TestEnv {
 
         // Clear the thread-local home override
         set_home_override(None);
+
+        release_test_lock();
     }
 }
 
@@ -235,6 +260,60 @@ pub fn setup_test_env_non_interactive() -> TestEnv {
     env
 }
 
+/// Creates a qs command configured to run in the test environment.
+pub fn qs_cmd(env: &TestEnv) -> Command {
+    let mut cmd = Command::cargo_bin("qs").unwrap();
+    cmd.current_dir(env.project_dir.path());
+    cmd.env("HOME", env.home_dir.path());
+    cmd
+}
+
+/// Runs `qs list --no-interactive` with extra arguments and returns stdout.
+pub fn list_output(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env)
+        .arg("list")
+        .arg("--no-interactive")
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+/// Runs a qs command with `--format json` and returns the records it printed.
+pub fn json_records(env: &TestEnv, args: &[&str]) -> Vec<serde_json::Value> {
+    let output = qs_cmd(env)
+        .args(args)
+        .args(["--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output).expect("stdout should be valid JSON");
+    json.as_array()
+        .expect("stdout should be a JSON array")
+        .clone()
+}
+
+/// Runs a qs command with `--format json` and returns the listed IDs in order.
+pub fn json_ids(env: &TestEnv, args: &[&str]) -> Vec<String> {
+    json_records(env, args)
+        .iter()
+        .map(|item| item["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Loads the only item in the stack directory.
+pub fn load_only_item(env: &TestEnv) -> queuestack::Item {
+    let files = env.list_stack_files();
+    assert_eq!(files.len(), 1, "Should have one item");
+    queuestack::Item::load(&files[0]).expect("item should load")
+}
+
 // =============================================================================
 // Config Builder Helpers
 // =============================================================================
@@ -243,7 +322,7 @@ pub fn setup_test_env_non_interactive() -> TestEnv {
 struct ConfigLines(Vec<String>);
 
 impl ConfigLines {
-    fn new() -> Self {
+    const fn new() -> Self {
         Self(Vec::new())
     }
 
@@ -259,6 +338,13 @@ impl ConfigLines {
         }
     }
 
+    fn add_list(&mut self, key: &str, values: Option<&[String]>) {
+        if let Some(values) = values {
+            let quoted: Vec<String> = values.iter().map(|v| format!("\"{v}\"")).collect();
+            self.0.push(format!("{key} = [{}]", quoted.join(", ")));
+        }
+    }
+
     fn build(self) -> String {
         self.0.join("\n")
     }
@@ -299,7 +385,7 @@ impl GlobalConfigBuilder {
         self
     }
 
-    pub fn use_git_user(mut self, use_git: bool) -> Self {
+    pub const fn use_git_user(mut self, use_git: bool) -> Self {
         self.use_git_user = use_git;
         self
     }
@@ -309,7 +395,7 @@ impl GlobalConfigBuilder {
         self
     }
 
-    pub fn interactive(mut self, interactive: bool) -> Self {
+    pub const fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
@@ -353,6 +439,8 @@ pub struct ProjectConfigBuilder {
     id_pattern: Option<String>,
     stack_dir: Option<String>,
     archive_dir: Option<String>,
+    priorities: Option<Vec<String>>,
+    index: Option<bool>,
 }
 
 impl Default for ProjectConfigBuilder {
@@ -365,6 +453,8 @@ impl Default for ProjectConfigBuilder {
             id_pattern: None,
             stack_dir: Some("queuestack".to_string()),
             archive_dir: Some("archive".to_string()),
+            priorities: None,
+            index: None,
         }
     }
 }
@@ -380,7 +470,7 @@ impl ProjectConfigBuilder {
     }
 
     #[allow(dead_code)]
-    pub fn use_git_user(mut self, use_git: bool) -> Self {
+    pub const fn use_git_user(mut self, use_git: bool) -> Self {
         self.use_git_user = Some(use_git);
         self
     }
@@ -392,7 +482,7 @@ impl ProjectConfigBuilder {
     }
 
     #[allow(dead_code)]
-    pub fn interactive(mut self, interactive: bool) -> Self {
+    pub const fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = Some(interactive);
         self
     }
@@ -412,6 +502,17 @@ impl ProjectConfigBuilder {
         self
     }
 
+    pub fn priorities(mut self, levels: &[&str]) -> Self {
+        self.priorities = Some(levels.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    #[allow(dead_code)]
+    pub const fn index(mut self, enabled: bool) -> Self {
+        self.index = Some(enabled);
+        self
+    }
+
     pub fn build(&self) -> String {
         let mut lines = ConfigLines::new();
         lines.add_string("user_name", self.user_name.as_deref());
@@ -421,6 +522,8 @@ impl ProjectConfigBuilder {
         lines.add_string("id_pattern", self.id_pattern.as_deref());
         lines.add_string("stack_dir", self.stack_dir.as_deref());
         lines.add_string("archive_dir", self.archive_dir.as_deref());
+        lines.add_list("priorities", self.priorities.as_deref());
+        lines.add_bool("index", self.index);
         lines.build()
     }
 }
@@ -443,7 +546,7 @@ pub fn make_item_content(
             "\n{}",
             labels
                 .iter()
-                .map(|l| format!("  - {}", l))
+                .map(|l| format!("  - {l}"))
                 .collect::<Vec<_>>()
                 .join("\n")
         )
@@ -458,7 +561,7 @@ pub fn make_item_content(
         Some(att) if !att.is_empty() => format!(
             "attachments:\n{}",
             att.iter()
-                .map(|a| format!("  - {}", a))
+                .map(|a| format!("  - {a}"))
                 .collect::<Vec<_>>()
                 .join("\n")
         ),
@@ -466,7 +569,7 @@ pub fn make_item_content(
     };
 
     format!(
-        r#"---
+        r"---
 id: {id}
 title: {title}
 author: Test User
@@ -478,7 +581,7 @@ labels: {labels_yaml}
 ---
 
 Test item body.
-"#
+"
     )
 }
 
@@ -497,7 +600,7 @@ pub fn create_test_item(
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
         .collect::<String>();
 
-    let filename = format!("{}-{}.md", id, slug);
+    let filename = format!("{id}-{slug}.md");
     let content = make_item_content(id, title, status, labels, category, None);
 
     let dir = if let Some(cat) = category {
@@ -533,7 +636,7 @@ impl TestEnv {
             .into_iter()
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .filter(|p| {
                 p.file_name()
                     .and_then(|n| n.to_str())
@@ -556,8 +659,7 @@ impl TestEnv {
     pub fn attachment_exists(&self, item_path: &Path, attachment_name: &str) -> bool {
         item_path
             .parent()
-            .map(|dir| dir.join(attachment_name).exists())
-            .unwrap_or(false)
+            .is_some_and(|dir| dir.join(attachment_name).exists())
     }
 
     /// Lists attachment files in the archive directory for an item ID.
@@ -581,7 +683,7 @@ pub fn create_test_item_with_attachments(
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
         .collect::<String>();
 
-    let filename = format!("{}-{}.md", id, slug);
+    let filename = format!("{id}-{slug}.md");
     let content = make_item_content(id, title, status, &[], category, Some(attachments));
 
     let dir = if let Some(cat) = category {
@@ -1,6 +1,6 @@
 //! # Config Tests
 //!
-//! Tests for configuration handling including interactive flags, use_git_user,
+//! Tests for configuration handling including interactive flags, `use_git_user`,
 //! editor settings, and custom directory configurations.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
@@ -16,7 +16,7 @@ use queuestack::config::GlobalConfig;
 // Config Combination Tests (interactive + no_interactive)
 // =============================================================================
 
-/// Tests that commands work correctly with interactive=true and no_interactive=false.
+/// Tests that commands work correctly with interactive=true and `no_interactive=false`.
 /// Note: Editor won't actually open in tests because stdout is not a terminal.
 #[test]
 fn test_config_interactive_true_no_interactive_false() {
@@ -36,6 +36,7 @@ fn test_config_interactive_true_no_interactive_false() {
         }, // Would open editor if in terminal
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -43,7 +44,7 @@ fn test_config_interactive_true_no_interactive_false() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that no_interactive flag overrides interactive=true config.
+/// Tests that `no_interactive` flag overrides interactive=true config.
 #[test]
 fn test_config_interactive_true_no_interactive_true() {
     let env = TestEnv::new();
@@ -62,6 +63,7 @@ fn test_config_interactive_true_no_interactive_true() {
         }, // Overrides interactive
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -69,7 +71,7 @@ fn test_config_interactive_true_no_interactive_true() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that with interactive=false, editor never opens regardless of no_interactive.
+/// Tests that with interactive=false, editor never opens regardless of `no_interactive`.
 #[test]
 fn test_config_interactive_false_no_interactive_false() {
     let env = TestEnv::new();
@@ -88,6 +90,7 @@ fn test_config_interactive_false_no_interactive_false() {
         }, // Doesn't matter since interactive is false
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -95,7 +98,7 @@ fn test_config_interactive_false_no_interactive_false() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that both interactive=false and no_interactive=true definitely prevents editor.
+/// Tests that both interactive=false and `no_interactive=true` definitely prevents editor.
 #[test]
 fn test_config_interactive_false_no_interactive_true() {
     let env = TestEnv::new();
@@ -113,6 +116,7 @@ fn test_config_interactive_false_no_interactive_true() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -124,7 +128,7 @@ fn test_config_interactive_false_no_interactive_true() {
 // use_git_user Config Tests
 // =============================================================================
 
-/// Tests that use_git_user=false prevents using git user.name even if available.
+/// Tests that `use_git_user=false` prevents using git user.name even if available.
 #[test]
 fn test_use_git_user_disabled() {
     let env = TestEnv::new();
@@ -148,6 +152,7 @@ fn test_use_git_user_disabled() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -160,7 +165,7 @@ fn test_use_git_user_disabled() {
     );
 }
 
-/// Tests that use_git_user=true allows falling back to git config.
+/// Tests that `use_git_user=true` allows falling back to git config.
 /// Note: This test verifies the config is parsed correctly; actual git fallback
 /// depends on git being configured on the test machine.
 #[test]
@@ -186,6 +191,7 @@ fn test_use_git_user_enabled_with_explicit_name() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -243,7 +249,7 @@ fn test_editor_with_arguments() {
     );
 }
 
-/// Tests that Config::editor() returns the configured value.
+/// Tests that `Config::editor()` returns the configured value.
 #[test]
 fn test_config_editor_resolution() {
     use queuestack::Config;
@@ -294,6 +300,7 @@ fn test_custom_stack_directory() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -367,6 +374,7 @@ fn test_different_users_in_parallel() {
             },
             as_template: false,
             from_template: None,
+            ..Default::default()
         };
 
         commands::new(args).expect("new should succeed");
@@ -392,6 +400,7 @@ fn test_different_users_in_parallel() {
             },
             as_template: false,
             from_template: None,
+            ..Default::default()
         };
 
         commands::new(args).expect("new should succeed");
@@ -550,7 +559,7 @@ invalid_option = true
     assert_eq!(config.template_dir, Some("blueprints".to_string()));
 }
 
-/// Tests that setup migrates legacy field names (default_id_pattern -> id_pattern).
+/// Tests that setup migrates legacy field names (`default_id_pattern` -> `id_pattern`).
 #[test]
 fn test_setup_migrates_legacy_fields() {
     let env = TestEnv::new();
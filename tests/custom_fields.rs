@@ -0,0 +1,350 @@
+//! # Custom Field Tests
+//!
+//! Tests for custom frontmatter fields: round-trip preservation,
+//! `--set/--unset`, `qs list --where`, and the typed field schema.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use common::{list_output, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands::{self, UpdateArgs};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Writes an item whose frontmatter contains keys queuestack doesn't know.
+fn create_item_with_extra_keys(env: &TestEnv, id: &str, title: &str, extra: &str) -> PathBuf {
+    let slug = title.to_lowercase().replace(' ', "-");
+    let content = format!(
+        "---\n\
+         id: {id}\n\
+         estimate: 3\n\
+         title: {title}\n\
+         author: Test User\n\
+         created_at: 2026-01-01T00:00:00Z\n\
+         status: open\n\
+         {extra}\
+         ---\n\n\
+         Body text\n"
+    );
+    let path = env.stack_path().join(format!("{id}-{slug}.md"));
+    fs::write(&path, content).expect("Failed to write item file");
+    path
+}
+
+fn set_fields(id: &str, pairs: &[(&str, &str)]) -> anyhow::Result<()> {
+    commands::update(UpdateArgs {
+        id: Some(id.to_string()),
+        set_fields: pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect(),
+        ..Default::default()
+    })
+}
+
+/// Returns the line positions of the given keys in the item's frontmatter.
+fn key_positions(content: &str, keys: &[&str]) -> Vec<usize> {
+    keys.iter()
+        .map(|key| {
+            content
+                .lines()
+                .position(|line| line.starts_with(&format!("{key}:")))
+                .unwrap_or_else(|| panic!("key '{key}' missing from:\n{content}"))
+        })
+        .collect()
+}
+
+// =============================================================================
+// Round-Trip Preservation
+// =============================================================================
+
+#[test]
+fn test_unknown_keys_survive_update_and_keep_order() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "sprint: Q3\n");
+
+    commands::update(UpdateArgs {
+        id: Some("260101".to_string()),
+        title: Some("Renamed Task".to_string()),
+        ..Default::default()
+    })
+    .expect("update should succeed");
+
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    let positions = key_positions(&content, &["id", "estimate", "title", "sprint"]);
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "Key order should be preserved:\n{content}"
+    );
+    assert!(content.contains("estimate: 3"));
+    assert!(content.contains("sprint: Q3"));
+}
+
+#[test]
+fn test_unknown_keys_survive_close_and_attachments() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "");
+
+    qs_cmd(&env)
+        .args([
+            "attachments",
+            "add",
+            "--id",
+            "260101",
+            "https://example.com",
+        ])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+
+    let path = env.find_item_by_id("260101").expect("item should exist");
+    assert!(path.starts_with(env.archive_path()));
+    assert!(env.read_item(&path).contains("estimate: 3"));
+}
+
+// =============================================================================
+// Set / Unset
+// =============================================================================
+
+#[test]
+fn test_set_fields_keeps_scalar_types() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "");
+
+    set_fields("260101", &[("estimate", "5"), ("team", "Backend")]).expect("set should succeed");
+
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(content.contains("estimate: 5"), "Numbers stay unquoted");
+    assert!(content.contains("team: Backend"));
+    // Existing keys are updated in place, new keys are appended
+    let positions = key_positions(&content, &["estimate", "title", "team"]);
+    assert!(positions[0] < positions[1] && positions[1] < positions[2]);
+}
+
+#[test]
+fn test_set_reserved_key_fails() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "");
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--set", "status=closed"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("built-in field"));
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--set", "novalue"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected key=value"));
+}
+
+#[test]
+fn test_unset_field() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "sprint: Q3\n");
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--unset", "estimate", "sprint"])
+        .assert()
+        .success();
+
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(!content.contains("estimate"));
+    assert!(!content.contains("sprint"));
+
+    // Unsetting a missing field is a no-op
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--unset", "estimate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes"));
+}
+
+// =============================================================================
+// List Filtering
+// =============================================================================
+
+#[test]
+fn test_list_where_filters_by_field() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "First", "sprint: 12\n");
+    create_item_with_extra_keys(&env, "260102-BBB", "Second", "sprint: 13\n");
+    create_item_with_extra_keys(&env, "260103-CCC", "Third", "");
+
+    let stdout = list_output(&env, &["--where", "sprint=12"]);
+    assert_eq!(stdout.trim(), "queuestack/260101-AAA-first.md");
+
+    // Multiple filters use AND logic
+    let stdout = list_output(&env, &["--where", "sprint=13", "--where", "estimate=3"]);
+    assert_eq!(stdout.trim(), "queuestack/260102-BBB-second.md");
+
+    let stdout = list_output(&env, &["--where", "sprint=12", "--where", "estimate=4"]);
+    assert!(stdout.contains("No items found"));
+}
+
+#[test]
+fn test_json_output_includes_fields() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "sprint: Q3\n");
+
+    let stdout = list_output(&env, &["--format", "json"]);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json[0]["fields"]["estimate"], 3);
+    assert_eq!(json[0]["fields"]["sprint"], "Q3");
+}
+
+// =============================================================================
+// Field Schema
+// =============================================================================
+
+const SCHEMA: &str = r#"
+[[fields]]
+name = "severity"
+type = "enum"
+values = ["minor", "major", "blocker"]
+default = "minor"
+
+[[fields]]
+name = "estimate"
+type = "int"
+
+[[fields]]
+name = "reviewed"
+type = "bool"
+"#;
+
+fn setup_with_schema() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(SCHEMA);
+    env
+}
+
+fn new_item(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env)
+        .args(["new", "--no-interactive"])
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let path = String::from_utf8(output).unwrap();
+    env.read_item(&env.project_path().join(path.trim()))
+}
+
+#[test]
+fn test_new_item_gets_schema_defaults() {
+    let env = setup_with_schema();
+
+    let content = new_item(&env, &["Crash on save"]);
+    assert!(content.contains("severity: minor"));
+    assert!(!content.contains("estimate"), "No default, no value");
+}
+
+#[test]
+fn test_new_item_validates_schema_values() {
+    let env = setup_with_schema();
+
+    // Enum values match case-insensitively and are stored as configured
+    let content = new_item(
+        &env,
+        &[
+            "Crash",
+            "--set",
+            "severity=MAJOR",
+            "estimate=3",
+            "reviewed=yes",
+        ],
+    );
+    assert!(content.contains("severity: major"));
+    assert!(content.contains("estimate: 3"));
+    assert!(content.contains("reviewed: true"));
+
+    qs_cmd(&env)
+        .args(["new", "--no-interactive", "Bad", "--set", "severity=huge"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "expected one of: minor, major, blocker",
+        ));
+
+    qs_cmd(&env)
+        .args(["new", "--no-interactive", "Bad", "--set", "estimate=lots"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a whole number"));
+}
+
+#[test]
+fn test_required_field() {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config("[[fields]]\nname = \"component\"\nrequired = true\n");
+
+    qs_cmd(&env)
+        .args(["new", "--no-interactive", "Task"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'component' is required"));
+
+    new_item(&env, &["Task", "--set", "component=api"]);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "26", "--unset", "component"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be removed"));
+}
+
+#[test]
+fn test_update_validates_schema_values() {
+    let env = setup_with_schema();
+    create_item_with_extra_keys(&env, "260101-AAA", "Task", "");
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--set", "estimate=soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a whole number"));
+
+    set_fields("260101", &[("severity", "Blocker")]).expect("set should succeed");
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(content.contains("severity: blocker"));
+}
+
+#[test]
+fn test_invalid_schema_is_reported() {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config("[[fields]]\nname = \"kind\"\ntype = \"enum\"\n");
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid field schema"));
+}
+
+#[test]
+fn test_list_where_compares_typed_values() {
+    let env = setup_with_schema();
+    new_item(&env, &["Small", "--set", "estimate=1"]);
+    new_item(&env, &["Large", "--set", "estimate=8", "severity=major"]);
+
+    let stdout = list_output(&env, &["--where", "estimate>=3"]);
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.contains("large"));
+
+    let stdout = list_output(&env, &["--where", "severity!=minor"]);
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.contains("large"));
+}
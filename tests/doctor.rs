@@ -0,0 +1,198 @@
+//! # Doctor Tests
+//!
+//! Tests for `qs doctor`: detecting parse errors, duplicate IDs, stale
+//! filenames, misplaced items, and missing or orphaned attachments, and the
+//! repairs applied with `--fix`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, setup_test_env_non_interactive,
+};
+use predicates::prelude::*;
+use queuestack::{
+    commands::doctor::{self, ProblemKind, Repair},
+    config::Config,
+    item::{Item, Status},
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Returns the kinds of all problems found.
+fn kinds() -> Vec<ProblemKind> {
+    let config = Config::load().unwrap();
+    doctor::diagnose(&config)
+        .into_iter()
+        .map(|problem| problem.kind)
+        .collect()
+}
+
+// =============================================================================
+// Diagnostics
+// =============================================================================
+
+#[test]
+fn test_doctor_clean_project() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], Some("backend"));
+
+    qs_cmd(&env)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No problems found."));
+}
+
+#[test]
+fn test_doctor_reports_parse_errors_and_duplicates() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260101-AAA", "Copy", "open", &[], Some("backend"));
+    fs::write(env.stack_path().join("broken.md"), "no frontmatter").unwrap();
+
+    let mut found = kinds();
+    found.sort_by_key(|kind| format!("{kind:?}"));
+    assert_eq!(found, [ProblemKind::DuplicateId, ProblemKind::ParseError]);
+
+    // Neither has a safe repair
+    qs_cmd(&env)
+        .args(["doctor", "--fix"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("broken.md: Failed to parse"))
+        .stdout(predicate::str::contains("Duplicate ID 260101-AAA"))
+        .stdout(predicate::str::contains("2 problems found"));
+}
+
+#[test]
+fn test_doctor_reports_filename_mismatch() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    fs::rename(&path, env.stack_path().join("260101-AAA-old-title.md")).unwrap();
+
+    let config = Config::load().unwrap();
+    let problems = doctor::diagnose(&config);
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].kind, ProblemKind::FilenameMismatch);
+    assert_eq!(
+        problems[0].repair,
+        Some(Repair::Rename("260101-AAA-first.md".to_string()))
+    );
+
+    qs_cmd(&env)
+        .arg("doctor")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("expected 260101-AAA-first.md"))
+        .stdout(predicate::str::contains("1 can be fixed with --fix"));
+}
+
+#[test]
+fn test_doctor_reports_wrong_location() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "closed", &[], None);
+    let path = create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+    fs::create_dir_all(env.archive_path()).unwrap();
+    fs::rename(&path, env.archive_path().join("260102-BBB-second.md")).unwrap();
+
+    let config = Config::load().unwrap();
+    let repairs: Vec<_> = doctor::diagnose(&config)
+        .into_iter()
+        .map(|problem| problem.repair)
+        .collect();
+    assert!(repairs.contains(&Some(Repair::Archive)));
+    assert!(repairs.contains(&Some(Repair::Unarchive)));
+}
+
+#[test]
+fn test_doctor_reports_attachments() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "First",
+        "open",
+        &["260101-AAA-Attachment-1-log.txt", "https://example.com"],
+        None,
+    );
+    fs::remove_file(path.with_file_name("260101-AAA-Attachment-1-log.txt")).unwrap();
+    fs::write(
+        env.stack_path().join("260101-AAA-Attachment-2-notes.md"),
+        "notes",
+    )
+    .unwrap();
+    fs::write(env.stack_path().join("260199-ZZZ-Attachment-1-old.png"), "").unwrap();
+
+    let config = Config::load().unwrap();
+    let problems = doctor::diagnose(&config);
+    let of_kind = |kind| problems.iter().filter(move |p| p.kind == kind);
+    assert_eq!(of_kind(ProblemKind::MissingAttachment).count(), 1);
+
+    // An unlisted attachment next to its item can be listed, a lost one can't
+    let orphans: Vec<_> = of_kind(ProblemKind::OrphanedAttachment).collect();
+    assert_eq!(orphans.len(), 2);
+    assert_eq!(orphans[0].repair, Some(Repair::LinkAttachment(path)));
+    assert!(orphans[1].message.contains("no item with ID 260199-ZZZ"));
+    assert!(orphans[1].repair.is_none());
+}
+
+// =============================================================================
+// Repairs
+// =============================================================================
+
+#[test]
+fn test_doctor_fix_renames_and_moves() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "First", "closed", &[], Some("backend"));
+    fs::rename(&path, path.with_file_name("260101-AAA-old-title.md")).unwrap();
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+
+    // Renaming and archiving the same item takes two passes
+    qs_cmd(&env)
+        .args(["doctor", "--fix"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("renamed to 260101-AAA-first.md"))
+        .stdout(predicate::str::contains("archived to"))
+        .stdout(predicate::str::contains("No problems found."));
+
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    assert_eq!(
+        path,
+        env.archive_path()
+            .join("backend")
+            .join("260101-AAA-first.md")
+    );
+    assert_eq!(Item::load(&path).unwrap().status(), Status::Closed);
+}
+
+#[test]
+fn test_doctor_fix_attachments() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "First", "open", &[], Some("backend"));
+    fs::write(env.stack_path().join("260101-AAA-Attachment-1-log.txt"), "").unwrap();
+
+    // The attachment is moved next to its item, then listed
+    qs_cmd(&env)
+        .args(["doctor", "--fix"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("moved to"))
+        .stdout(predicate::str::contains("listed in"));
+
+    assert!(env.attachment_exists(&path, "260101-AAA-Attachment-1-log.txt"));
+    let item = Item::load(&path).unwrap();
+    assert_eq!(item.attachments(), ["260101-AAA-Attachment-1-log.txt"]);
+    assert!(
+        item.updated_at().is_some(),
+        "listing an attachment is a change"
+    );
+}
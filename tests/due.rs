@@ -0,0 +1,196 @@
+//! # Due Date Tests
+//!
+//! Tests for item due dates: setting via `qs new`/`qs update`, relative
+//! expressions, due/overdue filters, and due date sorting.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use chrono::{Days, NaiveDate};
+use common::{
+    create_test_item, list_output, load_only_item, qs_cmd, setup_test_env_non_interactive,
+};
+use queuestack::{
+    commands::{self, InteractiveArgs, NewArgs, UpdateArgs},
+    item::due::today,
+    Item,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn set_due(id: &str, due: &str) {
+    commands::update(UpdateArgs {
+        id: Some(id.to_string()),
+        due: Some(due.to_string()),
+        ..Default::default()
+    })
+    .expect("update should succeed");
+}
+
+// =============================================================================
+// Setting Due Dates
+// =============================================================================
+
+#[test]
+fn test_new_with_absolute_due_date() {
+    let env = setup_test_env_non_interactive();
+
+    commands::new(NewArgs {
+        title: Some("Release".to_string()),
+        due: Some("2026-03-15".to_string()),
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        ..Default::default()
+    })
+    .expect("new should succeed");
+
+    let item = load_only_item(&env);
+    assert_eq!(item.due(), NaiveDate::from_ymd_opt(2026, 3, 15));
+
+    let content = env.read_item(item.path.as_ref().unwrap());
+    let due_line = content.lines().find(|l| l.starts_with("due:")).unwrap();
+    assert_eq!(
+        due_line.trim_start_matches("due:").trim().trim_matches('"'),
+        "2026-03-15"
+    );
+}
+
+#[test]
+fn test_hand_written_due_date_is_parsed() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+    let content = env
+        .read_item(&path)
+        .replacen("status: open", "status: open\ndue: 2026-01-31", 1);
+    std::fs::write(&path, content).unwrap();
+
+    assert_eq!(
+        Item::load(&path).unwrap().due(),
+        NaiveDate::from_ymd_opt(2026, 1, 31)
+    );
+}
+
+#[test]
+fn test_new_with_relative_due_date() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["new", "Soon", "--due", "+3d", "--no-interactive"])
+        .assert()
+        .success();
+
+    let expected = today().checked_add_days(Days::new(3));
+    assert_eq!(load_only_item(&env).due(), expected);
+}
+
+#[test]
+fn test_new_with_invalid_due_date_fails() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["new", "Someday", "--due", "someday", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid due date"));
+
+    assert!(
+        env.list_stack_files().is_empty(),
+        "No item should be created"
+    );
+}
+
+#[test]
+fn test_update_set_and_remove_due_date() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    set_due("260101", "2026-02-01");
+    assert_eq!(
+        load_only_item(&env).due(),
+        NaiveDate::from_ymd_opt(2026, 2, 1)
+    );
+
+    commands::update(UpdateArgs {
+        id: Some("260101".to_string()),
+        remove_due: true,
+        ..Default::default()
+    })
+    .expect("update should succeed");
+
+    let item = load_only_item(&env);
+    assert_eq!(item.due(), None);
+    let content = env.read_item(item.path.as_ref().unwrap());
+    assert!(!content.contains("due:"), "Cleared due date is omitted");
+}
+
+// =============================================================================
+// Filtering and Sorting
+// =============================================================================
+
+#[test]
+fn test_list_overdue_and_due_range() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Past", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Next Week", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "No Due", "open", &[], None);
+    set_due("260101", "-2d");
+    set_due("260102", "+7d");
+
+    let stdout = list_output(&env, &["--overdue"]);
+    assert_eq!(stdout.trim(), "queuestack/260101-AAA-past.md");
+
+    let stdout = list_output(&env, &["--due-after", "today"]);
+    assert_eq!(stdout.trim(), "queuestack/260102-BBB-next-week.md");
+
+    let stdout = list_output(&env, &["--due-before", "+30d"]);
+    assert_eq!(stdout.lines().count(), 2, "Items without due date excluded");
+}
+
+#[test]
+fn test_list_overdue_excludes_closed_items() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Past", "open", &[], None);
+    set_due("260101", "-2d");
+
+    commands::execute_close(Some("260101".to_string()), None).expect("close should succeed");
+
+    let stdout = list_output(&env, &["--overdue", "--closed"]);
+    assert!(stdout.contains("No items found"));
+}
+
+#[test]
+fn test_list_sort_by_due() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Later", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Undated", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Sooner", "open", &[], None);
+    set_due("260101", "2026-05-01");
+    set_due("260103", "2026-04-01");
+
+    let stdout = list_output(&env, &["--sort", "due"]);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "queuestack/260103-CCC-sooner.md",
+            "queuestack/260101-AAA-later.md",
+            "queuestack/260102-BBB-undated.md",
+        ]
+    );
+}
+
+#[test]
+fn test_list_invalid_due_filter_fails() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["list", "--due-before", "whenever"])
+        .assert()
+        .failure();
+}
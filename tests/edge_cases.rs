@@ -32,6 +32,7 @@ fn test_special_characters_in_title() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed with special characters");
@@ -55,6 +56,7 @@ fn test_unicode_in_title() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed with unicode");
@@ -69,7 +71,7 @@ fn test_empty_title() {
 
     // Empty title should be rejected
     let args = NewArgs {
-        title: Some("".to_string()),
+        title: Some(String::new()),
         labels: vec![],
         category: None,
         attachments: vec![],
@@ -79,6 +81,7 @@ fn test_empty_title() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -104,6 +107,7 @@ fn test_very_long_title() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed with long title");
@@ -132,6 +136,7 @@ fn test_whitespace_only_title() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     // Whitespace-only title should be rejected (trimmed to empty)
@@ -157,6 +162,7 @@ fn test_category_with_special_characters() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -187,6 +193,7 @@ fn test_label_with_special_characters() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -218,6 +225,7 @@ fn test_duplicate_labels_ignored() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -255,6 +263,7 @@ fn test_partial_id_matching() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update with partial ID should succeed");
@@ -278,6 +287,7 @@ fn test_ambiguous_partial_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     let result = commands::update(args);
@@ -0,0 +1,187 @@
+//! # Export Tests
+//!
+//! Tests for `qs export`: CSV, JSON, and HTML output, the shared `qs list`
+//! filters, and writing to a file.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, setup_test_env_non_interactive,
+    TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::item::{Item, Status};
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Creates an item without the legacy `category` key the test helper writes.
+fn create_item(
+    env: &TestEnv,
+    id: &str,
+    title: &str,
+    labels: &[&str],
+    category: Option<&str>,
+) -> Item {
+    let path = create_test_item(env, id, title, "open", labels, category);
+    let mut item = Item::load(&path).unwrap();
+    item.remove_custom_field("category");
+    item.path = Some(path);
+    item
+}
+
+/// Sets up a project with an open bug (with a custom field and a body), an
+/// open uncategorized item, and an archived bug.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+
+    let mut item = create_item(&env, "260101-AAA", "Login crash", &["bug"], Some("backend"));
+    item.set_custom_field("sprint", serde_yml::Value::from(12))
+        .unwrap();
+    item.set_description("Steps:\n\n1. Open the **login** page\n2. Type <b>\n");
+    item.save(item.path.as_ref().unwrap()).unwrap();
+
+    let item = create_item(&env, "260102-BBB", "Docs, part two", &[], None);
+    item.save(item.path.as_ref().unwrap()).unwrap();
+
+    let mut item = create_item(&env, "260103-CCC", "Old bug", &["bug"], None);
+    item.set_status(Status::Closed);
+    fs::create_dir_all(env.archive_path()).unwrap();
+    item.save(&env.archive_path().join("260103-CCC-old-bug.md"))
+        .unwrap();
+    fs::remove_file(item.path.as_ref().unwrap()).unwrap();
+    env
+}
+
+/// Runs `qs export` with the given arguments and returns stdout.
+fn export(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env)
+        .arg("export")
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+// =============================================================================
+// Formats
+// =============================================================================
+
+#[test]
+fn test_export_csv() {
+    let env = setup();
+
+    let csv = export(&env, &["--format", "csv"]);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3, "header and two open items: {csv}");
+    assert!(lines[0].starts_with("id,title,status,state,category,labels,"));
+    assert!(lines[0].ends_with(",path,sprint"));
+    assert!(lines[1].starts_with("260101-AAA,Login crash,open,,backend,bug,"));
+    assert!(lines[1].ends_with(",12"));
+    assert!(lines[2].starts_with("260102-BBB,\"Docs, part two\",open,"));
+}
+
+#[test]
+fn test_export_json_includes_bodies() {
+    let env = setup();
+
+    let json: Value = serde_json::from_str(&export(&env, &["--format", "json"])).unwrap();
+    let items = json.as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["category"], "backend");
+    assert_eq!(items[0]["fields"]["sprint"], 12);
+    assert!(items[0]["body"].as_str().unwrap().contains("**login**"));
+}
+
+#[test]
+fn test_export_uses_list_filters() {
+    let env = setup();
+
+    let jsonl = export(&env, &["--format", "jsonl", "--closed", "--label", "bug"]);
+    let ids: Vec<String> = jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap()["id"].to_string())
+        .collect();
+    assert_eq!(ids, ["\"260103-CCC\""]);
+
+    let csv = export(&env, &["--format", "csv", "--where", "sprint=12"]);
+    assert_eq!(csv.lines().count(), 2);
+}
+
+#[test]
+fn test_export_html_report() {
+    let env = setup();
+    create_test_item_with_attachments(
+        &env,
+        "260104-DDD",
+        "Has files",
+        "open",
+        &[
+            "260104-DDD-Attachment-1-log.txt",
+            "https://example.com/a?b=1&c=2",
+        ],
+        Some("backend"),
+    );
+    fs::create_dir(env.project_dir.path().join("reports")).unwrap();
+
+    qs_cmd(&env)
+        .args([
+            "export",
+            "--format",
+            "html",
+            "--output",
+            "reports/backlog.html",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Exported 3 items to reports/backlog.html",
+        ));
+
+    let html = fs::read_to_string(env.project_dir.path().join("reports/backlog.html")).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<section id=\"uncategorized\">"));
+    assert!(html.contains("<section id=\"category-backend\">"));
+    assert!(html.contains("<article id=\"item-260101-AAA\">"));
+    assert!(html.contains("<dt>sprint</dt><dd>12</dd>"));
+
+    // Bodies are rendered, raw HTML is escaped
+    assert!(html.contains("<strong>login</strong>"));
+    assert!(html.contains("Type &lt;b&gt;"));
+
+    // Attachments link relative to the report
+    assert!(html.contains(
+        "<a href=\"../queuestack/backend/260104-DDD-Attachment-1-log.txt\">260104-DDD-Attachment-1-log.txt</a>"
+    ));
+    assert!(html.contains("<a href=\"https://example.com/a?b=1&amp;c=2\">"));
+}
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+#[test]
+fn test_export_requires_format() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .arg("export")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Choose an export format"));
+    qs_cmd(&env)
+        .args(["list", "--format", "csv"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("only supported by qs export"));
+}
@@ -0,0 +1,310 @@
+//! # Import Tests
+//!
+//! Tests for `qs import`: mapping GitHub, GitLab, and Jira exports onto
+//! items, archiving closed issues, and skipping issues imported before.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{qs_cmd, setup_test_env_non_interactive};
+use predicates::prelude::*;
+use queuestack::{
+    commands::import::{self, Source},
+    config::Config,
+    item::{Item, Status},
+    storage,
+};
+
+// =============================================================================
+// Fixtures
+// =============================================================================
+
+/// `gh issue list --json ...` output with an open and a closed issue.
+const GITHUB_JSON: &str = r#"[
+  {
+    "number": 12,
+    "title": "Login fails on Safari",
+    "body": "Steps:\r\n\r\n1. Open the login page",
+    "labels": [{"name": "bug"}, {"name": "needs triage"}],
+    "author": {"login": "octocat", "name": "Mona Lisa"},
+    "assignees": [{"login": "hubot", "name": ""}],
+    "state": "OPEN",
+    "createdAt": "2024-03-05T09:15:00Z",
+    "updatedAt": "2024-03-06T10:00:00Z",
+    "closedAt": null,
+    "url": "https://github.com/acme/app/issues/12"
+  },
+  {
+    "number": 7,
+    "title": "Old crash",
+    "body": "",
+    "labels": [],
+    "author": {"login": "octocat"},
+    "assignees": [],
+    "state": "CLOSED",
+    "createdAt": "2023-11-01T08:00:00Z",
+    "updatedAt": "2023-11-02T08:00:00Z",
+    "closedAt": "2023-11-02T08:00:00Z",
+    "url": "https://github.com/acme/app/issues/7"
+  }
+]"#;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Loads all items, sorted by title.
+fn items() -> Vec<Item> {
+    let config = Config::load().unwrap();
+    let (mut items, _) = storage::load_all_items(&config);
+    items.sort_by(|a, b| a.title().cmp(b.title()));
+    items
+}
+
+// =============================================================================
+// GitHub
+// =============================================================================
+
+#[test]
+fn test_import_github_json() {
+    let env = setup_test_env_non_interactive();
+    fs::write(env.project_dir.path().join("issues.json"), GITHUB_JSON).unwrap();
+
+    qs_cmd(&env)
+        .args(["import", "--from", "github-json", "issues.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Imported item: queuestack/"))
+        .stdout(predicate::str::contains("2 imported, 0 already imported"));
+
+    let items = items();
+    assert_eq!(items.len(), 2);
+
+    let open = &items[0];
+    assert_eq!(open.title(), "Login fails on Safari");
+    assert!(open.id().starts_with("240305-"));
+    assert_eq!(open.author(), "Mona Lisa");
+    assert_eq!(open.labels(), ["bug", "needs-triage"]);
+    assert_eq!(open.assignees(), ["hubot"]);
+    assert_eq!(
+        open.description().trim(),
+        "Steps:\n\n1. Open the login page"
+    );
+    assert_eq!(
+        open.attachments(),
+        ["https://github.com/acme/app/issues/12"]
+    );
+    assert_eq!(
+        open.frontmatter.created_at.to_rfc3339(),
+        "2024-03-05T09:15:00+00:00"
+    );
+    assert!(open.frontmatter.updated_at.is_some());
+
+    let closed = &items[1];
+    assert_eq!(closed.status(), Status::Closed);
+    assert!(closed
+        .path
+        .as_ref()
+        .unwrap()
+        .starts_with(env.archive_path()));
+    assert_eq!(
+        closed.frontmatter.closed_at.unwrap().to_rfc3339(),
+        "2023-11-02T08:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_import_skips_imported_issues() {
+    let env = setup_test_env_non_interactive();
+    fs::write(env.project_dir.path().join("issues.json"), GITHUB_JSON).unwrap();
+
+    qs_cmd(&env)
+        .args([
+            "import",
+            "--from",
+            "github-json",
+            "issues.json",
+            "--category",
+            "github",
+        ])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["import", "--from", "github-json", "-"])
+        .write_stdin(GITHUB_JSON)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Already imported: Login fails on Safari",
+        ))
+        .stdout(predicate::str::contains("0 imported, 2 already imported"));
+
+    assert_eq!(items().len(), 2);
+    assert!(env.stack_path().join("github").is_dir());
+}
+
+#[test]
+fn test_import_skips_pull_requests() {
+    let issues = import::parse(
+        Source::GithubJson,
+        r#"[
+            {"title": "Issue", "state": "open", "html_url": "https://github.com/a/b/issues/1",
+             "url": "https://api.github.com/repos/a/b/issues/1", "user": {"login": "dev"}},
+            {"title": "PR", "state": "open", "pull_request": {}}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].author.as_deref(), Some("dev"));
+    assert_eq!(
+        issues[0].url.as_deref(),
+        Some("https://github.com/a/b/issues/1")
+    );
+}
+
+// =============================================================================
+// GitLab and Jira
+// =============================================================================
+
+#[test]
+fn test_import_gitlab_csv() {
+    let env = setup_test_env_non_interactive();
+    fs::write(
+        env.project_dir.path().join("export.csv"),
+        "Title,Description,Issue ID,URL,State,Author,Author Username,Assignee,Due Date,Created At (UTC),Updated At (UTC),Closed At (UTC),Labels\n\
+         \"Slow, flaky tests\",\"Multi\nline\",3,https://gitlab.com/acme/app/-/issues/3,Open,Jane Doe,jane,\"Ann, Bob\",2024-04-01,2024-03-05 09:15:00,2024-03-05 09:15:00,,\"ci,tests\"\n\
+         Fixed thing,,4,https://gitlab.com/acme/app/-/issues/4,Closed,Jane Doe,jane,,,2024-03-01 08:00:00,2024-03-02 08:00:00,2024-03-02 08:00:00,\n",
+    )
+    .unwrap();
+
+    qs_cmd(&env)
+        .args([
+            "import",
+            "--from",
+            "gitlab-csv",
+            "export.csv",
+            "--label",
+            "gitlab",
+        ])
+        .assert()
+        .success();
+
+    let items = items();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].status(), Status::Closed);
+    assert_eq!(items[0].labels(), ["gitlab"]);
+
+    let open = &items[1];
+    assert_eq!(open.title(), "Slow, flaky tests");
+    assert_eq!(open.labels(), ["ci", "tests", "gitlab"]);
+    assert_eq!(open.assignees(), ["Ann", "Bob"]);
+    assert_eq!(open.frontmatter.due.unwrap().to_string(), "2024-04-01");
+    assert_eq!(open.description().trim(), "Multi\nline");
+    // Never updated after creation
+    assert!(open.frontmatter.updated_at.is_none());
+}
+
+#[test]
+fn test_import_gitlab_json() {
+    let issues = import::parse(
+        Source::GitlabJson,
+        r#"[{"title": "API", "description": null, "state": "closed", "labels": ["api"],
+             "author": {"name": "Jane Doe", "username": "jane"},
+             "created_at": "2024-03-05T09:15:00.123Z", "closed_at": "2024-03-06T09:15:00Z",
+             "web_url": "https://gitlab.com/acme/app/-/issues/1"}]"#,
+    )
+    .unwrap();
+    assert_eq!(issues[0].labels, ["api"]);
+    assert_eq!(issues[0].author.as_deref(), Some("Jane Doe"));
+    assert!(issues[0].closed);
+    assert!(issues[0].body.is_empty());
+}
+
+#[test]
+fn test_import_jira_json() {
+    let issues = import::parse(
+        Source::JiraJson,
+        r#"{"issues": [{
+            "key": "APP-9",
+            "self": "https://acme.atlassian.net/rest/api/3/issue/10009",
+            "fields": {
+                "summary": "Export is slow",
+                "description": {"type": "doc", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "Takes a minute."}]}
+                ]},
+                "labels": ["perf"],
+                "reporter": {"displayName": "Sam"},
+                "assignee": {"displayName": "Kim"},
+                "created": "2024-03-05T09:15:00.000+0100",
+                "resolutiondate": null,
+                "status": {"name": "In Progress", "statusCategory": {"key": "indeterminate"}},
+                "duedate": "2024-04-01"
+            }
+        }]}"#,
+    )
+    .unwrap();
+    let issue = &issues[0];
+    assert_eq!(issue.title, "Export is slow");
+    assert_eq!(issue.body, "Takes a minute.");
+    assert_eq!(issue.author.as_deref(), Some("Sam"));
+    assert_eq!(issue.assignees, ["Kim"]);
+    assert!(!issue.closed);
+    assert_eq!(
+        issue.created_at.unwrap().to_rfc3339(),
+        "2024-03-05T08:15:00+00:00"
+    );
+    assert_eq!(
+        issue.url.as_deref(),
+        Some("https://acme.atlassian.net/browse/APP-9")
+    );
+}
+
+#[test]
+fn test_import_jira_csv_dry_run() {
+    let env = setup_test_env_non_interactive();
+    fs::write(
+        env.project_dir.path().join("jira.csv"),
+        "Summary,Issue key,Status,Reporter,Created,Resolved,Labels,Labels\n\
+         Crash on start,APP-1,Done,Sam,05/Mar/24 9:15 AM,06/Mar/24 10:00 AM,bug,urgent\n",
+    )
+    .unwrap();
+
+    let text = fs::read_to_string(env.project_dir.path().join("jira.csv")).unwrap();
+    let issues = import::parse(Source::JiraCsv, &text).unwrap();
+    assert_eq!(issues[0].labels, ["bug", "urgent"]);
+    assert!(issues[0].closed);
+    assert!(issues[0].url.is_none());
+
+    qs_cmd(&env)
+        .args(["import", "--from", "jira-csv", "jira.csv", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would import: Crash on start"))
+        .stdout(predicate::str::contains("1 would be imported"));
+    assert!(items().is_empty());
+}
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+#[test]
+fn test_import_rejects_invalid_files() {
+    let env = setup_test_env_non_interactive();
+    fs::write(env.project_dir.path().join("bad.json"), "{\"title\": 1}").unwrap();
+
+    qs_cmd(&env)
+        .args(["import", "--from", "github-json", "bad.json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected a JSON array of issues"));
+
+    assert!(import::parse(Source::GitlabJson, r#"[{"title": ""}]"#)
+        .unwrap_err()
+        .to_string()
+        .contains("Issue 1 has no title"));
+}
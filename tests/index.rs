@@ -0,0 +1,105 @@
+//! # Item Index Tests
+//!
+//! Tests for the optional on-disk item index (`index = true`): creation,
+//! incremental refresh after edits, and safe deletion.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, json_ids, setup_test_env_non_interactive, ProjectConfigBuilder, TestEnv,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Sets up a project with the index enabled or disabled and two items.
+fn setup(index: bool) -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(&ProjectConfigBuilder::new().index(index).build());
+
+    create_test_item(&env, "260101-AAA", "Login crash", "open", &["bug"], None);
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Slow queries",
+        "open",
+        &["perf"],
+        Some("backend"),
+    );
+    env
+}
+
+fn index_path(env: &TestEnv) -> std::path::PathBuf {
+    env.stack_path().join(".index.json")
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[test]
+fn test_list_creates_index() {
+    let env = setup(true);
+
+    assert_eq!(json_ids(&env, &["list"]), ["260101-AAA", "260102-BBB"]);
+    assert!(index_path(&env).exists());
+    let gitignore = fs::read_to_string(env.stack_path().join(".gitignore")).unwrap();
+    assert_eq!(gitignore, ".index.json\n");
+
+    // Results from the index match a fresh parse
+    assert_eq!(json_ids(&env, &["list", "--label", "perf"]), ["260102-BBB"]);
+    assert_eq!(json_ids(&env, &["search", "crash"]), ["260101-AAA"]);
+}
+
+#[test]
+fn test_index_picks_up_edits_and_new_items() {
+    let env = setup(true);
+    json_ids(&env, &["list"]);
+
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, content.replace("Login crash", "Logout hang")).unwrap();
+    create_test_item(&env, "260103-CCC", "Crash on exit", "open", &[], None);
+
+    assert_eq!(json_ids(&env, &["search", "crash"]), ["260103-CCC"]);
+    assert_eq!(json_ids(&env, &["search", "hang"]), ["260101-AAA"]);
+}
+
+#[test]
+fn test_index_drops_deleted_items() {
+    let env = setup(true);
+    json_ids(&env, &["list"]);
+
+    fs::remove_file(env.find_item_by_id("260101-AAA").unwrap()).unwrap();
+    assert_eq!(json_ids(&env, &["list"]), ["260102-BBB"]);
+    assert!(!fs::read_to_string(index_path(&env))
+        .unwrap()
+        .contains("260101-AAA"));
+}
+
+#[test]
+fn test_index_is_safe_to_delete_or_corrupt() {
+    let env = setup(true);
+    json_ids(&env, &["list"]);
+
+    fs::remove_file(index_path(&env)).unwrap();
+    assert_eq!(json_ids(&env, &["search", "slow"]), ["260102-BBB"]);
+    assert!(index_path(&env).exists());
+
+    fs::write(index_path(&env), "not json").unwrap();
+    assert_eq!(json_ids(&env, &["list"]).len(), 2);
+}
+
+#[test]
+fn test_no_index_when_disabled() {
+    let env = setup(false);
+
+    assert_eq!(json_ids(&env, &["list"]).len(), 2);
+    assert!(!index_path(&env).exists());
+}
@@ -0,0 +1,265 @@
+//! # JSON Output Tests
+//!
+//! Tests for the machine-readable `--format json` and `--format jsonl` output
+//! of listing commands.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, setup_test_env_non_interactive,
+    GlobalConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Runs qs with the given args and parses stdout as a single JSON document.
+fn run_json(env: &TestEnv, args: &[&str]) -> Value {
+    let output = qs_cmd(env)
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    serde_json::from_slice(&output.stdout).expect("stdout should be valid JSON")
+}
+
+// =============================================================================
+// Items
+// =============================================================================
+
+#[test]
+fn test_list_json_item_schema() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "First Task",
+        "open",
+        &["bug"],
+        Some("backend"),
+    );
+
+    let value = run_json(&env, &["list", "--format", "json"]);
+    let items = value.as_array().expect("array");
+    assert_eq!(items.len(), 1);
+
+    let item = &items[0];
+    assert_eq!(item["id"], "260101-AAA");
+    assert_eq!(item["title"], "First Task");
+    assert_eq!(item["author"], "Test User");
+    assert_eq!(item["status"], "open");
+    assert_eq!(item["labels"], serde_json::json!(["bug"]));
+    assert_eq!(item["attachments"], serde_json::json!([]));
+    assert_eq!(item["category"], "backend");
+    assert_eq!(item["path"], "queuestack/backend/260101-AAA-first-task.md");
+    assert!(item["created_at"].is_string());
+    assert!(item.get("body").is_none(), "body is opt-in");
+}
+
+#[test]
+fn test_list_json_uncategorized_is_null() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let value = run_json(&env, &["list", "--format", "json"]);
+    assert!(value[0]["category"].is_null());
+}
+
+#[test]
+fn test_list_json_with_body() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let value = run_json(&env, &["list", "--format", "json", "--with-body"]);
+    assert!(value[0]["body"].is_string());
+}
+
+#[test]
+fn test_list_json_empty_is_empty_array() {
+    let env = setup_test_env_non_interactive();
+
+    let value = run_json(&env, &["list", "--format", "json"]);
+    assert_eq!(value, serde_json::json!([]));
+}
+
+#[test]
+fn test_list_json_ignores_interactive_flag() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(true).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let value = run_json(&env, &["list", "--format", "json", "-i"]);
+    assert_eq!(value.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_list_jsonl_one_object_per_line() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Task Two", "open", &[], None);
+
+    let output = qs_cmd(&env)
+        .args(["list", "--format", "jsonl"])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    for line in lines {
+        let value: Value = serde_json::from_str(line).expect("each line is JSON");
+        assert!(value.is_object());
+    }
+}
+
+#[test]
+fn test_format_flag_is_global() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let value = run_json(&env, &["--format", "json", "list"]);
+    assert_eq!(value[0]["id"], "260101-AAA");
+}
+
+#[test]
+fn test_format_flag_rejected_without_output() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["new", "Task", "--format", "json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--format is only supported by"));
+    assert_eq!(env.count_all_items(), 0, "Nothing is created");
+
+    // The default format is accepted everywhere
+    qs_cmd(&env)
+        .args(["new", "Task", "--format", "text", "--no-interactive"])
+        .assert()
+        .success();
+}
+
+// =============================================================================
+// Search
+// =============================================================================
+
+#[test]
+fn test_search_json() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Dark mode", "open", &[], None);
+
+    let value = run_json(&env, &["search", "login", "--format", "json"]);
+    let items = value.as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["title"], "Login bug");
+}
+
+#[test]
+fn test_search_json_no_matches_is_empty_array() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let value = run_json(&env, &["search", "nonexistent", "--format", "json"]);
+    assert_eq!(value, serde_json::json!([]));
+}
+
+// =============================================================================
+// Labels, Categories, Attachments, Meta, Templates
+// =============================================================================
+
+#[test]
+fn test_labels_json() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "One", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &["bug", "ui"], None);
+
+    let value = run_json(&env, &["list", "--labels", "--format", "json"]);
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {"name": "bug", "count": 2},
+            {"name": "ui", "count": 1}
+        ])
+    );
+}
+
+#[test]
+fn test_categories_json() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], Some("backend"));
+    create_test_item(&env, "260102-BBB", "Two", "open", &[], None);
+
+    let value = run_json(&env, &["list", "--categories", "--format", "json"]);
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {"name": "backend", "count": 1},
+            {"name": null, "count": 1}
+        ])
+    );
+}
+
+#[test]
+fn test_attachments_json() {
+    let env = setup_test_env_non_interactive();
+    create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Task",
+        "open",
+        &["260101-AAA-Attachment-1-log.txt", "https://example.com"],
+        None,
+    );
+
+    let value = run_json(
+        &env,
+        &[
+            "list",
+            "--attachments",
+            "--id",
+            "260101",
+            "--format",
+            "json",
+        ],
+    );
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {"index": 1, "attachment": "260101-AAA-Attachment-1-log.txt", "kind": "file"},
+            {"index": 2, "attachment": "https://example.com", "kind": "url"}
+        ])
+    );
+}
+
+#[test]
+fn test_meta_json_is_single_object() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &["bug"], None);
+
+    let value = run_json(
+        &env,
+        &["list", "--meta", "--id", "260101", "--format", "json"],
+    );
+    assert!(value.is_object());
+    assert_eq!(value["id"], "260101-AAA");
+    assert_eq!(value["labels"], serde_json::json!(["bug"]));
+}
+
+#[test]
+fn test_templates_json_empty() {
+    let env = setup_test_env_non_interactive();
+
+    let value = run_json(&env, &["list", "--templates", "--format", "json"]);
+    assert_eq!(value, serde_json::json!([]));
+}
@@ -22,13 +22,14 @@ fn test_labels_empty_project() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -51,13 +52,14 @@ fn test_labels_shows_unique_labels() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -98,13 +100,14 @@ fn test_labels_includes_archived_items() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should include labels from both open and archived items
@@ -124,13 +127,14 @@ fn test_labels_without_init() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
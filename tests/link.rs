@@ -0,0 +1,269 @@
+//! # Link Tests
+//!
+//! Tests for item relationships: `qs link`, the `--blocked`/`--ready` list
+//! filters, and dependency reporting on `qs close`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, list_output, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, LinkArgs, UpdateArgs},
+    Item,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn load(env: &TestEnv, id: &str) -> Item {
+    let path = env.find_item_by_id(id).expect("item should exist");
+    Item::load(&path).expect("item should load")
+}
+
+fn link_blocks(id: &str, target: &str) {
+    commands::link(&LinkArgs {
+        id: Some(id.to_string()),
+        blocks: vec![target.to_string()],
+        ..Default::default()
+    })
+    .expect("link should succeed");
+}
+
+// =============================================================================
+// Linking
+// =============================================================================
+
+#[test]
+fn test_link_blocks_updates_both_items() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Backend", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Frontend", "open", &[], None);
+
+    // Partial IDs resolve to full IDs
+    link_blocks("260101", "260102");
+
+    assert_eq!(load(&env, "260101").blocks(), ["260102-BBB".to_string()]);
+    assert_eq!(
+        load(&env, "260102").blocked_by(),
+        ["260101-AAA".to_string()]
+    );
+}
+
+#[test]
+fn test_link_blocked_by_and_related() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Third", "open", &[], None);
+
+    commands::link(&LinkArgs {
+        id: Some("260102".to_string()),
+        blocked_by: vec!["260101".to_string()],
+        related: vec!["260103".to_string()],
+        ..Default::default()
+    })
+    .expect("link should succeed");
+
+    assert_eq!(load(&env, "260101").blocks(), ["260102-BBB".to_string()]);
+    assert_eq!(load(&env, "260102").related(), ["260103-CCC".to_string()]);
+    assert_eq!(load(&env, "260103").related(), ["260102-BBB".to_string()]);
+}
+
+#[test]
+fn test_link_remove() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+    link_blocks("260101", "260102");
+
+    commands::link(&LinkArgs {
+        id: Some("260101".to_string()),
+        blocks: vec!["260102".to_string()],
+        remove: true,
+        ..Default::default()
+    })
+    .expect("unlink should succeed");
+
+    assert!(load(&env, "260101").blocks().is_empty());
+    assert!(load(&env, "260102").blocked_by().is_empty());
+    let content = env.read_item(&env.find_item_by_id("260102").unwrap());
+    assert!(!content.contains("blocked_by"), "Empty lists are omitted");
+}
+
+#[test]
+fn test_link_rejects_self_and_cycles() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260101", "--blocks", "260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("to itself"));
+
+    link_blocks("260101", "260102");
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260102", "--blocks", "260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cycle"));
+}
+
+#[test]
+fn test_link_unknown_target_fails() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260101", "--blocks", "999999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No item found"));
+}
+
+#[test]
+fn test_link_failing_target_writes_nothing() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260101", "--blocks", "260102"])
+        .args(["--blocks", "999999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No item found"));
+
+    assert!(load(&env, "260101").blocks().is_empty());
+    assert!(load(&env, "260102").blocked_by().is_empty());
+}
+
+#[test]
+fn test_link_same_target_twice() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260101", "--blocks", "260102"])
+        .args(["--related", "260102"])
+        .assert()
+        .success();
+
+    let target = load(&env, "260102");
+    assert_eq!(target.blocked_by(), ["260101-AAA".to_string()]);
+    assert_eq!(target.related(), ["260101-AAA".to_string()]);
+}
+
+// =============================================================================
+// Blocked / Ready Filters
+// =============================================================================
+
+#[test]
+fn test_list_blocked_and_ready() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Waiting", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Free", "open", &[], None);
+    link_blocks("260101", "260102");
+
+    let stdout = list_output(&env, &["--blocked"]);
+    assert_eq!(stdout.trim(), "queuestack/260102-BBB-waiting.md");
+
+    let stdout = list_output(&env, &["--ready"]);
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(!stdout.contains("260102-BBB"));
+
+    // Closing the blocker makes the dependent ready
+    commands::execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    let stdout = list_output(&env, &["--blocked"]);
+    assert!(stdout.contains("No items found"));
+}
+
+// =============================================================================
+// Close Reporting
+// =============================================================================
+
+#[test]
+fn test_close_reports_unblocked_dependents() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Waiting", "open", &[], None);
+    link_blocks("260101", "260102");
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unblocked: 260102-BBB"));
+}
+
+#[test]
+fn test_close_warns_about_still_blocked_dependents() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Blocker Two", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Waiting", "open", &[], None);
+    link_blocks("260101", "260103");
+    link_blocks("260102", "260103");
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unblocked").not())
+        .stderr(predicate::str::contains(
+            "'260103-CCC' is still blocked by open item(s): 260102-BBB",
+        ));
+}
+
+#[test]
+fn test_close_warns_about_open_blockers() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Waiting", "open", &[], None);
+    link_blocks("260101", "260102");
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260102"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "'260102-BBB' was still blocked by open item(s): 260101-AAA",
+        ));
+}
+
+#[test]
+fn test_links_survive_rename_and_archive() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Waiting", "open", &[], None);
+    link_blocks("260101", "260102");
+
+    // Rename and move the dependent, archive the blocker
+    commands::update(UpdateArgs {
+        id: Some("260102".to_string()),
+        title: Some("Renamed Dependent".to_string()),
+        category: Some("later".to_string()),
+        ..Default::default()
+    })
+    .expect("update should succeed");
+    commands::execute_close(Some("260101".to_string()), None).expect("close should succeed");
+
+    let dependent = load(&env, "260102");
+    assert_eq!(dependent.blocked_by(), ["260101-AAA".to_string()]);
+
+    // Reopening the blocker blocks the dependent again
+    commands::execute_reopen(Some("260101".to_string()), None).expect("reopen should succeed");
+    let stdout = list_output(&env, &["--blocked"]);
+    assert_eq!(
+        stdout.trim(),
+        "queuestack/later/260102-BBB-renamed-dependent.md"
+    );
+}
@@ -22,13 +22,14 @@ fn test_list_empty_project() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should not error even if empty
@@ -61,13 +62,14 @@ fn test_list_shows_open_items() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should succeed (output goes to stdout)
@@ -97,13 +99,14 @@ fn test_list_filter_by_label() {
         labels: vec!["bug".to_string()],
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -125,13 +128,14 @@ fn test_list_sort_by_title() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Title,
+        sort: Some(SortBy::Title),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -160,13 +164,14 @@ fn test_list_shows_closed_items() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -188,13 +193,14 @@ fn test_list_filter_by_author() {
         labels: Vec::new(),
         author: Some("Test User".to_string()),
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -216,13 +222,14 @@ fn test_list_sort_by_date() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Date,
+        sort: Some(SortBy::Date),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -253,13 +260,14 @@ fn test_list_combined_filters() {
         labels: vec!["bug".to_string()],
         author: Some("Test User".to_string()),
         category: None,
-        sort: SortBy::Title,
+        sort: Some(SortBy::Title),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -287,13 +295,14 @@ fn test_list_open_and_closed_flags_together() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -315,13 +324,14 @@ fn test_list_without_init() {
         labels: Vec::new(),
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -343,13 +353,14 @@ fn test_list_author_case_insensitive() {
         labels: Vec::new(),
         author: Some("TEST USER".to_string()), // uppercase of "Test User"
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     let result = commands::list(&filter);
@@ -370,13 +381,14 @@ fn test_list_nonexistent_label_filter() {
         labels: vec!["nonexistent-label".to_string()],
         author: None,
         category: None,
-        sort: SortBy::Id,
+        sort: Some(SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should succeed but return empty list
@@ -399,13 +411,14 @@ fn test_list_interactive_combinations() {
             labels: Vec::new(),
             author: None,
             category: None,
-            sort: SortBy::Id,
+            sort: Some(SortBy::Id),
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: true,
             }, // Override interactive
             id: None,
             file: None,
+            ..Default::default()
         };
 
         commands::list(&filter).expect("list should succeed");
@@ -424,13 +437,14 @@ fn test_list_interactive_combinations() {
             labels: Vec::new(),
             author: None,
             category: None,
-            sort: SortBy::Id,
+            sort: Some(SortBy::Id),
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: false,
             }, // Would show selector if in terminal
             id: None,
             file: None,
+            ..Default::default()
         };
 
         // Works because we're not in a terminal, so interactive selection is skipped
@@ -450,13 +464,14 @@ fn test_list_interactive_combinations() {
             labels: Vec::new(),
             author: None,
             category: None,
-            sort: SortBy::Id,
+            sort: Some(SortBy::Id),
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: false,
             },
             id: None,
             file: None,
+            ..Default::default()
         };
 
         commands::list(&filter).expect("list should succeed");
@@ -475,13 +490,14 @@ fn test_list_interactive_combinations() {
             labels: Vec::new(),
             author: None,
             category: None,
-            sort: SortBy::Id,
+            sort: Some(SortBy::Id),
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: true,
             },
             id: None,
             file: None,
+            ..Default::default()
         };
 
         commands::list(&filter).expect("list should succeed");
@@ -27,6 +27,7 @@ fn test_new_creates_item() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -60,6 +61,7 @@ fn test_new_with_labels() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -87,6 +89,7 @@ fn test_new_with_category() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -112,6 +115,7 @@ fn test_new_uses_custom_id_pattern() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -145,6 +149,7 @@ fn test_new_project_id_pattern_overrides_global() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -174,6 +179,7 @@ fn test_new_with_labels_and_category() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -213,15 +219,16 @@ fn test_new_with_attachments() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
 
-    let files = env.list_stack_files();
-    assert_eq!(files.len(), 1, "Should have one item");
+    let items = env.list_stack_files();
+    assert_eq!(items.len(), 1, "Should have one item");
 
     // Verify attachments were added
-    let content = env.read_item(&files[0]);
+    let content = env.read_item(&items[0]);
     assert!(
         content.contains("attachments:"),
         "Should have attachments field"
@@ -240,7 +247,7 @@ fn test_new_with_attachments() {
     );
 
     // Get item ID from filename
-    let item_id = files[0]
+    let item_id = items[0]
         .file_name()
         .unwrap()
         .to_str()
@@ -276,6 +283,7 @@ fn test_new_with_empty_labels() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -315,6 +323,7 @@ fn test_new_multiple_items_unique_ids() {
             },
             as_template: false,
             from_template: None,
+            ..Default::default()
         };
         commands::new(args).expect("new should succeed");
     }
@@ -358,6 +367,7 @@ fn test_new_category_with_slash_normalized() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -387,6 +397,7 @@ fn test_new_normalizes_spaces_in_labels_and_category() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -434,6 +445,7 @@ fn test_new_without_init() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
 
     let result = commands::new(args);
@@ -6,27 +6,18 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::case_sensitive_file_extension_comparisons)]
+
 mod common;
 
 use std::fs;
 
-use assert_cmd::Command;
-use common::{create_test_item, create_test_item_with_attachments, GlobalConfigBuilder, TestEnv};
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
 use predicates::prelude::*;
 use queuestack::commands;
 
-// =============================================================================
-// Helper Functions
-// =============================================================================
-
-/// Creates a qs command configured to run in the test environment.
-fn qs_cmd(env: &TestEnv) -> Command {
-    let mut cmd = Command::cargo_bin("qs").unwrap();
-    cmd.current_dir(env.project_dir.path());
-    cmd.env("HOME", env.home_dir.path());
-    cmd
-}
-
 // =============================================================================
 // list --no-interactive Output Tests
 // =============================================================================
@@ -90,19 +81,17 @@ fn test_list_items_one_per_line() {
     let lines: Vec<&str> = stdout.lines().collect();
 
     // Should be exactly 3 lines (one per item)
-    assert_eq!(lines.len(), 3, "Expected 3 lines, got: {:?}", lines);
+    assert_eq!(lines.len(), 3, "Expected 3 lines, got: {lines:?}");
 
     // Each line should be a path
     for line in &lines {
         assert!(
             line.ends_with(".md"),
-            "Each line should be a .md file path, got: {}",
-            line
+            "Each line should be a .md file path, got: {line}"
         );
         assert!(
             !line.contains("  "),
-            "Lines should not have double spaces (no table formatting): {}",
-            line
+            "Lines should not have double spaces (no table formatting): {line}"
         );
     }
 }
@@ -190,8 +179,7 @@ fn test_list_labels_one_per_line() {
     for line in &lines {
         assert!(
             line.contains(" (") && line.ends_with(')'),
-            "Each line should be 'label (count)', got: {}",
-            line
+            "Each line should be 'label (count)', got: {line}"
         );
     }
 }
@@ -373,8 +361,7 @@ fn test_list_categories_one_per_line() {
     for line in &lines {
         assert!(
             line.contains(" (") && line.ends_with(')'),
-            "Each line should be 'category (count)', got: {}",
-            line
+            "Each line should be 'category (count)', got: {line}"
         );
     }
 }
@@ -458,9 +445,12 @@ fn test_list_attachments_one_per_line() {
         .clone();
 
     let stdout = String::from_utf8_lossy(&output);
-    let lines: Vec<&str> = stdout.lines().collect();
 
-    assert_eq!(lines.len(), 3, "Expected 3 lines (one per attachment)");
+    assert_eq!(
+        stdout.lines().count(),
+        3,
+        "Expected 3 lines (one per attachment)"
+    );
 }
 
 #[test]
@@ -679,8 +669,7 @@ fn test_output_ends_with_newline() {
 
         assert!(
             output.ends_with(b"\n"),
-            "{:?} output should end with newline",
-            args
+            "{args:?} output should end with newline"
         );
     }
 }
@@ -0,0 +1,181 @@
+//! # Parse Error Tests
+//!
+//! Tests for item files that fail to parse: the warning of listing
+//! commands, and `--strict` / `strict = true` turning them into errors.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use queuestack::{config::Config, storage};
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Sets up a project with one valid item and one with broken YAML on line 3.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+
+    create_test_item(&env, "260101-AAA", "Login crash", "open", &[], None);
+    fs::write(
+        env.stack_path().join("260102-BBB-broken.md"),
+        "---\nid: 260102-BBB\ntitle: Broken: again\nauthor: Test\n---\n\nBody\n",
+    )
+    .unwrap();
+    env
+}
+
+// =============================================================================
+// Warnings
+// =============================================================================
+
+#[test]
+fn test_list_warns_about_broken_files() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA-login-crash.md"))
+        .stdout(predicate::str::contains("broken").not())
+        .stderr(predicate::str::contains("1 item file could not be parsed"))
+        .stderr(predicate::str::contains(
+            "queuestack/260102-BBB-broken.md:3:",
+        ));
+}
+
+#[test]
+fn test_json_output_stays_valid() {
+    let env = setup();
+
+    let output = qs_cmd(&env)
+        .args(["list", "--format", "json"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("260102-BBB-broken.md"))
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("stdout should be valid JSON");
+    assert_eq!(json.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_search_and_board_warn() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["search", "login", "--no-interactive"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("260102-BBB-broken.md"));
+    qs_cmd(&env)
+        .arg("board")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("260102-BBB-broken.md"));
+}
+
+#[test]
+fn test_bulk_query_warns() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["close", "--query", "login", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 would change"))
+        .stderr(predicate::str::contains("260102-BBB-broken.md"));
+}
+
+#[test]
+fn test_index_reports_broken_files() {
+    let env = setup();
+    env.write_project_config("index = true\n");
+
+    // Broken files are never cached, so every run reports them
+    for _ in 0..2 {
+        qs_cmd(&env)
+            .args(["list", "--no-interactive"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("260102-BBB-broken.md"));
+    }
+}
+
+#[test]
+fn test_markdown_attachments_are_not_items() {
+    let env = setup();
+    fs::remove_file(env.stack_path().join("260102-BBB-broken.md")).unwrap();
+    fs::write(
+        env.stack_path().join("260101-AAA-Attachment-1-notes.md"),
+        "# Notes\n",
+    )
+    .unwrap();
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_parse_failure_location() {
+    let _env = setup();
+    let config = Config::load().unwrap();
+
+    let (items, failures) = storage::load_all_items(&config);
+    assert_eq!(items.len(), 1);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].location, Some((3, 14)));
+    assert_eq!(
+        failures[0].describe(&config),
+        "queuestack/260102-BBB-broken.md:3:14: mapping values are not allowed in this context"
+    );
+}
+
+// =============================================================================
+// Strict Mode
+// =============================================================================
+
+#[test]
+fn test_strict_flag_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive", "--strict"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("1 item file failed to parse"))
+        .stderr(predicate::str::contains("260102-BBB-broken.md:3:"));
+    qs_cmd(&env).args(["board", "--strict"]).assert().failure();
+}
+
+#[test]
+fn test_strict_config_option_fails() {
+    let env = setup();
+    env.write_project_config("strict = true\n");
+
+    qs_cmd(&env)
+        .args(["search", "login", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("strict mode"));
+
+    // Without broken files strict mode changes nothing
+    fs::remove_file(env.stack_path().join("260102-BBB-broken.md")).unwrap();
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .success();
+}
@@ -0,0 +1,190 @@
+//! # Priority Tests
+//!
+//! Tests for item priorities: setting via `qs new`/`qs update`, filtering,
+//! and priority-aware sorting.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{
+    create_test_item, load_only_item, qs_cmd, setup_test_env_non_interactive, ProjectConfigBuilder,
+};
+use queuestack::commands::{self, InteractiveArgs, NewArgs, UpdateArgs};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+const fn no_interactive() -> InteractiveArgs {
+    InteractiveArgs {
+        interactive: false,
+        no_interactive: true,
+    }
+}
+
+// =============================================================================
+// Setting Priority
+// =============================================================================
+
+#[test]
+fn test_new_with_priority() {
+    let env = setup_test_env_non_interactive();
+
+    commands::new(NewArgs {
+        title: Some("Outage".to_string()),
+        priority: Some("High".to_string()),
+        interactive: no_interactive(),
+        ..Default::default()
+    })
+    .expect("new should succeed");
+
+    // Stored with the configured spelling
+    assert_eq!(load_only_item(&env).priority(), Some("high"));
+}
+
+#[test]
+fn test_new_with_unknown_priority_fails() {
+    let env = setup_test_env_non_interactive();
+
+    let result = commands::new(NewArgs {
+        title: Some("Task".to_string()),
+        priority: Some("urgent".to_string()),
+        interactive: no_interactive(),
+        ..Default::default()
+    });
+
+    let err = result.expect_err("unknown priority should fail");
+    assert!(err.to_string().contains("critical, high, medium, low"));
+    assert!(
+        env.list_stack_files().is_empty(),
+        "No item should be created"
+    );
+}
+
+#[test]
+fn test_new_with_custom_priority_levels() {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .priorities(&["p0", "p1"])
+            .build(),
+    );
+
+    commands::new(NewArgs {
+        title: Some("Task".to_string()),
+        priority: Some("p1".to_string()),
+        interactive: no_interactive(),
+        ..Default::default()
+    })
+    .expect("new should succeed");
+
+    assert_eq!(load_only_item(&env).priority(), Some("p1"));
+}
+
+#[test]
+fn test_update_set_and_remove_priority() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    commands::update(UpdateArgs {
+        id: Some("260101".to_string()),
+        priority: Some("low".to_string()),
+        ..Default::default()
+    })
+    .expect("update should succeed");
+    assert_eq!(load_only_item(&env).priority(), Some("low"));
+
+    commands::update(UpdateArgs {
+        id: Some("260101".to_string()),
+        remove_priority: true,
+        ..Default::default()
+    })
+    .expect("update should succeed");
+
+    let item = load_only_item(&env);
+    assert_eq!(item.priority(), None);
+    let content = env.read_item(item.path.as_ref().unwrap());
+    assert!(
+        !content.contains("priority:"),
+        "Cleared priority is omitted"
+    );
+}
+
+#[test]
+fn test_update_unknown_priority_fails() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let result = commands::update(UpdateArgs {
+        id: Some("260101".to_string()),
+        priority: Some("someday".to_string()),
+        ..Default::default()
+    });
+    assert!(result.is_err(), "unknown priority should fail");
+}
+
+// =============================================================================
+// Filtering and Sorting
+// =============================================================================
+
+#[test]
+fn test_list_filter_and_sort_by_priority() {
+    let env = setup_test_env_non_interactive();
+    for (id, title, priority) in [
+        ("260101-AAA", "Low Task", Some("low")),
+        ("260102-BBB", "Critical Task", Some("critical")),
+        ("260103-CCC", "Unset Task", None),
+        ("260104-DDD", "High Task", Some("high")),
+    ] {
+        create_test_item(&env, id, title, "open", &[], None);
+        if let Some(priority) = priority {
+            commands::update(UpdateArgs {
+                id: Some(id.to_string()),
+                priority: Some(priority.to_string()),
+                ..Default::default()
+            })
+            .expect("update should succeed");
+        }
+    }
+
+    let output = qs_cmd(&env)
+        .args(["list", "--no-interactive", "--sort", "priority"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let ids: Vec<&str> = stdout
+        .lines()
+        .map(|l| &l["queuestack/".len().."queuestack/".len() + 10])
+        .collect();
+    assert_eq!(
+        ids,
+        vec!["260102-BBB", "260104-DDD", "260101-AAA", "260103-CCC"]
+    );
+
+    let output = qs_cmd(&env)
+        .args(["list", "--no-interactive", "--priority", "high", "critical"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(stdout.contains("260102-BBB"));
+    assert!(stdout.contains("260104-DDD"));
+
+    let output = qs_cmd(&env)
+        .args(["list", "--no-interactive", "--priority", "none"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert_eq!(stdout.trim(), "queuestack/260103-CCC-unset-task.md");
+}
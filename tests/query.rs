@@ -0,0 +1,199 @@
+//! # Query Language Tests
+//!
+//! Tests for query expressions in `qs list --query` and `qs search`:
+//! field conditions, boolean operators, grouping, and parse errors.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{
+    create_test_item, json_ids, json_records, qs_cmd, setup_test_env_non_interactive, TestEnv,
+};
+use predicates::prelude::*;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Sets up a project with a few labeled and categorized items.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+
+    create_test_item(&env, "260101-AAA", "Login crash", "open", &["bug"], None);
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Old report",
+        "open",
+        &["bug", "wontfix"],
+        None,
+    );
+    create_test_item(
+        &env,
+        "260103-CCC",
+        "Slow queries",
+        "open",
+        &["perf"],
+        Some("backend"),
+    );
+    create_test_item(
+        &env,
+        "260104-DDD",
+        "Broken button",
+        "open",
+        &["bug"],
+        Some("frontend"),
+    );
+    env
+}
+
+fn list_query(env: &TestEnv, query: &str) -> Vec<String> {
+    json_ids(env, &["list", "--query", query])
+}
+
+// =============================================================================
+// qs list --query
+// =============================================================================
+
+#[test]
+fn test_query_label_and_not() {
+    let env = setup();
+
+    assert_eq!(
+        list_query(&env, "label:bug AND NOT label:wontfix"),
+        ["260101-AAA", "260104-DDD"]
+    );
+}
+
+#[test]
+fn test_query_or_with_grouping() {
+    let env = setup();
+
+    assert_eq!(
+        list_query(
+            &env,
+            "label:bug AND (category:backend OR category:frontend)"
+        ),
+        ["260104-DDD"]
+    );
+    assert_eq!(
+        list_query(&env, "label:perf OR title:login"),
+        ["260101-AAA", "260103-CCC"]
+    );
+}
+
+#[test]
+fn test_query_implicit_and_with_text() {
+    let env = setup();
+
+    assert_eq!(list_query(&env, "crash label:bug"), ["260101-AAA"]);
+    assert_eq!(list_query(&env, "\"old report\""), ["260102-BBB"]);
+}
+
+#[test]
+fn test_query_dates_and_author() {
+    let env = setup();
+
+    // Test items are created on 2026-01-09
+    assert_eq!(list_query(&env, "created>2026-01-01 author:test").len(), 4);
+    assert!(list_query(&env, "created<2026-01-01").is_empty());
+}
+
+#[test]
+fn test_query_combines_with_other_filters() {
+    let env = setup();
+
+    assert_eq!(
+        json_ids(&env, &["list", "--label", "bug", "-q", "NOT label:wontfix"]),
+        ["260101-AAA", "260104-DDD"]
+    );
+}
+
+#[test]
+fn test_query_invalid_syntax_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--query", "(label:bug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Missing ')'"));
+    qs_cmd(&env)
+        .args(["list", "--query", "created>soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid date"));
+}
+
+#[test]
+fn test_multiple_label_flags_require_all() {
+    let env = setup();
+
+    assert_eq!(
+        json_ids(&env, &["list", "--label", "bug", "wontfix"]),
+        ["260102-BBB"]
+    );
+}
+
+// =============================================================================
+// qs search
+// =============================================================================
+
+#[test]
+fn test_search_accepts_query_syntax() {
+    let env = setup();
+
+    assert_eq!(
+        json_ids(&env, &["search", "label:bug NOT label:wontfix"]),
+        ["260101-AAA", "260104-DDD"]
+    );
+    assert_eq!(
+        json_ids(&env, &["search", "login OR slow"]),
+        ["260101-AAA", "260103-CCC"]
+    );
+}
+
+#[test]
+fn test_search_bare_words_need_full_text_for_body() {
+    let env = setup();
+
+    assert!(json_ids(&env, &["search", "body"]).is_empty());
+    assert_eq!(json_ids(&env, &["search", "body", "--full-text"]).len(), 4);
+}
+
+#[test]
+fn test_search_falls_back_to_plain_text() {
+    let env = setup();
+    for title in [
+        "Second: thing",
+        "(draft) Notes",
+        "See https://example.com/42",
+    ] {
+        qs_cmd(&env).args(["new", title]).assert().success();
+    }
+    let search_titles = |text: &str| -> Vec<String> {
+        json_records(&env, &["search", text])
+            .iter()
+            .map(|item| item["title"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // Text that isn't a valid query expression is matched as typed
+    assert_eq!(search_titles("Second: thing"), ["Second: thing"]);
+    assert_eq!(search_titles("(draft"), ["(draft) Notes"]);
+    assert!(search_titles("label:bug AND").is_empty());
+    // URLs are text, not `https` field conditions
+    assert_eq!(
+        search_titles("https://example.com/42"),
+        ["See https://example.com/42"]
+    );
+
+    // `qs list --query` still rejects invalid expressions
+    qs_cmd(&env)
+        .args(["list", "--query", "label:bug AND"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unexpected end of query"));
+}
@@ -27,6 +27,7 @@ fn test_search_by_title() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -49,6 +50,7 @@ fn test_search_by_id() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -71,6 +73,7 @@ fn test_search_case_insensitive() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -93,6 +96,7 @@ fn test_search_no_results() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -106,7 +110,7 @@ fn test_search_full_text() {
     commands::init().expect("init should succeed");
 
     // Create item with specific body content
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Generic Title
 author: Test User
@@ -117,7 +121,7 @@ category: ~
 ---
 
 This is the body with unique keyword: SEARCHTERM123
-"#;
+";
     std::fs::write(
         env.stack_path().join("260101-AAA-generic-title.md"),
         content,
@@ -132,6 +136,7 @@ This is the body with unique keyword: SEARCHTERM123
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -145,7 +150,7 @@ fn test_search_full_text_no_match_without_flag() {
     commands::init().expect("init should succeed");
 
     // Create item with body content but not in title
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Generic Title
 author: Test User
@@ -156,7 +161,7 @@ category: ~
 ---
 
 Body with keyword: ONLYINBODY
-"#;
+";
     std::fs::write(
         env.stack_path().join("260101-AAA-generic-title.md"),
         content,
@@ -171,6 +176,7 @@ Body with keyword: ONLYINBODY
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -201,6 +207,7 @@ fn test_search_closed_items() {
             no_interactive: true,
         },
         closed: true,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -214,7 +221,7 @@ fn test_search_full_text_and_closed_combined() {
     commands::init().expect("init should succeed");
 
     // Create a closed item with searchable body
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Old Task
 author: Test User
@@ -225,7 +232,7 @@ category: ~
 ---
 
 Body contains: ARCHIVEDCONTENT
-"#;
+";
     std::fs::write(env.archive_path().join("260101-AAA-old-task.md"), content).expect("write item");
 
     let args = SearchArgs {
@@ -236,6 +243,7 @@ Body contains: ARCHIVEDCONTENT
             no_interactive: true,
         },
         closed: true,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -263,6 +271,7 @@ fn test_search_multiple_matches() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -286,6 +295,7 @@ fn test_search_without_init() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -315,6 +325,7 @@ fn test_search_partial_word() {
             no_interactive: true,
         },
         closed: false,
+        ..Default::default()
     };
 
     let result = commands::search(&args);
@@ -338,6 +349,7 @@ fn test_search_interactive_combinations() {
                 no_interactive: true,
             },
             closed: false,
+            ..Default::default()
         };
 
         commands::search(&args).expect("search should succeed");
@@ -358,6 +370,7 @@ fn test_search_interactive_combinations() {
                 no_interactive: false,
             },
             closed: false,
+            ..Default::default()
         };
 
         commands::search(&args).expect("search should succeed");
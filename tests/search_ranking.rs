@@ -0,0 +1,141 @@
+//! # Search Ranking Tests
+//!
+//! Tests for `qs search --regex` and `--fuzzy`, relevance ranking, and the
+//! body snippets printed in non-interactive output.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{json_ids, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Writes an item with the given title and body.
+fn write_item(env: &TestEnv, id: &str, title: &str, body: &str) {
+    let content = format!(
+        "---\nid: {id}\ntitle: {title}\nauthor: Test User\ncreated_at: 2026-01-09T12:00:00Z\nstatus: open\nlabels: []\n---\n\n{body}"
+    );
+    std::fs::write(env.stack_path().join(format!("{id}-item.md")), content).expect("write item");
+}
+
+// =============================================================================
+// Ranking
+// =============================================================================
+
+#[test]
+fn test_title_hits_rank_above_body_hits() {
+    let env = setup_test_env_non_interactive();
+    write_item(&env, "260101-AAA", "Unrelated", "The login form breaks\n");
+    write_item(&env, "260102-BBB", "Relogin loop", "");
+    write_item(&env, "260103-CCC", "Login page", "");
+
+    assert_eq!(
+        json_ids(&env, &["search", "login", "--full-text"]),
+        ["260103-CCC", "260102-BBB", "260101-AAA"]
+    );
+}
+
+#[test]
+fn test_equal_scores_keep_id_order() {
+    let env = setup_test_env_non_interactive();
+    write_item(&env, "260102-BBB", "Fix bug", "");
+    write_item(&env, "260101-AAA", "Fix bug", "");
+
+    assert_eq!(
+        json_ids(&env, &["search", "bug"]),
+        ["260101-AAA", "260102-BBB"]
+    );
+}
+
+// =============================================================================
+// Regex and Fuzzy Modes
+// =============================================================================
+
+#[test]
+fn test_regex_search() {
+    let env = setup_test_env_non_interactive();
+    write_item(&env, "260101-AAA", "Crash in v2.3", "");
+    write_item(&env, "260102-BBB", "Crash in beta", "");
+
+    assert_eq!(
+        json_ids(&env, &["search", r"v\d+\.\d+", "--regex"]),
+        ["260101-AAA"]
+    );
+    // Case-insensitive
+    assert_eq!(json_ids(&env, &["search", "^CRASH", "--regex"]).len(), 2);
+}
+
+#[test]
+fn test_invalid_regex_fails() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["search", "(unclosed", "--regex"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid regex"));
+}
+
+#[test]
+fn test_fuzzy_search_ranks_closer_matches_first() {
+    let env = setup_test_env_non_interactive();
+    write_item(&env, "260101-AAA", "Long lines in agenda", "");
+    write_item(&env, "260102-BBB", "Login button", "");
+    write_item(&env, "260103-CCC", "Nothing here", "");
+
+    assert_eq!(
+        json_ids(&env, &["search", "lgn", "--fuzzy"]),
+        ["260102-BBB", "260101-AAA"]
+    );
+}
+
+#[test]
+fn test_regex_and_fuzzy_conflict() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["search", "x", "--regex", "--fuzzy"])
+        .assert()
+        .failure();
+}
+
+// =============================================================================
+// Snippets
+// =============================================================================
+
+#[test]
+fn test_non_interactive_output_shows_snippets() {
+    let env = setup_test_env_non_interactive();
+    write_item(
+        &env,
+        "260101-AAA",
+        "Report",
+        "Steps:\nThe app crashes on submit\nafter login\n",
+    );
+
+    qs_cmd(&env)
+        .args(["search", "crashes", "--full-text", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA-item.md\n"))
+        .stdout(predicate::str::contains(
+            "    Steps:\n  > The app crashes on submit\n    after login\n",
+        ));
+}
+
+#[test]
+fn test_no_snippet_for_title_only_matches() {
+    let env = setup_test_env_non_interactive();
+    write_item(&env, "260101-AAA", "Crash report", "Nothing relevant\n");
+
+    qs_cmd(&env)
+        .args(["search", "crash", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(">").not());
+}
@@ -0,0 +1,284 @@
+//! # Serve Command Tests
+//!
+//! Tests for `qs serve --stdio`: the MCP handshake, tool calls through
+//! `tools/call` and as plain JSON-RPC methods, and tool errors.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use serde_json::{json, Value};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Login fails", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Add dark mode", "open", &["ui"], None);
+    env
+}
+
+/// Sends messages to `qs serve --stdio` and returns the responses.
+fn serve(env: &TestEnv, messages: &[Value]) -> Vec<Value> {
+    let input: String = messages.iter().map(|m| m.to_string() + "\n").collect();
+    let output = qs_cmd(env)
+        .args(["serve", "--stdio"])
+        .write_stdin(input)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be JSON"))
+        .collect()
+}
+
+/// Builds a `tools/call` request.
+fn call(id: u64, name: &str, arguments: &Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments },
+    })
+}
+
+// =============================================================================
+// Protocol
+// =============================================================================
+
+#[test]
+fn test_handshake_and_tools_list() {
+    let env = setup();
+
+    let responses = serve(
+        &env,
+        &[
+            json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {
+                "protocolVersion": "2025-06-18", "capabilities": {},
+                "clientInfo": {"name": "test", "version": "1"}
+            }}),
+            json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}),
+        ],
+    );
+
+    assert_eq!(responses.len(), 2, "Notifications get no response");
+    assert_eq!(responses[0]["result"]["protocolVersion"], "2025-06-18");
+    assert!(responses[0]["result"]["capabilities"]["tools"].is_object());
+
+    let tools = responses[1]["result"]["tools"].as_array().unwrap();
+    assert_eq!(tools.len(), 8);
+    let new = tools.iter().find(|tool| tool["name"] == "new").unwrap();
+    assert_eq!(new["inputSchema"]["required"], json!(["title"]));
+    assert!(new["outputSchema"]["properties"]["item"].is_object());
+}
+
+#[test]
+fn test_serve_requires_stdio_flag() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .arg("serve")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--stdio"));
+}
+
+// =============================================================================
+// Tools
+// =============================================================================
+
+#[test]
+fn test_list_and_search() {
+    let env = setup();
+
+    let responses = serve(
+        &env,
+        &[
+            call(1, "list", &json!({"labels": ["bug"]})),
+            call(2, "search", &json!({"query": "dark"})),
+        ],
+    );
+
+    let items = &responses[0]["result"]["structuredContent"]["items"];
+    assert_eq!(items.as_array().unwrap().len(), 1);
+    assert_eq!(items[0]["id"], "260101-AAA");
+    assert_eq!(responses[0]["result"]["isError"], false);
+
+    // The text content carries the same JSON for clients without structured output
+    let text = responses[1]["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap();
+    let structured: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(structured["items"][0]["title"], "Add dark mode");
+}
+
+#[test]
+fn test_new_update_comment_close() {
+    let env = setup();
+
+    let responses = serve(
+        &env,
+        &[
+            call(
+                1,
+                "new",
+                &json!({"title": "Write docs", "labels": ["docs"], "body": "- [ ] README"}),
+            ),
+            call(
+                2,
+                "update",
+                &json!({"id": "260101", "title": "Login crashes"}),
+            ),
+            call(
+                3,
+                "comment",
+                &json!({"id": "260101", "text": "Seen on iOS"}),
+            ),
+            call(4, "close", &json!({"id": "260101"})),
+        ],
+    );
+
+    let created = &responses[0]["result"]["structuredContent"]["item"];
+    assert_eq!(created["author"], "Test User");
+    assert_eq!(created["labels"], json!(["docs"]));
+    assert!(created["body"].as_str().unwrap().contains("- [ ] README"));
+
+    let updated = &responses[1]["result"]["structuredContent"];
+    assert_eq!(updated["changed"], true);
+    assert_eq!(updated["item"]["title"], "Login crashes");
+
+    let commented = &responses[2]["result"]["structuredContent"]["item"];
+    assert_eq!(commented["comments"][0]["text"], "Seen on iOS");
+
+    let closed = &responses[3]["result"]["structuredContent"]["item"];
+    assert_eq!(closed["status"], "closed");
+    assert!(closed["path"].as_str().unwrap().contains(".archive"));
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_close_reports_dependents() {
+    let env = setup();
+    create_test_item(&env, "260103-CCC", "Ship release", "open", &[], None);
+    create_test_item(&env, "260104-DDD", "Write notes", "open", &[], None);
+    for (id, blocks) in [
+        ("260101-AAA", "260102-BBB"),
+        ("260101-AAA", "260103-CCC"),
+        ("260104-DDD", "260103-CCC"),
+    ] {
+        qs_cmd(&env)
+            .args(["link", "--id", id, "--blocks", blocks])
+            .assert()
+            .success();
+    }
+
+    let responses = serve(&env, &[call(1, "close", &json!({"id": "260101"}))]);
+
+    let result = &responses[0]["result"]["structuredContent"];
+    assert_eq!(result["item"]["status"], "closed");
+    assert_eq!(result["open_blockers"], json!([]));
+    assert_eq!(result["unblocked"], json!(["260102-BBB"]));
+    assert_eq!(
+        result["still_blocked"],
+        json!([{"id": "260103-CCC", "blocked_by": ["260104-DDD"]}])
+    );
+}
+
+#[test]
+fn test_attach() {
+    let env = setup();
+    env.create_test_file("log.txt", "trace");
+
+    let responses = serve(
+        &env,
+        &[call(
+            1,
+            "attach",
+            &json!({"id": "260102", "sources": ["log.txt", "https://example.com/1", "missing.txt"]}),
+        )],
+    );
+
+    let result = &responses[0]["result"]["structuredContent"];
+    assert_eq!(
+        result["added"],
+        json!(["260102-BBB-Attachment-1-log.txt", "https://example.com/1"])
+    );
+    assert_eq!(result["not_found"].as_array().unwrap().len(), 1);
+    assert_eq!(result["item"]["attachments"], result["added"]);
+}
+
+#[test]
+fn test_tool_errors() {
+    let env = setup();
+
+    let responses = serve(
+        &env,
+        &[
+            call(1, "show", &json!({"id": "999999"})),
+            call(2, "new", &json!({"title": "   "})),
+            call(3, "close", &json!({"idd": "260101"})),
+            call(4, "nope", &json!({})),
+        ],
+    );
+
+    // Tool failures are results the model can read
+    for response in &responses[..3] {
+        assert_eq!(response["result"]["isError"], true);
+        assert!(response["result"]["content"][0]["text"].is_string());
+    }
+    assert!(responses[1]["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("Title cannot be empty"));
+    assert!(responses[2]["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid arguments"));
+
+    // Unknown tools are protocol errors
+    assert_eq!(responses[3]["error"]["code"], -32602);
+}
+
+#[test]
+fn test_plain_json_rpc_methods() {
+    let env = setup();
+
+    let responses = serve(
+        &env,
+        &[
+            json!({"jsonrpc": "2.0", "id": "a", "method": "show", "params": {"id": "260102"}}),
+            json!({"jsonrpc": "2.0", "id": "b", "method": "close", "params": {"id": "999999"}}),
+        ],
+    );
+
+    assert_eq!(responses[0]["id"], "a");
+    assert_eq!(responses[0]["result"]["item"]["title"], "Add dark mode");
+    assert_eq!(responses[1]["error"]["code"], -32000);
+}
+
+#[test]
+fn test_matches_cli_json() {
+    let env = setup();
+
+    let responses = serve(&env, &[call(1, "show", &json!({"id": "260101"}))]);
+    let cli = qs_cmd(&env)
+        .args(["show", "--id", "260101", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let cli: Value = serde_json::from_slice(&cli).unwrap();
+
+    assert_eq!(responses[0]["result"]["structuredContent"]["item"], cli);
+}
@@ -0,0 +1,160 @@
+//! # Show Command Tests
+//!
+//! Tests for `qs show`: the metadata header, rendered Markdown body,
+//! `--raw` output, and JSON output.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+const BODY: &str = "\n## Steps\n\n1. Open **settings**\n2. Run `qs list`\n\n\
+                    > Happens on every login\n\nSee [the docs](https://example.com/docs).\n";
+
+/// Creates an item via the CLI and appends a Markdown body.
+fn create_item_with_body(env: &TestEnv) -> std::path::PathBuf {
+    qs_cmd(env)
+        .args([
+            "new",
+            "--no-interactive",
+            "Login fails",
+            "--label",
+            "bug",
+            "--priority",
+            "high",
+            "--set",
+            "estimate=3",
+        ])
+        .assert()
+        .success();
+    let path = env.find_item_by_id("26").expect("item should exist");
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, format!("{content}{BODY}")).unwrap();
+    path
+}
+
+fn show_output(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env)
+        .arg("show")
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+// =============================================================================
+// Header and Body
+// =============================================================================
+
+#[test]
+fn test_show_prints_header() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_body(&env);
+
+    let stdout = show_output(&env, &["--id", "26"]);
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("Login fails"));
+    assert!(stdout.contains("Status      open"));
+    assert!(stdout.contains("Priority    high"));
+    assert!(stdout.contains("Labels      bug"));
+    assert!(stdout.contains("Author      Test User"));
+    assert!(stdout.contains("(just now)"), "Age is shown:\n{stdout}");
+    assert!(stdout.contains("estimate    3"));
+    assert!(!stdout.contains("Category"), "Empty fields are left out");
+}
+
+#[test]
+fn test_show_renders_markdown() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_body(&env);
+
+    let stdout = show_output(&env, &["--id", "26"]);
+    assert!(stdout.contains("## Steps"));
+    assert!(stdout.contains("1. Open settings"));
+    assert!(stdout.contains("2. Run qs list"));
+    assert!(stdout.contains("│ Happens on every login"));
+    assert!(stdout.contains("See the docs (https://example.com/docs)."));
+    assert!(!stdout.contains("**"), "Markdown syntax is rendered");
+}
+
+#[test]
+fn test_show_respects_no_color() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_body(&env);
+
+    qs_cmd(&env)
+        .args(["show", "--id", "26"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_show_archived_item_with_category() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Old Bug",
+        "open",
+        &["bug"],
+        Some("bugs"),
+    );
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+
+    let stdout = show_output(&env, &["--id", "260101"]);
+    assert!(stdout.contains("Status      closed"));
+    assert!(stdout.contains("Category    bugs"));
+    assert!(stdout.contains("Test item body."));
+}
+
+// =============================================================================
+// Raw and JSON Output
+// =============================================================================
+
+#[test]
+fn test_show_raw_prints_file_unchanged() {
+    let env = setup_test_env_non_interactive();
+    let path = create_item_with_body(&env);
+
+    let stdout = show_output(&env, &["--id", "26", "--raw"]);
+    assert_eq!(stdout, fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn test_show_json_includes_body() {
+    let env = setup_test_env_non_interactive();
+    create_item_with_body(&env);
+
+    let stdout = show_output(&env, &["--id", "26", "--format", "json"]);
+    let json: Value = serde_json::from_str(&stdout).expect("stdout should be valid JSON");
+    assert_eq!(json["title"], "Login fails");
+    assert!(json["body"].as_str().unwrap().contains("## Steps"));
+}
+
+#[test]
+fn test_show_unknown_item_fails() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "999"])
+        .assert()
+        .failure();
+}
@@ -32,6 +32,7 @@ fn test_new_as_template() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -69,6 +70,7 @@ fn test_new_as_template_with_category() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
 
     commands::new(args).expect("new should succeed");
@@ -110,6 +112,7 @@ fn test_list_templates() {
             },
             as_template: true,
             from_template: None,
+            ..Default::default()
         };
         commands::new(args).expect("new should succeed");
     }
@@ -121,13 +124,14 @@ fn test_list_templates() {
         labels: vec![],
         author: None,
         category: None,
-        sort: commands::SortBy::Id,
+        sort: Some(commands::SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Should not error
@@ -160,6 +164,7 @@ fn test_new_from_template() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -170,7 +175,7 @@ fn test_new_from_template() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template
@@ -185,6 +190,7 @@ fn test_new_from_template() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        ..Default::default()
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -218,6 +224,7 @@ fn test_new_from_template_inherits_labels() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -227,7 +234,7 @@ fn test_new_from_template_inherits_labels() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template with additional label
@@ -242,6 +249,7 @@ fn test_new_from_template_inherits_labels() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        ..Default::default()
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -274,6 +282,7 @@ fn test_new_from_template_inherits_category() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -283,7 +292,7 @@ fn test_new_from_template_inherits_category() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template without specifying category
@@ -298,6 +307,7 @@ fn test_new_from_template_inherits_category() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        ..Default::default()
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -328,6 +338,7 @@ fn test_templates_excluded_from_list() {
         },
         as_template: false,
         from_template: None,
+        ..Default::default()
     };
     commands::new(item_args).expect("create item should succeed");
 
@@ -343,6 +354,7 @@ fn test_templates_excluded_from_list() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -353,13 +365,14 @@ fn test_templates_excluded_from_list() {
         labels: vec![],
         author: None,
         category: None,
-        sort: commands::SortBy::Id,
+        sort: Some(commands::SortBy::Id),
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        ..Default::default()
     };
 
     // Verify counts
@@ -390,6 +403,7 @@ fn test_find_template_by_title() {
         },
         as_template: true,
         from_template: None,
+        ..Default::default()
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -405,6 +419,7 @@ fn test_find_template_by_title() {
         },
         as_template: false,
         from_template: Some(Some("bug report".to_string())), // Case-insensitive title match
+        ..Default::default()
     };
     commands::new(item_args).expect("create from template by title should succeed");
 
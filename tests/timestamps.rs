@@ -0,0 +1,189 @@
+//! # Timestamp Tests
+//!
+//! Tests for the `updated_at` and `closed_at` timestamps maintained by every
+//! write path, and for `qs list --sort updated` and `qs list --stale`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, json_records, qs_cmd, setup_test_env_non_interactive};
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn ids(records: &[Value]) -> Vec<&str> {
+    records
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect()
+}
+
+// =============================================================================
+// updated_at
+// =============================================================================
+
+#[test]
+fn test_new_item_has_no_updated_at() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Fresh", "open", &[], None);
+
+    let records = json_records(&env, &["list"]);
+    assert!(records[0]["updated_at"].is_null());
+    assert!(records[0]["closed_at"].is_null());
+}
+
+#[test]
+fn test_update_sets_updated_at() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--label", "bug"])
+        .assert()
+        .success();
+
+    let content = env.read_item(&path);
+    assert!(content.contains("updated_at: "), "{content}");
+    assert!(json_records(&env, &["list"])[0]["updated_at"].is_string());
+}
+
+#[test]
+fn test_update_without_changes_keeps_file() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &["bug"], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--remove-label", "missing"])
+        .assert()
+        .success();
+
+    assert!(!env.read_item(&path).contains("updated_at"));
+}
+
+#[test]
+fn test_comment_and_attachments_set_updated_at() {
+    let env = setup_test_env_non_interactive();
+    let path = create_test_item(&env, "260101-AAA", "Commented", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Attached", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101", "Looks good"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args([
+            "attachments",
+            "add",
+            "--id",
+            "260102",
+            "https://example.com",
+        ])
+        .assert()
+        .success();
+
+    assert!(env.read_item(&path).contains("updated_at: "));
+    let records = json_records(&env, &["list"]);
+    assert!(records.iter().all(|r| r["updated_at"].is_string()));
+}
+
+#[test]
+fn test_link_sets_updated_at_on_both_items() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Blocker", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Blocked", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "260101", "--blocks", "260102"])
+        .assert()
+        .success();
+
+    let records = json_records(&env, &["list"]);
+    assert!(records.iter().all(|r| r["updated_at"].is_string()));
+}
+
+// =============================================================================
+// closed_at
+// =============================================================================
+
+#[test]
+fn test_close_sets_closed_at_and_reopen_clears_it() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+
+    let closed = json_records(&env, &["list", "--closed"]);
+    assert!(closed[0]["closed_at"].is_string());
+    assert_eq!(closed[0]["closed_at"], closed[0]["updated_at"]);
+
+    qs_cmd(&env)
+        .args(["reopen", "--id", "260101"])
+        .assert()
+        .success();
+
+    let path = env.find_item_by_id("260101").unwrap();
+    let content = env.read_item(&path);
+    assert!(!content.contains("closed_at"), "{content}");
+    assert!(content.contains("updated_at: "));
+}
+
+// =============================================================================
+// Sorting and Stale Filter
+// =============================================================================
+
+#[test]
+fn test_sort_by_updated() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Untouched", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "First", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Second", "open", &[], None);
+
+    for id in ["260103", "260102"] {
+        qs_cmd(&env)
+            .args(["update", "--id", id, "--label", "touched"])
+            .assert()
+            .success();
+    }
+
+    let records = json_records(&env, &["list", "--sort", "updated"]);
+    assert_eq!(ids(&records), ["260102-BBB", "260103-CCC", "260101-AAA"]);
+}
+
+#[test]
+fn test_stale_filter() {
+    let env = setup_test_env_non_interactive();
+    // Test items are created on 2026-01-09 and never updated
+    create_test_item(&env, "260101-AAA", "Old", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Recent", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260102", "--label", "active"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        ids(&json_records(&env, &["list", "--stale", "30d"])),
+        ["260101-AAA"]
+    );
+    assert_eq!(
+        ids(&json_records(&env, &["list", "--stale", "100000d"])),
+        Vec::<&str>::new()
+    );
+}
+
+#[test]
+fn test_stale_rejects_invalid_age() {
+    let env = setup_test_env_non_interactive();
+
+    qs_cmd(&env)
+        .args(["list", "--stale", "soon"])
+        .assert()
+        .failure();
+}
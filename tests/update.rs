@@ -26,6 +26,7 @@ fn test_update_title() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -55,6 +56,7 @@ fn test_update_add_labels() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -84,6 +86,7 @@ fn test_update_move_to_category() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -108,6 +111,7 @@ fn test_update_clear_category() {
         remove_labels: vec![],
         category: None,
         remove_category: true,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -133,6 +137,7 @@ fn test_update_nonexistent_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     let result = commands::update(args);
@@ -159,6 +164,7 @@ fn test_update_multiple_labels_at_once() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -186,6 +192,7 @@ fn test_update_title_and_category_combined() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -223,6 +230,7 @@ fn test_update_title_and_labels_combined() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -256,6 +264,7 @@ fn test_update_all_fields_combined() {
         remove_labels: vec![],
         category: Some("features".to_string()),
         remove_category: false,
+        ..Default::default()
     };
 
     commands::update(args).expect("update should succeed");
@@ -286,6 +295,7 @@ fn test_update_category_then_clear() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        ..Default::default()
     };
     commands::update(args).expect("update should succeed");
 
@@ -298,6 +308,7 @@ fn test_update_category_then_clear() {
         remove_labels: vec![],
         category: None,
         remove_category: true,
+        ..Default::default()
     };
     commands::update(args).expect("clear category should succeed");
 
@@ -324,6 +335,7 @@ fn test_update_with_full_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     let result = commands::update(args);
@@ -344,6 +356,7 @@ fn test_update_without_init() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        ..Default::default()
     };
 
     let result = commands::update(args);
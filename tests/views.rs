@@ -0,0 +1,236 @@
+//! # Saved View Tests
+//!
+//! Tests for saved views: `qs list --view`, combining views with command-line
+//! filters, `qs view list`, and config validation.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, json_ids, qs_cmd, setup_test_env_non_interactive, GlobalConfigBuilder,
+    TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+use serde_json::Value;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+const VIEWS: &str = r#"
+[[views]]
+name = "triage"
+labels = ["bug"]
+sort = "title"
+columns = ["id", "title", "assignees"]
+
+[[views]]
+name = "backend-done"
+category = "backend"
+closed = true
+
+[[views]]
+name = "not-ui"
+query = "NOT label:ui"
+"#;
+
+/// Sets up a project with saved views and a few items.
+fn setup() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(VIEWS);
+
+    create_test_item(&env, "260101-AAA", "Zebra crash", "open", &["bug"], None);
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Apple crash",
+        "open",
+        &["bug", "ui"],
+        None,
+    );
+    create_test_item(&env, "260103-CCC", "Docs", "open", &["docs"], None);
+    create_test_item(
+        &env,
+        "260104-DDD",
+        "Old query",
+        "closed",
+        &[],
+        Some("backend"),
+    );
+
+    // Zebra is the newest item, and the closed one lives in the archive
+    let zebra = env.find_item_by_id("260101-AAA").unwrap();
+    let content = fs::read_to_string(&zebra).unwrap();
+    fs::write(&zebra, content.replace("2026-01-09", "2026-01-10")).unwrap();
+    let archive = env.archive_path().join("backend");
+    fs::create_dir_all(&archive).unwrap();
+    fs::rename(
+        env.stack_path().join("backend/260104-DDD-old-query.md"),
+        archive.join("260104-DDD-old-query.md"),
+    )
+    .unwrap();
+    env
+}
+
+// =============================================================================
+// qs list --view
+// =============================================================================
+
+#[test]
+fn test_list_view_applies_filters_and_sort() {
+    let env = setup();
+
+    // Sorted by title from the view
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "triage"]),
+        ["260102-BBB", "260101-AAA"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "not-ui"]),
+        ["260101-AAA", "260103-CCC"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "backend-done"]),
+        ["260104-DDD"]
+    );
+}
+
+#[test]
+fn test_list_view_name_is_case_insensitive() {
+    let env = setup();
+    assert_eq!(json_ids(&env, &["list", "--view", "TRIAGE"]).len(), 2);
+}
+
+#[test]
+fn test_list_view_combines_with_cli_filters() {
+    let env = setup();
+
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "triage", "--label", "ui"]),
+        ["260102-BBB"]
+    );
+    // An explicit --sort overrides the view's
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "triage", "--sort", "date"]),
+        ["260101-AAA", "260102-BBB"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "triage", "--sort", "id"]),
+        ["260101-AAA", "260102-BBB"]
+    );
+    assert_eq!(
+        json_ids(&env, &["list", "--view", "not-ui", "-q", "crash"]),
+        ["260101-AAA"]
+    );
+}
+
+#[test]
+fn test_list_unknown_view_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--view", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown view 'nope'"))
+        .stderr(predicate::str::contains("triage"));
+}
+
+#[test]
+fn test_list_view_without_views_fails() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["list", "--view", "triage"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no views are defined"));
+}
+
+// =============================================================================
+// qs view list
+// =============================================================================
+
+#[test]
+fn test_view_list_text() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["view", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("triage"))
+        .stdout(predicate::str::contains("labels: bug"))
+        .stdout(predicate::str::contains("columns: ID, Title, Assignees"))
+        .stdout(predicate::str::contains("query: NOT label:ui"));
+}
+
+#[test]
+fn test_view_list_json() {
+    let env = setup();
+
+    let output = qs_cmd(&env)
+        .args(["view", "list", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    let views = json.as_array().unwrap();
+    assert_eq!(views.len(), 3);
+    assert_eq!(views[0]["name"], "triage");
+    assert_eq!(views[0]["columns"][2], "assignees");
+    assert_eq!(views[1]["closed"], true);
+}
+
+#[test]
+fn test_view_list_empty() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["view", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No views defined"));
+}
+
+// =============================================================================
+// Validation
+// =============================================================================
+
+#[test]
+fn test_invalid_view_config_fails() {
+    let env = setup();
+    env.write_project_config("[[views]]\nname = \"bad\"\nquery = \"(label:bug\"\n");
+
+    qs_cmd(&env)
+        .args(["list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid view"))
+        .stderr(predicate::str::contains("bad"));
+}
+
+#[test]
+fn test_invalid_view_sort_fails() {
+    let env = setup();
+    env.write_project_config("[[views]]\nname = \"odd\"\nsort = \"sideways\"\n");
+
+    qs_cmd(&env)
+        .args(["list", "--view", "odd"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid sort 'sideways' in view 'odd'",
+        ));
+}
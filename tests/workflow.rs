@@ -0,0 +1,247 @@
+//! # Workflow Tests
+//!
+//! Tests for configurable workflow states: `qs status`, transition rules,
+//! archived states, and `qs list --state`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, qs_cmd, setup_test_env_non_interactive, TestEnv};
+use predicates::prelude::*;
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+const WORKFLOW: &str = r#"
+[workflow]
+states = ["todo", "in-progress", "review", "done", "wontfix"]
+archived = ["done", "wontfix"]
+
+[workflow.transitions]
+todo = ["in-progress", "wontfix"]
+in-progress = ["review", "todo"]
+review = ["done", "in-progress"]
+done = ["todo"]
+"#;
+
+fn setup_with_workflow() -> TestEnv {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(WORKFLOW);
+    env
+}
+
+fn new_item(env: &TestEnv, title: &str) -> String {
+    let output = qs_cmd(env)
+        .args(["new", "--no-interactive", title])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let path = String::from_utf8(output).unwrap();
+    env.read_item(&env.project_path().join(path.trim()))
+}
+
+fn move_to(env: &TestEnv, state: &str) -> assert_cmd::assert::Assert {
+    qs_cmd(env).args(["status", "--id", "26", state]).assert()
+}
+
+fn item_content(env: &TestEnv) -> String {
+    env.read_item(&env.find_item_by_id("26").expect("item should exist"))
+}
+
+// =============================================================================
+// Default Workflow
+// =============================================================================
+
+#[test]
+fn test_status_without_workflow_closes_and_reopens() {
+    let env = setup_test_env_non_interactive();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    move_to(&env, "closed").success();
+    let path = env.find_item_by_id("260101").unwrap();
+    assert!(path.starts_with(env.archive_path()));
+    let content = env.read_item(&path);
+    assert!(content.contains("status: closed"));
+    assert!(
+        !content.contains("state:"),
+        "No state key without a workflow"
+    );
+
+    move_to(&env, "Open").success();
+    let path = env.find_item_by_id("260101").unwrap();
+    assert!(path.starts_with(env.stack_path()));
+
+    move_to(&env, "review")
+        .failure()
+        .stderr(predicate::str::contains("Unknown state 'review'"));
+}
+
+// =============================================================================
+// Custom Workflow
+// =============================================================================
+
+#[test]
+fn test_new_item_gets_initial_state() {
+    let env = setup_with_workflow();
+
+    let content = new_item(&env, "Task");
+    assert!(content.contains("state: todo"));
+    assert!(content.contains("status: open"));
+}
+
+#[test]
+fn test_allowed_and_disallowed_transitions() {
+    let env = setup_with_workflow();
+    new_item(&env, "Task");
+
+    move_to(&env, "in-progress")
+        .success()
+        .stdout(predicate::str::contains("Moved to 'in-progress'"));
+    assert!(item_content(&env).contains("state: in-progress"));
+
+    move_to(&env, "done").failure().stderr(
+        predicate::str::contains("Cannot move")
+            .and(predicate::str::contains("allowed: review, todo")),
+    );
+    assert!(item_content(&env).contains("state: in-progress"));
+
+    move_to(&env, "in-progress")
+        .failure()
+        .stderr(predicate::str::contains("already in-progress"));
+
+    qs_cmd(&env)
+        .args(["status", "--id", "26", "wontfix", "--force"])
+        .assert()
+        .success();
+    assert!(item_content(&env).contains("state: wontfix"));
+}
+
+#[test]
+fn test_archived_state_moves_item() {
+    let env = setup_with_workflow();
+    new_item(&env, "Task");
+
+    move_to(&env, "in-progress").success();
+    move_to(&env, "review").success();
+    move_to(&env, "done").success();
+
+    let path = env.find_item_by_id("26").unwrap();
+    assert!(path.starts_with(env.archive_path()));
+    let content = env.read_item(&path);
+    assert!(content.contains("status: closed"));
+    assert!(content.contains("state: done"));
+
+    move_to(&env, "todo").success();
+    let path = env.find_item_by_id("26").unwrap();
+    assert!(path.starts_with(env.stack_path()));
+    assert!(env.read_item(&path).contains("status: open"));
+}
+
+#[test]
+fn test_close_and_reopen_use_workflow_states() {
+    let env = setup_with_workflow();
+    new_item(&env, "Task");
+    move_to(&env, "in-progress").success();
+
+    qs_cmd(&env)
+        .args(["close", "--id", "26"])
+        .assert()
+        .success();
+    let content = item_content(&env);
+    assert!(
+        content.contains("state: done"),
+        "Close uses the first archived state"
+    );
+
+    qs_cmd(&env)
+        .args(["reopen", "--id", "26"])
+        .assert()
+        .success();
+    assert!(item_content(&env).contains("state: todo"));
+}
+
+#[test]
+fn test_close_ignores_transition_rules() {
+    let env = setup_with_workflow();
+    new_item(&env, "Task");
+
+    // todo -> done is not an allowed transition
+    move_to(&env, "done").failure();
+
+    qs_cmd(&env)
+        .args(["close", "--id", "26"])
+        .assert()
+        .success();
+    assert!(item_content(&env).contains("state: done"));
+}
+
+#[test]
+fn test_list_by_state() {
+    let env = setup_with_workflow();
+    create_test_item(&env, "260101-AAA", "Legacy", "open", &[], None);
+    new_item(&env, "Started");
+
+    let started = env
+        .list_stack_files()
+        .into_iter()
+        .find(|p| p.to_string_lossy().contains("started"))
+        .unwrap();
+    qs_cmd(&env)
+        .args(["status", "--file"])
+        .arg(&started)
+        .arg("in-progress")
+        .assert()
+        .success();
+
+    let output = qs_cmd(&env)
+        .args(["list", "--no-interactive", "--state", "in-progress"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.contains("started"));
+
+    // Items without a state key count as the initial state
+    let output = qs_cmd(&env)
+        .args(["list", "--no-interactive", "--state", "todo"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert!(String::from_utf8(output).unwrap().contains("legacy"));
+}
+
+#[test]
+fn test_json_output_includes_state() {
+    let env = setup_with_workflow();
+    new_item(&env, "Task");
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"state\": \"todo\""));
+}
+
+#[test]
+fn test_invalid_workflow_is_reported() {
+    let env = setup_test_env_non_interactive();
+    env.write_project_config(
+        "[workflow]\nstates = [\"todo\", \"done\"]\narchived = [\"closed\"]\n",
+    );
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid workflow"));
+}